@@ -32,7 +32,7 @@ async fn main() {
             println!(
                 "Now playing: {} - {}",
                 track.name(),
-                track.artists().first().unwrap().name()
+                track.primary_artist().map_or("unknown artist", |artist| artist.name())
             );
         }
     }
@@ -47,7 +47,9 @@ async fn main() {
     println!(
         "{} - {} ({})",
         one_track.name(),
-        one_track.artists().first().unwrap().name(),
+        one_track
+            .primary_artist()
+            .map_or("unknown artist", |artist| artist.name()),
         one_track.album().name()
     );
 }
@@ -48,7 +48,7 @@ async fn main() {
             println!(
                 "Now playing: {} - {}",
                 track.name(),
-                track.artists().first().unwrap().name()
+                track.primary_artist().map_or("unknown artist", |artist| artist.name())
             );
         }
     }
@@ -63,7 +63,9 @@ async fn main() {
     println!(
         "{} - {} ({})",
         one_track.name(),
-        one_track.artists().first().unwrap().name(),
+        one_track
+            .primary_artist()
+            .map_or("unknown artist", |artist| artist.name()),
         one_track.album().name()
     );
 
@@ -24,7 +24,9 @@ async fn main() {
     println!(
         "{} - {} ({})",
         one_track.name(),
-        one_track.artists().first().unwrap().name(),
+        one_track
+            .primary_artist()
+            .map_or("unknown artist", |artist| artist.name()),
         one_track.album().name()
     );
 
@@ -103,7 +103,7 @@ async fn main() {
 
     tokio::time::sleep(Duration::from_secs(5)).await;
     println!("Seek");
-    user_client.seek(60 * 1000u32).send_async().await.unwrap();
+    user_client.seek(Duration::from_secs(60)).send_async().await.unwrap();
 
     tokio::time::sleep(Duration::from_secs(5)).await;
     println!("Previous");
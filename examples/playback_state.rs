@@ -60,7 +60,7 @@ async fn main() {
         );
 
         println!(
-            "Playing on: {} ({}), which is a {:?}",
+            "Playing on: {} ({:?}), which is a {:?}",
             playback_state.device().name(),
             playback_state.device().id(),
             playback_state.device().device_type()
@@ -71,7 +71,9 @@ async fn main() {
                 println!(
                     "{} - {} ({}) [{}] - {:?}/{:?}",
                     full_track.name(),
-                    full_track.artists().first().unwrap().name(),
+                    full_track
+                        .primary_artist()
+                        .map_or("unknown artist", |artist| artist.name()),
                     full_track.album().name(),
                     full_track.id(),
                     item.progress(),
@@ -91,7 +93,9 @@ async fn main() {
                 println!(
                     "{} - {} ({}) [{}] - {:?}/{:?}",
                     full_track.name(),
-                    full_track.artists().first().unwrap().name(),
+                    full_track
+                        .primary_artist()
+                        .map_or("unknown artist", |artist| artist.name()),
                     full_track.album().name(),
                     full_track.id(),
                     item.progress(),
@@ -0,0 +1,32 @@
+use dotenvy::dotenv;
+use ferrispot::{client::SpotifyClientBuilder, model::id::Id, prelude::*};
+
+#[tokio::main]
+async fn main() {
+    dotenv().ok();
+    env_logger::init();
+
+    let spotify_client =
+        SpotifyClientBuilder::new(std::env::var("CLIENT_ID").expect("Spotify client ID not in environment"))
+            .client_secret(std::env::var("CLIENT_SECRET").expect("Spotify client secret not in environment"))
+            // a synchronous (blocking) client may be built with .build_sync() if the "sync" crate feature is enabled
+            .build_async()
+            .await
+            .expect("failed to build Spotify client");
+
+    let related_artists = spotify_client
+        .related_artists(Id::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap())
+        .send_async()
+        .await
+        .unwrap();
+
+    // an artist may not have any related artists, in which case the list is empty
+    for artist in related_artists {
+        println!(
+            "{} ({} followers) [{}]",
+            artist.name(),
+            artist.followers().total(),
+            artist.id()
+        );
+    }
+}
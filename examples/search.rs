@@ -28,7 +28,7 @@ async fn main() {
         println!(
             "{} - {} ({}) [{}]",
             track.name(),
-            track.artists().first().unwrap().name(),
+            track.primary_artist().map_or("unknown artist", |artist| artist.name()),
             track.album().name(),
             track.id(),
         );
@@ -41,7 +41,7 @@ async fn main() {
         println!(
             "{} - {} ({}) [{}]",
             track.name(),
-            track.artists().first().unwrap().name(),
+            track.primary_artist().map_or("unknown artist", |artist| artist.name()),
             track.album().name(),
             track.id(),
         );
@@ -54,7 +54,7 @@ async fn main() {
         println!(
             "{} - {} ({}) [{}]",
             track.name(),
-            track.artists().first().unwrap().name(),
+            track.primary_artist().map_or("unknown artist", |artist| artist.name()),
             track.album().name(),
             track.id(),
         );
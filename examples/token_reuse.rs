@@ -57,7 +57,9 @@ async fn main() {
                 println!(
                     "{} - {} ({})",
                     full_track.name(),
-                    full_track.artists().first().unwrap().name(),
+                    full_track
+                        .primary_artist()
+                        .map_or("unknown artist", |artist| artist.name()),
                     full_track.album().name()
                 )
             }
@@ -28,7 +28,9 @@ async fn main() {
     println!(
         "{} - {} ({})",
         one_track.name(),
-        one_track.artists().first().unwrap().name(),
+        one_track
+            .primary_artist()
+            .map_or("unknown artist", |artist| artist.name()),
         one_track.album().name()
     );
 
@@ -48,7 +50,7 @@ async fn main() {
         println!(
             "{} - {} ({})",
             track.name(),
-            track.artists().first().unwrap().name(),
+            track.primary_artist().map_or("unknown artist", |artist| artist.name()),
             track.album().name()
         );
     }
@@ -60,7 +60,7 @@ async fn main() {
     println!(
         "{} has {} followers",
         public_user.display_name().unwrap_or(public_user.id().as_str()),
-        public_user.followers().total
+        public_user.followers().total()
     );
 
     let you = user_client.current_user_profile().send_async().await.unwrap();
@@ -72,10 +72,10 @@ async fn main() {
         print!("Hi {display_name}! ");
     }
 
-    println!("You have {} followers", current_user.followers().total);
+    println!("You have {} followers", current_user.followers().total());
 
     match PrivateUser::try_from(you) {
-        Ok(private_user) => println!("Your subscription level is: {}", private_user.product()),
+        Ok(private_user) => println!("Your subscription level is: {:?}", private_user.product()),
         Err(_) => println!("I don't have access to your private profile information"),
     }
 }
@@ -89,21 +89,27 @@ pub mod request_builder;
 
 pub(crate) mod object;
 pub(crate) mod private;
+pub(crate) mod response_cache;
+mod retry_policy;
 pub(crate) mod scoped;
 pub(crate) mod unscoped;
 
-use std::sync::{Arc, RwLock};
+#[cfg(all(test, any(feature = "async", feature = "sync")))]
+pub(crate) mod test_util;
 
-use base64::Engine;
-use const_format::concatcp;
-use log::debug;
-use reqwest::{
-    header::{self, HeaderMap},
-    IntoUrl, Method, StatusCode,
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
 };
+
+use base64::Engine;
+use log::{debug, warn};
+use reqwest::{header::HeaderValue, IntoUrl, Method, StatusCode, Url};
 use serde::Deserialize;
 
 use self::implicit_grant::ImplicitGrantUserClientBuilder;
+use self::response_cache::ResponseCache;
 #[cfg(feature = "async")]
 use self::{
     authorization_code::{AsyncAuthorizationCodeUserClient, AsyncAuthorizationCodeUserClientBuilder},
@@ -116,10 +122,18 @@ use self::{
     implicit_grant::SyncImplicitGrantUserClientBuilder,
     private::SyncClient,
 };
-pub use self::{scoped::ScopedClient, unscoped::UnscopedClient};
+pub use self::{
+    retry_policy::{RetryPolicy, RetryStatus},
+    scoped::ScopedClient,
+    unscoped::UnscopedClient,
+};
 use crate::{
     error::{Error, Result},
-    model::error::{AuthenticationErrorKind, AuthenticationErrorResponse},
+    model::{
+        error::{AuthError, AuthenticationErrorKind},
+        Market,
+    },
+    util::redacted::Redacted,
 };
 
 /// Type alias for an asynchronous Spotify client. See [SpotifyClient](SpotifyClient).
@@ -143,33 +157,69 @@ pub type SyncSpotifyClientWithSecret = SpotifyClientWithSecret<SyncClient>;
 const RANDOM_STATE_LENGTH: usize = 16;
 const PKCE_VERIFIER_LENGTH: usize = 128; // maximum Spotify allows
 const CLIENT_CREDENTIALS_TOKEN_REQUEST_FORM: &[(&str, &str)] = &[("grant_type", "client_credentials")];
+// refresh access tokens slightly before they actually expire so requests in flight don't fail
+const ACCESS_TOKEN_EXPIRY_SKEW: Duration = Duration::from_secs(10);
+// how many chunked sub-requests an asynchronous client sends at once, unless configured otherwise
+#[cfg(feature = "async")]
+const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 5;
+// how many times a request is retried after being rate limited, unless configured otherwise
+const DEFAULT_MAX_RATE_LIMIT_RETRIES: u32 = 3;
+const DEFAULT_USER_AGENT: &str = concat!("ferrispot/", env!("CARGO_PKG_VERSION"));
 
 const API_BASE_URL: &str = "https://api.spotify.com/v1/";
 
+// the endpoints are relative to the API base URL
+
 // unscoped endpoints
-const API_TRACKS_ENDPOINT: &str = concatcp!(API_BASE_URL, "tracks");
-const API_SEARCH_ENDPOINT: &str = concatcp!(API_BASE_URL, "search");
+const API_TRACKS_ENDPOINT: &str = "tracks";
+const API_SEARCH_ENDPOINT: &str = "search";
+const API_ARTISTS_ENDPOINT: &str = "artists";
+const API_ALBUMS_ENDPOINT: &str = "albums";
+const API_AUDIO_ANALYSIS_ENDPOINT: &str = "audio-analysis";
+const API_AUDIO_FEATURES_ENDPOINT: &str = "audio-features";
+const API_SHOWS_ENDPOINT: &str = "shows";
+const API_EPISODES_ENDPOINT: &str = "episodes";
+const API_AUDIOBOOKS_ENDPOINT: &str = "audiobooks";
+const API_CHAPTERS_ENDPOINT: &str = "chapters";
+const API_RECOMMENDATIONS_ENDPOINT: &str = "recommendations";
+const API_NEW_RELEASES_ENDPOINT: &str = "browse/new-releases";
+const API_FEATURED_PLAYLISTS_ENDPOINT: &str = "browse/featured-playlists";
+const API_CATEGORIES_ENDPOINT: &str = "browse/categories";
+const API_GENRE_SEEDS_ENDPOINT: &str = "recommendations/available-genre-seeds";
+const API_MARKETS_ENDPOINT: &str = "markets";
 
 // scoped endpoints
-const API_USER_PROFILE_ENDPOINT: &str = concatcp!(API_BASE_URL, "users");
-const API_CURRENT_USER_PROFILE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me");
-const API_PLAYBACK_STATE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player");
-const API_CURRENTLY_PLAYING_ITEM_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/currently-playing");
-const API_PLAYER_PLAY_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/play");
-const API_PLAYER_PAUSE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/pause");
-const API_PLAYER_REPEAT_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/repeat");
-const API_PLAYER_SHUFFLE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/shuffle");
-const API_PLAYER_VOLUME_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/volume");
-const API_PLAYER_NEXT_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/next");
-const API_PLAYER_PREVIOUS_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/previous");
-const API_PLAYER_SEEK_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/seek");
-const API_PLAYER_QUEUE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/queue");
-const API_PLAYER_DEVICES_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/devices");
-
-// accounts
+const API_USER_PROFILE_ENDPOINT: &str = "users";
+const API_CURRENT_USER_PROFILE_ENDPOINT: &str = "me";
+const API_PLAYBACK_STATE_ENDPOINT: &str = "me/player";
+const API_CURRENTLY_PLAYING_ITEM_ENDPOINT: &str = "me/player/currently-playing";
+const API_PLAYER_ENDPOINT: &str = "me/player";
+const API_PLAYER_PLAY_ENDPOINT: &str = "me/player/play";
+const API_PLAYER_PAUSE_ENDPOINT: &str = "me/player/pause";
+const API_PLAYER_REPEAT_ENDPOINT: &str = "me/player/repeat";
+const API_PLAYER_SHUFFLE_ENDPOINT: &str = "me/player/shuffle";
+const API_PLAYER_VOLUME_ENDPOINT: &str = "me/player/volume";
+const API_PLAYER_NEXT_ENDPOINT: &str = "me/player/next";
+const API_PLAYER_PREVIOUS_ENDPOINT: &str = "me/player/previous";
+const API_PLAYER_SEEK_ENDPOINT: &str = "me/player/seek";
+const API_PLAYER_QUEUE_ENDPOINT: &str = "me/player/queue";
+const API_PLAYER_DEVICES_ENDPOINT: &str = "me/player/devices";
+const API_RECENTLY_PLAYED_ENDPOINT: &str = "me/player/recently-played";
+const API_SAVED_TRACKS_ENDPOINT: &str = "me/tracks";
+const API_SAVED_TRACKS_CONTAINS_ENDPOINT: &str = "me/tracks/contains";
+const API_SAVED_ALBUMS_ENDPOINT: &str = "me/albums";
+const API_SAVED_ALBUMS_CONTAINS_ENDPOINT: &str = "me/albums/contains";
+const API_SAVED_SHOWS_ENDPOINT: &str = "me/shows";
+const API_SAVED_SHOWS_CONTAINS_ENDPOINT: &str = "me/shows/contains";
+const API_FOLLOWING_ENDPOINT: &str = "me/following";
+const API_FOLLOWING_CONTAINS_ENDPOINT: &str = "me/following/contains";
+const API_CURRENT_USER_PLAYLISTS_ENDPOINT: &str = "me/playlists";
+const API_PLAYLISTS_ENDPOINT: &str = "playlists";
+
+// accounts endpoints are relative to the accounts base URL
 const ACCOUNTS_BASE_URL: &str = "https://accounts.spotify.com/";
-const ACCOUNTS_AUTHORIZE_ENDPOINT: &str = concatcp!(ACCOUNTS_BASE_URL, "authorize");
-const ACCOUNTS_API_TOKEN_ENDPOINT: &str = concatcp!(ACCOUNTS_BASE_URL, "api/token");
+const ACCOUNTS_AUTHORIZE_ENDPOINT: &str = "authorize";
+const ACCOUNTS_API_TOKEN_ENDPOINT: &str = "api/token";
 
 /// Clients that have automatically refreshable access tokens implement this trait. The asynchronous
 /// [SpotifyClientWithSecret](SpotifyClientWithSecret) and
@@ -180,7 +230,11 @@ const ACCOUNTS_API_TOKEN_ENDPOINT: &str = concatcp!(ACCOUNTS_BASE_URL, "api/toke
 #[cfg(feature = "async")]
 #[async_trait::async_trait]
 pub trait AccessTokenRefreshAsync: crate::private::Sealed {
-    /// Asynchronously request a new access token from Spotify and save it internally in the client.
+    /// Asynchronously request a new access token from Spotify and save it internally in the client along with its
+    /// expiry.
+    ///
+    /// The client refreshes its access token automatically once it expires, but this function may be used to refresh
+    /// it at any other time, such as right after the application wakes up from sleep.
     async fn refresh_access_token(&self) -> Result<()>;
 }
 
@@ -192,10 +246,39 @@ pub trait AccessTokenRefreshAsync: crate::private::Sealed {
 /// this trait, since even though it has an access token, it cannot be automatically refreshed.
 #[cfg(feature = "sync")]
 pub trait AccessTokenRefreshSync: crate::private::Sealed {
-    /// Synchronously request a new access token from Spotify and save it internally in the client.
+    /// Synchronously request a new access token from Spotify and save it internally in the client along with its
+    /// expiry.
+    ///
+    /// The client refreshes its access token automatically once it expires, but this function may be used to refresh
+    /// it at any other time, such as right after the application wakes up from sleep.
     fn refresh_access_token(&self) -> Result<()>;
 }
 
+/// Clients that may send requests to the API implement this trait. It gives insight into how the client has been rate
+/// limited, which may help pacing the requests. [SpotifyClientWithSecret](SpotifyClientWithSecret),
+/// [AuthorizationCodeUserClient](authorization_code::AuthorizationCodeUserClient) and
+/// [ImplicitGrantUserClient](implicit_grant::ImplicitGrantUserClient) implement this trait.
+pub trait RateLimitInformation: crate::private::Sealed {
+    /// Returns the wait time Spotify asked for in the `Retry-After` header of the latest rate limited response the
+    /// client received, or `None` if the client hasn't been rate limited.
+    ///
+    /// The value is shared between the client's clones and every other client that shares the same HTTP client, such
+    /// as the user clients built from the same [SpotifyClient](SpotifyClient).
+    fn last_retry_after(&self) -> Option<Duration>;
+}
+
+impl<T> RateLimitInformation for T
+where
+    T: private::RetryAfterState,
+{
+    fn last_retry_after(&self) -> Option<Duration> {
+        *self
+            .retry_after_state()
+            .read()
+            .expect("last retry after rwlock poisoned")
+    }
+}
+
 /// A base Spotify client that does *not* have a client secret.
 ///
 /// This client by itself cannot be used to access the Spotify API, since it has no way of authenticating itself to the
@@ -244,36 +327,112 @@ where
     http_client: C,
 }
 
-#[derive(Debug)]
 struct SpotifyClientWithSecretRef {
     client_id: String,
     // client_secret: String,
     access_token: RwLock<String>,
+    access_token_expiry: RwLock<Instant>,
 }
 
 /// Builder for [SpotifyClient](SpotifyClient).
 #[derive(Debug, Clone)]
 pub struct SpotifyClientBuilder {
     client_id: String,
+    #[cfg(feature = "async")]
+    http_client: Option<reqwest::Client>,
+    #[cfg(feature = "async")]
+    max_concurrent_requests: usize,
+    #[cfg(feature = "sync")]
+    blocking_http_client: Option<reqwest::blocking::Client>,
+    base_urls: private::BaseUrls,
+    response_cache_capacity: Option<usize>,
+    retry_policy: Option<RetryPolicy>,
+    max_rate_limit_retries: u32,
+    default_market: Option<Market>,
+    user_agent: Option<String>,
 }
 
 /// Builder for [SpotifyClientWithSecret](SpotifyClientWithSecret). New instances are acquired through the
-/// [`client_secret`-function](SpotifyClientBuilder::client_secret) in [SpotifyClientBuilder].
-#[derive(Debug, Clone)]
+/// [`client_secret`-function](SpotifyClientBuilder::client_secret) in [SpotifyClientBuilder]. The client secret is
+/// redacted from the builder's debug output.
+#[derive(Clone)]
 pub struct SpotifyClientWithSecretBuilder {
     client_id: String,
     client_secret: String,
+    #[cfg(feature = "async")]
+    http_client: Option<reqwest::Client>,
+    #[cfg(feature = "async")]
+    max_concurrent_requests: usize,
+    #[cfg(feature = "sync")]
+    blocking_http_client: Option<reqwest::blocking::Client>,
+    base_urls: private::BaseUrls,
+    response_cache_capacity: Option<usize>,
+    retry_policy: Option<RetryPolicy>,
+    max_rate_limit_retries: u32,
+    default_market: Option<Market>,
+    user_agent: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct ClientTokenResponse {
     access_token: String,
+    expires_in: u32,
 
-    // these fields are in the response but the library doesn't need them. keep them here for logging purposes
+    // this field is in the response but the library doesn't need it. keep it here for logging purposes
     #[allow(dead_code)]
     token_type: String,
-    #[allow(dead_code)]
-    expires_in: u32,
+}
+
+impl fmt::Debug for SpotifyClientWithSecretRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpotifyClientWithSecretRef")
+            .field("client_id", &self.client_id)
+            .field("access_token", &Redacted)
+            .field("access_token_expiry", &self.access_token_expiry)
+            .finish()
+    }
+}
+
+impl fmt::Debug for SpotifyClientWithSecretBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SpotifyClientWithSecretBuilder");
+        debug
+            .field("client_id", &self.client_id)
+            .field("client_secret", &Redacted);
+
+        #[cfg(feature = "async")]
+        debug
+            .field("http_client", &self.http_client)
+            .field("max_concurrent_requests", &self.max_concurrent_requests);
+
+        #[cfg(feature = "sync")]
+        debug.field("blocking_http_client", &self.blocking_http_client);
+
+        debug
+            .field("base_urls", &self.base_urls)
+            .field("response_cache_capacity", &self.response_cache_capacity)
+            .field("retry_policy", &self.retry_policy)
+            .field("max_rate_limit_retries", &self.max_rate_limit_retries)
+            .field("default_market", &self.default_market)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
+impl fmt::Debug for ClientTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientTokenResponse")
+            .field("access_token", &Redacted)
+            .field("expires_in", &self.expires_in)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
+impl ClientTokenResponse {
+    fn expiry(&self) -> Instant {
+        Instant::now() + Duration::from_secs(self.expires_in.into())
+    }
 }
 
 #[cfg(feature = "async")]
@@ -289,7 +448,11 @@ impl AsyncSpotifyClient {
     where
         S: Into<String>,
     {
-        ImplicitGrantUserClientBuilder::new(redirect_uri.into(), Arc::clone(&self.inner), self.http_client.clone())
+        ImplicitGrantUserClientBuilder::new(
+            redirect_uri.into(),
+            Arc::clone(&self.inner),
+            self.http_client.with_own_response_cache(),
+        )
     }
 
     /// Returns a new builder for an [AuthorizationCodeUserClient](authorization_code::AuthorizationCodeUserClient)
@@ -303,7 +466,7 @@ impl AsyncSpotifyClient {
         AsyncAuthorizationCodeUserClientBuilder::new(
             redirect_uri.into(),
             self.inner.client_id.clone(),
-            self.http_client.clone(),
+            self.http_client.with_own_response_cache(),
         )
         .with_pkce()
     }
@@ -321,7 +484,7 @@ impl AsyncSpotifyClient {
         S: Into<String>,
     {
         AsyncAuthorizationCodeUserClient::new_with_refresh_token(
-            self.http_client.clone(),
+            self.http_client.with_own_response_cache(),
             refresh_token.into(),
             Some(self.inner.client_id.clone()),
         )
@@ -342,7 +505,11 @@ impl SyncSpotifyClient {
     where
         S: Into<String>,
     {
-        ImplicitGrantUserClientBuilder::new(redirect_uri.into(), Arc::clone(&self.inner), self.http_client.clone())
+        ImplicitGrantUserClientBuilder::new(
+            redirect_uri.into(),
+            Arc::clone(&self.inner),
+            self.http_client.with_own_response_cache(),
+        )
     }
 
     /// Returns a new builder for an [AuthorizationCodeUserClient](authorization_code::AuthorizationCodeUserClient)
@@ -356,7 +523,7 @@ impl SyncSpotifyClient {
         SyncAuthorizationCodeUserClientBuilder::new(
             redirect_uri.into(),
             self.inner.client_id.clone(),
-            self.http_client.clone(),
+            self.http_client.with_own_response_cache(),
         )
         .with_pkce()
     }
@@ -374,7 +541,7 @@ impl SyncSpotifyClient {
         S: Into<String>,
     {
         SyncAuthorizationCodeUserClient::new_with_refresh_token(
-            self.http_client.clone(),
+            self.http_client.with_own_response_cache(),
             refresh_token.into(),
             Some(self.inner.client_id.clone()),
         )
@@ -392,7 +559,7 @@ impl AsyncSpotifyClientWithSecret {
         AsyncAuthorizationCodeUserClientBuilder::new(
             redirect_uri.into(),
             self.inner.client_id.clone(),
-            self.http_client.clone(),
+            self.http_client.with_own_response_cache(),
         )
     }
 
@@ -407,8 +574,12 @@ impl AsyncSpotifyClientWithSecret {
     where
         S: Into<String>,
     {
-        AsyncAuthorizationCodeUserClient::new_with_refresh_token(self.http_client.clone(), refresh_token.into(), None)
-            .await
+        AsyncAuthorizationCodeUserClient::new_with_refresh_token(
+            self.http_client.with_own_response_cache(),
+            refresh_token.into(),
+            None,
+        )
+        .await
     }
 }
 
@@ -423,7 +594,7 @@ impl SyncSpotifyClientWithSecret {
         SyncAuthorizationCodeUserClientBuilder::new(
             redirect_uri.into(),
             self.inner.client_id.clone(),
-            self.http_client.clone(),
+            self.http_client.with_own_response_cache(),
         )
     }
 
@@ -438,7 +609,11 @@ impl SyncSpotifyClientWithSecret {
     where
         S: Into<String>,
     {
-        SyncAuthorizationCodeUserClient::new_with_refresh_token(self.http_client.clone(), refresh_token.into(), None)
+        SyncAuthorizationCodeUserClient::new_with_refresh_token(
+            self.http_client.with_own_response_cache(),
+            refresh_token.into(),
+            None,
+        )
     }
 }
 
@@ -450,6 +625,18 @@ impl SpotifyClientBuilder {
     {
         Self {
             client_id: client_id.into(),
+            #[cfg(feature = "async")]
+            http_client: None,
+            #[cfg(feature = "async")]
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            #[cfg(feature = "sync")]
+            blocking_http_client: None,
+            base_urls: private::BaseUrls::default(),
+            response_cache_capacity: None,
+            retry_policy: None,
+            max_rate_limit_retries: DEFAULT_MAX_RATE_LIMIT_RETRIES,
+            default_market: None,
+            user_agent: None,
         }
     }
 
@@ -461,54 +648,240 @@ impl SpotifyClientBuilder {
         SpotifyClientWithSecretBuilder {
             client_id: self.client_id,
             client_secret: client_secret.into(),
+            #[cfg(feature = "async")]
+            http_client: self.http_client,
+            #[cfg(feature = "async")]
+            max_concurrent_requests: self.max_concurrent_requests,
+            #[cfg(feature = "sync")]
+            blocking_http_client: self.blocking_http_client,
+            base_urls: self.base_urls,
+            response_cache_capacity: self.response_cache_capacity,
+            retry_policy: self.retry_policy,
+            max_rate_limit_retries: self.max_rate_limit_retries,
+            default_market: self.default_market,
+            user_agent: self.user_agent,
+        }
+    }
+
+    /// Use the given HTTP client for every request the asynchronous client and the user clients built from it send,
+    /// instead of a new default client. This allows configuring e.g. a proxy, TLS or connection pooling.
+    ///
+    /// The client is kept if the builder is turned into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    #[cfg(feature = "async")]
+    pub fn with_http_client(self, http_client: reqwest::Client) -> Self {
+        Self {
+            http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Set how many requests the asynchronous client and the user clients built from it send concurrently when a
+    /// request on many items is split into multiple requests, such as with
+    /// [`tracks_chunked`](crate::client::UnscopedClient::tracks_chunked). The limit is shared by every clone of the
+    /// client, so sending many such requests at once doesn't send more requests at a time either. Sending too many
+    /// requests at once may cause Spotify to rate limit the client.
+    ///
+    /// Default: 5. Minimum: 1.
+    ///
+    /// The limit is kept if the builder is turned into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    #[cfg(feature = "async")]
+    pub fn max_concurrent_requests(self, max_concurrent_requests: usize) -> Self {
+        Self {
+            max_concurrent_requests,
+            ..self
+        }
+    }
+
+    /// Use the given blocking HTTP client for every request the synchronous client and the user clients built from it
+    /// send, instead of a new default client. This allows configuring e.g. a proxy, TLS or connection pooling.
+    ///
+    /// The client is kept if the builder is turned into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    #[cfg(feature = "sync")]
+    pub fn with_blocking_http_client(self, http_client: reqwest::blocking::Client) -> Self {
+        Self {
+            blocking_http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Send the API requests to the given base URL instead of Spotify's Web API at `https://api.spotify.com/v1/`. This
+    /// is mostly useful for testing an application against a mock server.
+    ///
+    /// The base URL is kept if the builder is turned into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    pub fn api_base_url(self, api_base_url: Url) -> Self {
+        Self {
+            base_urls: private::BaseUrls {
+                api: with_trailing_slash(api_base_url),
+                ..self.base_urls
+            },
+            ..self
+        }
+    }
+
+    /// Send the token requests to, and build the authorize URLs with, the given base URL instead of Spotify's accounts
+    /// service at `https://accounts.spotify.com/`. This is mostly useful for testing an application against a mock
+    /// server.
+    ///
+    /// The base URL is kept if the builder is turned into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    pub fn accounts_base_url(self, accounts_base_url: Url) -> Self {
+        Self {
+            base_urls: private::BaseUrls {
+                accounts: with_trailing_slash(accounts_base_url),
+                ..self.base_urls
+            },
+            ..self
+        }
+    }
+
+    /// Cache the response bodies of up to the given amount of API requests in memory, and send the cached response's
+    /// entity tag (`ETag`) in an `If-None-Match` header when the same request is sent again. If Spotify responds that
+    /// the resource hasn't changed (HTTP 304 Not Modified), the cached response body is used instead. This saves
+    /// bandwidth for applications that poll the same resource. Only `GET` requests whose responses have an entity tag
+    /// are cached. Once the cache is full, the oldest response is evicted from it.
+    ///
+    /// Every user client built from the client gets its own cache of the same capacity, so one user's responses are
+    /// never served to another user. The setting is kept if the builder is turned into a
+    /// [SpotifyClientWithSecretBuilder] with the [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    pub fn enable_response_cache(self, capacity: usize) -> Self {
+        Self {
+            response_cache_capacity: Some(capacity),
+            ..self
+        }
+    }
+
+    /// Retry requests that fail because of transient errors, such as Spotify's servers being temporarily unavailable,
+    /// according to the given policy. See [RetryPolicy] for which requests are retried. By default, such requests
+    /// aren't retried.
+    ///
+    /// The policy is shared by the client and all the user clients built from it. It is kept if the builder is turned
+    /// into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    pub fn retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(retry_policy),
+            ..self
+        }
+    }
+
+    /// The maximum amount of times a request is retried after being rate limited, if
+    /// [reacting to rate limits](BaseRequestBuilder::react_to_rate_limit) is enabled. Once the retries are exhausted,
+    /// the request fails with [Error::RateLimit](crate::error::Error::RateLimit). A single request may override the
+    /// maximum with its [`max_rate_limit_retries`-function](BaseRequestBuilder::max_rate_limit_retries).
+    ///
+    /// Default: 3.
+    ///
+    /// The maximum is shared by the client and all the user clients built from it. It is kept if the builder is turned
+    /// into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    pub fn max_rate_limit_retries(self, max_rate_limit_retries: u32) -> Self {
+        Self {
+            max_rate_limit_retries,
+            ..self
+        }
+    }
+
+    /// Apply the given market to every request that accepts a market but isn't given one, such as
+    /// [track](UnscopedClient::track), [tracks](UnscopedClient::tracks) and [albums](UnscopedClient::albums). A market
+    /// given to a request with its `market`-function overrides the default market. By default, no market is applied.
+    ///
+    /// The market is shared by the client and all the user clients built from it. It is kept if the builder is turned
+    /// into a [SpotifyClientWithSecretBuilder] with the
+    /// [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    ///
+    /// Either a [CountryCode](crate::model::CountryCode) or a [Market] may be given.
+    pub fn default_market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        Self {
+            default_market: Some(market.into()),
+            ..self
+        }
+    }
+
+    /// Send the given `User-Agent` header with every request, including the requests for access tokens. Spotify
+    /// recommends a user agent that describes the application. If the user agent isn't a valid header value, such as
+    /// when it contains a newline, the default user agent is sent instead.
+    ///
+    /// Default: `ferrispot/<version>`, such as `ferrispot/0.4.1`. If a custom HTTP client is given with
+    /// `with_http_client` or `with_blocking_http_client`, the HTTP client's own user agent is sent by default instead.
+    ///
+    /// The user agent is shared by the client and all the user clients built from it. It is kept if the builder is
+    /// turned into a [SpotifyClientWithSecretBuilder] with the [`client_secret`-function](SpotifyClientBuilder::client_secret).
+    pub fn user_agent<S>(self, user_agent: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            user_agent: Some(user_agent.into()),
+            ..self
         }
     }
 
     /// Finalize the builder and return an asynchronous Spotify client.
     #[cfg(feature = "async")]
     pub fn build_async(self) -> AsyncSpotifyClient {
-        self.build_client()
+        let user_agent = user_agent_header(self.user_agent, self.http_client.is_some());
+        let http_client = AsyncClient::from_http_client(
+            self.http_client.unwrap_or_default(),
+            None,
+            self.base_urls,
+            self.max_concurrent_requests,
+            self.response_cache_capacity.map(ResponseCache::new),
+            self.retry_policy,
+            self.max_rate_limit_retries,
+            self.default_market,
+            user_agent,
+        );
+        Self::build_client(self.client_id, http_client)
     }
 
     /// Finalize the builder and return a synchronous Spotify client.
     #[cfg(feature = "sync")]
     pub fn build_sync(self) -> SyncSpotifyClient {
-        self.build_client()
+        let user_agent = user_agent_header(self.user_agent, self.blocking_http_client.is_some());
+        let http_client = SyncClient::from_http_client(
+            self.blocking_http_client.unwrap_or_default(),
+            None,
+            self.base_urls,
+            self.response_cache_capacity.map(ResponseCache::new),
+            self.retry_policy,
+            self.max_rate_limit_retries,
+            self.default_market,
+            user_agent,
+        );
+        Self::build_client(self.client_id, http_client)
     }
 
-    fn build_client<C>(self) -> SpotifyClient<C>
+    fn build_client<C>(client_id: String, http_client: C) -> SpotifyClient<C>
     where
         C: private::HttpClient + Clone,
     {
         SpotifyClient {
-            inner: Arc::new(SpotifyClientRef {
-                client_id: self.client_id,
-            }),
-            http_client: C::new(),
+            inner: Arc::new(SpotifyClientRef { client_id }),
+            http_client,
         }
     }
 }
 
 impl SpotifyClientWithSecretBuilder {
-    fn get_default_headers(&self) -> HeaderMap {
-        let mut default_headers = header::HeaderMap::new();
-
-        // insert default client credentials header. the same HTTP client will be used with future user clients that
-        // require the authorization in order to exchange an authorization code for access and refresh tokens
-        // when finalizing the clients
-        default_headers.insert(
-            header::AUTHORIZATION,
-            build_authorization_header(&self.client_id, &self.client_secret)
-                .parse()
-                // this can only fail if the header value contains non-ASCII characters, which shouldn't happen since
-                // the given header value is in base64
-                .expect(
-                    "failed to insert authorization header into header map: non-ASCII characters in value (this is \
-                     likely a bug)",
-                ),
-        );
-
-        default_headers
+    fn get_client_authorization(&self) -> HeaderValue {
+        // the same HTTP client will be used with future user clients that require the client credentials authorization
+        // in order to exchange an authorization code for access and refresh tokens when finalizing the clients
+        let mut header: HeaderValue = build_authorization_header(&self.client_id, &self.client_secret)
+            .parse()
+            // this can only fail if the header value contains non-ASCII characters, which shouldn't happen since the
+            // given header value is in base64
+            .expect("failed to build authorization header: non-ASCII characters in value (this is likely a bug)");
+
+        // keeps the header's value out of the HTTP client's debug output
+        header.set_sensitive(true);
+        header
     }
 
     fn build_client<C>(self, token_response: ClientTokenResponse, http_client: C) -> SpotifyClientWithSecret<C>
@@ -517,11 +890,14 @@ impl SpotifyClientWithSecretBuilder {
     {
         debug!("Got token response for client credentials flow: {:?}", token_response);
 
+        let access_token_expiry = token_response.expiry();
+
         SpotifyClientWithSecret {
             inner: Arc::new(SpotifyClientWithSecretRef {
                 client_id: self.client_id,
                 // client_secret: self.client_secret,
                 access_token: RwLock::new(token_response.access_token),
+                access_token_expiry: RwLock::new(access_token_expiry),
             }),
             http_client,
         }
@@ -532,19 +908,24 @@ impl SpotifyClientWithSecretBuilder {
     /// Request an access token from Spotify using the client credentials flow and return an asynchronous Spotify
     /// client.
     #[cfg(feature = "async")]
-    pub async fn build_async(self) -> Result<AsyncSpotifyClientWithSecret> {
+    pub async fn build_async(mut self) -> Result<AsyncSpotifyClientWithSecret> {
         debug!("Requesting access token for client credentials flow");
 
-        let http_client = AsyncClient(
-            reqwest::Client::builder()
-                .default_headers(self.get_default_headers())
-                .build()
-                // this can only fail due to a system error or system misconfiguration
-                .expect("failed to build HTTP client: system error or system misconfiguration"),
+        let user_agent = user_agent_header(self.user_agent.take(), self.http_client.is_some());
+        let http_client = AsyncClient::from_http_client(
+            self.http_client.take().unwrap_or_default(),
+            Some(self.get_client_authorization()),
+            self.base_urls.clone(),
+            self.max_concurrent_requests,
+            self.response_cache_capacity.map(ResponseCache::new),
+            self.retry_policy.take(),
+            self.max_rate_limit_retries,
+            self.default_market,
+            user_agent,
         );
 
         let response = http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(CLIENT_CREDENTIALS_TOKEN_REQUEST_FORM)
             .send()
             .await?;
@@ -561,19 +942,23 @@ impl SpotifyClientWithSecretBuilder {
     /// Request an access token from Spotify using the client credentials flow and return a synchronous Spotify
     /// client.
     #[cfg(feature = "sync")]
-    pub fn build_sync(self) -> Result<SyncSpotifyClientWithSecret> {
+    pub fn build_sync(mut self) -> Result<SyncSpotifyClientWithSecret> {
         debug!("Requesting access token for client credentials flow");
 
-        let http_client = SyncClient(
-            reqwest::blocking::Client::builder()
-                .default_headers(self.get_default_headers())
-                .build()
-                // this can only fail due to a system error or system misconfiguration
-                .expect("failed to build blocking HTTP client: system error or system misconfiguration"),
+        let user_agent = user_agent_header(self.user_agent.take(), self.blocking_http_client.is_some());
+        let http_client = SyncClient::from_http_client(
+            self.blocking_http_client.take().unwrap_or_default(),
+            Some(self.get_client_authorization()),
+            self.base_urls.clone(),
+            self.response_cache_capacity.map(ResponseCache::new),
+            self.retry_policy.take(),
+            self.max_rate_limit_retries,
+            self.default_market,
+            user_agent,
         );
 
         let response = http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(CLIENT_CREDENTIALS_TOKEN_REQUEST_FORM)
             .send()?;
 
@@ -586,16 +971,97 @@ impl SpotifyClientWithSecretBuilder {
 
 impl<C> crate::private::Sealed for SpotifyClientWithSecret<C> where C: private::HttpClient + Clone {}
 
+impl<C> private::GrantedScopes for SpotifyClientWithSecret<C> where C: private::HttpClient + Clone {}
+
+impl<C> private::ApiBaseUrl for SpotifyClientWithSecret<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn api_base_url(&self) -> &Url {
+        &self.http_client.base_urls().api
+    }
+}
+
+impl<C> private::RetryAfterState for SpotifyClientWithSecret<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn retry_after_state(&self) -> &RwLock<Option<Duration>> {
+        self.http_client.last_retry_after()
+    }
+
+    fn max_rate_limit_retries(&self) -> u32 {
+        self.http_client.max_rate_limit_retries()
+    }
+}
+
+impl<C> private::ResponseCacheState for SpotifyClientWithSecret<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn response_cache(&self) -> Option<&ResponseCache> {
+        self.http_client.response_cache()
+    }
+}
+
+impl<C> private::RetryPolicyState for SpotifyClientWithSecret<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.http_client.retry_policy()
+    }
+}
+
+impl<C> private::DefaultMarketState for SpotifyClientWithSecret<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn default_market(&self) -> Option<Market> {
+        self.http_client.default_market()
+    }
+}
+
 impl<C> SpotifyClientWithSecret<C>
 where
     C: private::HttpClient + Clone,
 {
+    /// Returns how long the client's current access token is valid for. If the token has already expired, returns a
+    /// zero duration.
+    ///
+    /// The client automatically requests a new access token before sending a request if the current one has expired or
+    /// is about to expire.
+    pub fn token_expires_in(&self) -> Duration {
+        self.inner
+            .access_token_expiry
+            .read()
+            .expect("access token expiry rwlock poisoned")
+            .saturating_duration_since(Instant::now())
+    }
+
+    fn is_access_token_expired(&self) -> bool {
+        self.token_expires_in() <= ACCESS_TOKEN_EXPIRY_SKEW
+    }
+
     fn save_access_token(&self, token_response: ClientTokenResponse) {
         debug!("Got token response for client credentials flow: {:?}", token_response);
+
+        *self
+            .inner
+            .access_token_expiry
+            .write()
+            .expect("access token expiry rwlock poisoned") = token_response.expiry();
         *self.inner.access_token.write().expect("access token rwlock poisoned") = token_response.access_token;
     }
 }
 
+#[cfg(feature = "async")]
+impl private::ConcurrentRequestLimit for AsyncSpotifyClientWithSecret {
+    fn concurrent_requests(&self) -> &tokio::sync::Semaphore {
+        self.http_client.concurrent_requests()
+    }
+}
+
 #[cfg(feature = "async")]
 impl private::BuildHttpRequestAsync for AsyncSpotifyClientWithSecret {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
@@ -634,7 +1100,7 @@ impl AccessTokenRefreshAsync for AsyncSpotifyClientWithSecret {
         // instead of the access token
         let response = self
             .http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(CLIENT_CREDENTIALS_TOKEN_REQUEST_FORM)
             .send()
             .await?;
@@ -659,7 +1125,7 @@ impl AccessTokenRefreshSync for SyncSpotifyClientWithSecret {
         // instead of the access token
         let response = self
             .http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(CLIENT_CREDENTIALS_TOKEN_REQUEST_FORM)
             .send()?;
 
@@ -678,6 +1144,10 @@ impl private::AccessTokenExpiryAsync for AsyncSpotifyClientWithSecret {
         self.refresh_access_token().await?;
         Ok(private::AccessTokenExpiryResult::Ok)
     }
+
+    fn is_access_token_expired(&self) -> bool {
+        SpotifyClientWithSecret::is_access_token_expired(self)
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -686,6 +1156,19 @@ impl private::AccessTokenExpirySync for SyncSpotifyClientWithSecret {
         self.refresh_access_token()?;
         Ok(private::AccessTokenExpiryResult::Ok)
     }
+
+    fn is_access_token_expired(&self) -> bool {
+        SpotifyClientWithSecret::is_access_token_expired(self)
+    }
+}
+
+// base URLs without a trailing slash would have their last path segment replaced when joined with an endpoint
+fn with_trailing_slash(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+
+    url
 }
 
 fn build_authorization_header(client_id: &str, client_secret: &str) -> String {
@@ -701,10 +1184,10 @@ fn build_authorization_header(client_id: &str, client_secret: &str) -> String {
 #[cfg(feature = "async")]
 async fn extract_authentication_error_async(response: reqwest::Response) -> Result<reqwest::Response> {
     if let StatusCode::BAD_REQUEST = response.status() {
-        let error_response: AuthenticationErrorResponse = response.json().await?;
+        let error_response: AuthError = response.json().await?;
         debug!("Authentication error response: {error_response:?}");
 
-        Err(error_response.into_unhandled_error())
+        Err(Error::Auth(error_response))
     } else {
         Ok(response)
     }
@@ -715,10 +1198,10 @@ async fn extract_authentication_error_async(response: reqwest::Response) -> Resu
 #[cfg(feature = "sync")]
 fn extract_authentication_error_sync(response: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
     if let StatusCode::BAD_REQUEST = response.status() {
-        let error_response: AuthenticationErrorResponse = response.json()?;
+        let error_response: AuthError = response.json()?;
         debug!("Authentication error response: {error_response:?}");
 
-        Err(error_response.into_unhandled_error())
+        Err(Error::Auth(error_response))
     } else {
         Ok(response)
     }
@@ -726,14 +1209,14 @@ fn extract_authentication_error_sync(response: reqwest::blocking::Response) -> R
 
 /// Sleep for the specified amount of time by blocking the current thread.
 #[cfg(feature = "sync")]
-fn rate_limit_sleep_sync(sleep_time: u64) -> Result<()> {
-    std::thread::sleep(std::time::Duration::from_secs(sleep_time));
+fn rate_limit_sleep_sync(sleep_time: Duration) -> Result<()> {
+    std::thread::sleep(sleep_time);
     Ok(())
 }
 
 /// Return a rate limit error since no sleep utility has been enabled.
 #[cfg(all(feature = "async", not(feature = "tokio_sleep"), not(feature = "async_std_sleep")))]
-async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
+async fn rate_limit_sleep_async(sleep_time: Duration) -> Result<()> {
     Err(crate::error::Error::RateLimit(sleep_time))
 }
 
@@ -741,22 +1224,353 @@ async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
 // tokio sleep
 /// Sleep for the specified amount of time using tokio's sleep function.
 #[cfg(all(feature = "async", feature = "tokio_sleep"))]
-async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
-    tokio::time::sleep(std::time::Duration::from_secs(sleep_time)).await;
+async fn rate_limit_sleep_async(sleep_time: Duration) -> Result<()> {
+    tokio::time::sleep(sleep_time).await;
     Ok(())
 }
 
 /// Sleep for the specified amount of time using async_std's sleep function.
 #[cfg(all(feature = "async", feature = "async_std_sleep", not(feature = "tokio_sleep")))]
-async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
-    async_std::task::sleep(std::time::Duration::from_secs(sleep_time)).await;
+async fn rate_limit_sleep_async(sleep_time: Duration) -> Result<()> {
+    async_std::task::sleep(sleep_time).await;
     Ok(())
 }
 
+/// Sleep for the specified amount of time before retrying a failed request by blocking the current thread.
+#[cfg(feature = "sync")]
+fn retry_sleep_sync(sleep_time: Duration) -> bool {
+    std::thread::sleep(sleep_time);
+    true
+}
+
+/// Don't sleep and return false, since no sleep utility has been enabled and the request can't be retried.
+#[cfg(all(feature = "async", not(feature = "tokio_sleep"), not(feature = "async_std_sleep")))]
+async fn retry_sleep_async(_sleep_time: Duration) -> bool {
+    false
+}
+
+/// Sleep for the specified amount of time before retrying a failed request using tokio's sleep function.
+#[cfg(all(feature = "async", feature = "tokio_sleep"))]
+async fn retry_sleep_async(sleep_time: Duration) -> bool {
+    tokio::time::sleep(sleep_time).await;
+    true
+}
+
+/// Sleep for the specified amount of time before retrying a failed request using async_std's sleep function.
+#[cfg(all(feature = "async", feature = "async_std_sleep", not(feature = "tokio_sleep")))]
+async fn retry_sleep_async(sleep_time: Duration) -> bool {
+    async_std::task::sleep(sleep_time).await;
+    true
+}
+
+/// Returns the given user agent as a header value. If none is given or the given one is invalid, returns the default
+/// user agent, unless a custom HTTP client is used, in which case the HTTP client's own user agent is used instead.
+fn user_agent_header(user_agent: Option<String>, custom_http_client: bool) -> Option<HeaderValue> {
+    user_agent
+        .and_then(|user_agent| {
+            HeaderValue::try_from(user_agent)
+                .map_err(|_| warn!("Invalid user agent, using the default user agent instead"))
+                .ok()
+        })
+        .or_else(|| (!custom_http_client).then(|| HeaderValue::from_static(DEFAULT_USER_AGENT)))
+}
+
 fn map_client_authentication_error(err: Error) -> Error {
-    if let Error::UnhandledAuthenticationError(AuthenticationErrorKind::InvalidClient, description) = err {
-        Error::InvalidClient(description)
+    if let Error::Auth(AuthError {
+        error: AuthenticationErrorKind::InvalidClient,
+        error_description,
+    }) = err
+    {
+        Error::InvalidClient(error_description)
     } else {
         err
     }
 }
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        private::{AsyncClient, HttpClient},
+        request_builder::{AsyncRequestBuilder, BaseRequestBuilderContainer, RequestBuilder},
+        test_util::{
+            capture_logs, captured_logs, json_response, serve_requests, user_client_from, TRACK_ID, TRACK_JSON,
+        },
+        AsyncSpotifyClientWithSecret, ClientTokenResponse, SpotifyClientBuilder,
+    };
+    use crate::{
+        client::{AccessTokenRefreshAsync, UnscopedClient},
+        model::{
+            id::{Id, IdFromBare, IdTrait},
+            track::NonLocalTrackInformation,
+            user::PublicUser,
+        },
+    };
+
+    fn token_response(access_token: &str, expires_in: u32) -> ClientTokenResponse {
+        ClientTokenResponse {
+            access_token: access_token.to_owned(),
+            expires_in,
+            token_type: "Bearer".to_owned(),
+        }
+    }
+
+    fn client_with_token(expires_in: u32) -> AsyncSpotifyClientWithSecret {
+        SpotifyClientBuilder::new("client ID")
+            .client_secret("client secret")
+            .build_client(token_response("access token", expires_in), AsyncClient::new())
+    }
+
+    #[test]
+    fn fresh_access_token_is_not_expired() {
+        let client = client_with_token(3600);
+
+        assert!(!client.is_access_token_expired());
+        assert!(client.token_expires_in() > Duration::from_secs(3590));
+        assert!(client.token_expires_in() <= Duration::from_secs(3600));
+    }
+
+    #[tokio::test]
+    async fn expired_access_token_is_refreshed_once() {
+        let mut token_requests = 0;
+        let (url, server) = serve_requests(3, move |request| {
+            if request.path == "/api/token" {
+                token_requests += 1;
+                // the first token expires immediately, so the next request has to refresh it
+                let expires_in = if token_requests == 1 { 0 } else { 3600 };
+
+                json_response(
+                    "200 OK",
+                    &format!(
+                        r#"{{"access_token":"access token {token_requests}","token_type":"Bearer","expires_in":{expires_in}}}"#
+                    ),
+                )
+            } else {
+                json_response("200 OK", TRACK_JSON)
+            }
+        });
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .api_base_url(url.parse().unwrap())
+            .accounts_base_url(url.parse().unwrap())
+            .client_secret("client secret")
+            .build_async()
+            .await
+            .unwrap();
+
+        assert!(client.is_access_token_expired());
+
+        client
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .send_async()
+            .await
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        // the initial token, the refreshed token and the track
+        let paths = requests.iter().map(|request| request.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(paths, ["/api/token", "/api/token", &format!("/tracks/{TRACK_ID}")]);
+        assert_eq!(
+            requests[2].header("authorization").as_deref(),
+            Some("Bearer access token 2")
+        );
+        assert!(!client.is_access_token_expired());
+    }
+
+    #[test]
+    fn access_token_about_to_expire_is_considered_expired() {
+        // within the expiry skew window
+        let client = client_with_token(5);
+
+        assert!(client.token_expires_in() > Duration::ZERO);
+        assert!(client.is_access_token_expired());
+    }
+
+    #[tokio::test]
+    async fn custom_http_client_is_used() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "200 OK",
+                r#"{"display_name":null,"external_urls":{},"id":"user","type":"user"}"#,
+            )
+        });
+
+        let http_client = reqwest::Client::builder().user_agent("custom agent").build().unwrap();
+        let client = user_client_from(SpotifyClientBuilder::new("client ID").with_http_client(http_client));
+
+        RequestBuilder::<_, PublicUser>::new(reqwest::Method::GET, url, client)
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].header("user-agent").as_deref(), Some("custom agent"));
+    }
+
+    #[tokio::test]
+    async fn user_agent_is_sent() {
+        let (url, server) = serve_requests(3, |_| json_response("200 OK", TRACK_JSON));
+        let track = Id::from_bare(TRACK_ID).unwrap();
+
+        user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(url.parse().unwrap()))
+            .track(track.as_borrowed())
+            .send_async()
+            .await
+            .unwrap();
+
+        user_client_from(
+            SpotifyClientBuilder::new("client ID")
+                .api_base_url(url.parse().unwrap())
+                .user_agent("my-app/1.0"),
+        )
+        .track(track.as_borrowed())
+        .send_async()
+        .await
+        .unwrap();
+
+        // the user agent overrides the custom HTTP client's own user agent
+        let http_client = reqwest::Client::builder().user_agent("custom agent").build().unwrap();
+        user_client_from(
+            SpotifyClientBuilder::new("client ID")
+                .api_base_url(url.parse().unwrap())
+                .with_http_client(http_client)
+                .user_agent("my-app/1.0"),
+        )
+        .track(track)
+        .send_async()
+        .await
+        .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].header("user-agent"),
+            Some(format!("ferrispot/{}", env!("CARGO_PKG_VERSION")))
+        );
+        assert_eq!(requests[1].header("user-agent").as_deref(), Some("my-app/1.0"));
+        assert_eq!(requests[2].header("user-agent").as_deref(), Some("my-app/1.0"));
+    }
+
+    #[tokio::test]
+    async fn secrets_are_redacted_from_logs() {
+        const ACCESS_TOKEN: &str = "redacted-access-token";
+        const CLIENT_SECRET: &str = "redacted-client-secret";
+
+        capture_logs();
+
+        let (url, server) = serve_requests(2, |request| {
+            if request.path == "/api/token" {
+                json_response(
+                    "200 OK",
+                    &format!(r#"{{"access_token":"{ACCESS_TOKEN}","token_type":"Bearer","expires_in":3600}}"#),
+                )
+            } else {
+                json_response("200 OK", TRACK_JSON)
+            }
+        });
+
+        let builder = SpotifyClientBuilder::new("client ID")
+            .api_base_url(url.parse().unwrap())
+            .accounts_base_url(url.parse().unwrap())
+            .client_secret(CLIENT_SECRET);
+        let debug_builder = format!("{builder:?}");
+
+        let client = builder.build_async().await.unwrap();
+        client
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .send_async()
+            .await
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        let authorization = requests[0].header("authorization").unwrap();
+        let client_credentials = authorization.trim_start_matches("Basic ");
+        let output = format!("{}\n{debug_builder}\n{client:?}", captured_logs().join("\n"));
+
+        assert!(output.contains("Got token response for client credentials flow"));
+        for secret in [ACCESS_TOKEN, CLIENT_SECRET, client_credentials] {
+            assert!(!output.contains(secret), "{secret} found in output");
+        }
+    }
+
+    #[tokio::test]
+    async fn access_token_is_refreshed_manually() {
+        let mut token_requests = 0;
+        let (url, server) = serve_requests(3, move |request| {
+            if request.path == "/api/token" {
+                token_requests += 1;
+                let expires_in = 3600 * token_requests;
+
+                json_response(
+                    "200 OK",
+                    &format!(
+                        r#"{{"access_token":"access token {token_requests}","token_type":"Bearer","expires_in":{expires_in}}}"#
+                    ),
+                )
+            } else {
+                json_response("200 OK", TRACK_JSON)
+            }
+        });
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .api_base_url(url.parse().unwrap())
+            .accounts_base_url(url.parse().unwrap())
+            .client_secret("client secret")
+            .build_async()
+            .await
+            .unwrap();
+
+        client.refresh_access_token().await.unwrap();
+        client
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .send_async()
+            .await
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[1].path, "/api/token");
+        assert_eq!(
+            requests[2].header("authorization").as_deref(),
+            Some("Bearer access token 2")
+        );
+        assert!(client.token_expires_in() > Duration::from_secs(3600));
+    }
+
+    #[tokio::test]
+    async fn base_urls_are_configurable() {
+        let (url, server) = serve_requests(2, |request| {
+            if request.path == "/accounts/api/token" {
+                json_response(
+                    "200 OK",
+                    r#"{"access_token":"access token","token_type":"Bearer","expires_in":3600}"#,
+                )
+            } else {
+                json_response("200 OK", TRACK_JSON)
+            }
+        });
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .api_base_url(format!("{url}api").parse().unwrap())
+            .accounts_base_url(format!("{url}accounts").parse().unwrap())
+            .client_secret("client secret")
+            .build_async()
+            .await
+            .unwrap();
+
+        let track = client
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .send_async()
+            .await
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].path, "/accounts/api/token");
+        assert!(requests[0].header("authorization").unwrap().starts_with("Basic "));
+        assert_eq!(requests[1].path, format!("/api/tracks/{TRACK_ID}"));
+        assert_eq!(
+            requests[1].header("authorization").as_deref(),
+            Some("Bearer access token")
+        );
+        assert_eq!(track.id().as_str(), TRACK_ID);
+    }
+}
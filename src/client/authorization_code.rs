@@ -92,17 +92,23 @@
 //! // to the documentation above
 //! # }
 
-use std::sync::{Arc, RwLock};
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
 
 use base64::Engine;
 use log::debug;
 use rand::{distributions::Alphanumeric, Rng};
 use reqwest::{IntoUrl, Method, Url};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sha2::Digest;
 
 use super::{
-    private, ACCOUNTS_API_TOKEN_ENDPOINT, ACCOUNTS_AUTHORIZE_ENDPOINT, PKCE_VERIFIER_LENGTH, RANDOM_STATE_LENGTH,
+    private, response_cache::ResponseCache, RetryPolicy, ACCESS_TOKEN_EXPIRY_SKEW, ACCOUNTS_AUTHORIZE_ENDPOINT,
+    PKCE_VERIFIER_LENGTH, RANDOM_STATE_LENGTH,
 };
 #[cfg(feature = "async")]
 use super::{private::AsyncClient, AccessTokenRefreshAsync};
@@ -110,8 +116,12 @@ use super::{private::AsyncClient, AccessTokenRefreshAsync};
 use super::{private::SyncClient, AccessTokenRefreshSync};
 use crate::{
     error::{Error, Result},
-    model::error::AuthenticationErrorKind,
-    scope::ToScopesString,
+    model::{
+        error::{AuthError, AuthenticationErrorKind},
+        Market,
+    },
+    scope::{self, Scope, ToScopesString},
+    util::redacted::Redacted,
 };
 
 /// Type alias for an asynchronous authorization code user client. See
@@ -165,11 +175,35 @@ where
     http_client: C,
 }
 
-#[derive(Debug)]
 struct AuthorizationCodeUserClientRef {
     access_token: RwLock<String>,
     refresh_token: RwLock<String>,
+    access_token_expiry: RwLock<SystemTime>,
+    granted_scopes: RwLock<Option<HashSet<Scope>>>,
     client_id: Option<String>,
+    token_refresh_callback: RwLock<Option<TokenRefreshCallback>>,
+}
+
+type TokenRefreshCallback = Arc<dyn Fn(&TokenData) + Send + Sync>;
+
+/// A snapshot of an [AuthorizationCodeUserClient]'s tokens, returned by its
+/// [`token_data`-function](AuthorizationCodeUserClient::token_data).
+///
+/// The struct implements `Serialize` and `Deserialize` so it may be persisted in whichever way suits the application.
+/// A new client may later be built from the saved refresh token with the
+/// [`authorization_code_client_with_refresh_token`-function](crate::client::SpotifyClientWithSecret::authorization_code_client_with_refresh_token)
+/// or the [`authorization_code_client_with_refresh_token_and_pkce`-function](crate::client::SpotifyClient::authorization_code_client_with_refresh_token_and_pkce),
+/// without having to direct the user through the authorization flow again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenData {
+    /// The access token.
+    pub access_token: String,
+    /// The refresh token.
+    pub refresh_token: String,
+    /// The point in time the access token expires at.
+    pub expires_at: SystemTime,
+    /// The scopes the user has granted to the application, if known.
+    pub scopes: Option<HashSet<Scope>>,
 }
 
 /// An incomplete authorization code user client.
@@ -207,34 +241,74 @@ where
     http_client: C,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct AuthorizeUserTokenResponse {
     access_token: String,
     refresh_token: String,
-
-    // these fields are in the response but the library doesn't need them. keep them here for logging purposes
-    #[allow(dead_code)]
     scope: Option<String>,
-    #[allow(dead_code)]
     expires_in: u32,
+
+    // this field is in the response but the library doesn't need it. keep it here for logging purposes
     #[allow(dead_code)]
     token_type: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct RefreshUserTokenResponse {
     access_token: String,
     refresh_token: Option<String>,
-
-    // these fields are in the response but the library doesn't need them. keep them here for logging purposes
-    #[allow(dead_code)]
     scope: Option<String>,
-    #[allow(dead_code)]
     expires_in: u32,
+
+    // this field is in the response but the library doesn't need it. keep it here for logging purposes
     #[allow(dead_code)]
     token_type: String,
 }
 
+impl fmt::Debug for AuthorizationCodeUserClientRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthorizationCodeUserClientRef")
+            .field("access_token", &Redacted)
+            .field("refresh_token", &Redacted)
+            .field("access_token_expiry", &self.access_token_expiry)
+            .field("granted_scopes", &self.granted_scopes)
+            .field("client_id", &self.client_id)
+            .field(
+                "token_refresh_callback",
+                &self
+                    .token_refresh_callback
+                    .read()
+                    .expect("token refresh callback rwlock poisoned")
+                    .is_some(),
+            )
+            .finish()
+    }
+}
+
+impl fmt::Debug for AuthorizeUserTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthorizeUserTokenResponse")
+            .field("access_token", &Redacted)
+            .field("refresh_token", &Redacted)
+            .field("scope", &self.scope)
+            .field("expires_in", &self.expires_in)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
+impl fmt::Debug for RefreshUserTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshUserTokenResponse")
+            .field("access_token", &Redacted)
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| Redacted))
+            .field("scope", &self.scope)
+            .field("expires_in", &self.expires_in)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
 impl<C> AuthorizationCodeUserClient<C>
 where
     C: private::HttpClient + Clone,
@@ -251,18 +325,23 @@ where
         );
 
         let refresh_token = token_response.refresh_token.unwrap_or(refresh_token);
+        let granted_scopes = token_response.scope.as_deref().map(scope::parse_scopes_string);
 
         Self {
             inner: Arc::new(AuthorizationCodeUserClientRef {
                 access_token: RwLock::new(token_response.access_token),
                 refresh_token: RwLock::new(refresh_token),
+                access_token_expiry: RwLock::new(access_token_expiry(token_response.expires_in)),
+                granted_scopes: RwLock::new(granted_scopes),
                 client_id,
+                token_refresh_callback: RwLock::new(None),
             }),
             http_client,
         }
     }
 
-    /// Returns the current refresh token.
+    /// Returns the current refresh token. If Spotify rotates the refresh token when the access token is refreshed, the
+    /// new refresh token is returned. See [on_token_refresh](Self::on_token_refresh) for being notified of new tokens.
     ///
     /// The refresh token may be saved and reused later when creating a new client with the
     /// [`authorization_code_client_with_refresh_token`-function](crate::client::SpotifyClientWithSecret::authorization_code_client_with_refresh_token)
@@ -277,6 +356,72 @@ where
             .to_owned()
     }
 
+    /// Registers a callback that is called with the client's new tokens every time its access token is refreshed,
+    /// either automatically or manually. Replaces any previously registered callback in the client and all its clones.
+    ///
+    /// Spotify may rotate the refresh token when refreshing the access token, after which the previous refresh token
+    /// may stop working. The callback lets the application persist the current tokens whenever they change.
+    ///
+    /// The callback is called on the thread or task that refreshed the token, so it shouldn't block for long.
+    pub fn on_token_refresh<F>(&self, callback: F)
+    where
+        F: Fn(&TokenData) + Send + Sync + 'static,
+    {
+        *self
+            .inner
+            .token_refresh_callback
+            .write()
+            .expect("token refresh callback rwlock poisoned") = Some(Arc::new(callback));
+    }
+
+    /// Returns a snapshot of the client's current tokens, their expiry and the granted scopes.
+    ///
+    /// The returned [TokenData] may be persisted and its refresh token reused later to build a new client. This
+    /// function clones the internal tokens.
+    pub fn token_data(&self) -> TokenData {
+        TokenData {
+            access_token: self
+                .inner
+                .access_token
+                .read()
+                .expect("access token rwlock poisoned")
+                .to_owned(),
+            refresh_token: self.get_refresh_token(),
+            expires_at: *self
+                .inner
+                .access_token_expiry
+                .read()
+                .expect("access token expiry rwlock poisoned"),
+            scopes: self.granted_scopes(),
+        }
+    }
+
+    fn is_access_token_expired(&self) -> bool {
+        let expiry = *self
+            .inner
+            .access_token_expiry
+            .read()
+            .expect("access token expiry rwlock poisoned");
+
+        expiry
+            .duration_since(SystemTime::now())
+            .map_or(true, |expires_in| expires_in <= ACCESS_TOKEN_EXPIRY_SKEW)
+    }
+
+    /// Returns the scopes the user has granted to the application, if Spotify reported them when the access token was
+    /// last requested.
+    ///
+    /// Calling an endpoint that requires a scope that isn't in this set fails with
+    /// [Error::MissingScope](crate::error::Error::MissingScope) without sending the request. If the granted scopes are
+    /// unknown, every request is sent and Spotify decides whether the required scopes have been granted.
+    pub fn granted_scopes(&self) -> Option<HashSet<Scope>> {
+        self.inner
+            .granted_scopes
+            .read()
+            .expect("granted scopes rwlock poisoned")
+            .clone()
+    }
+
     fn update_access_and_refresh_tokens(&self, token_response: RefreshUserTokenResponse) {
         debug!(
             "Got token response for refreshing authorization code flow tokens: {:?}",
@@ -284,10 +429,35 @@ where
         );
 
         *self.inner.access_token.write().expect("access token rwlock poisoned") = token_response.access_token;
+        *self
+            .inner
+            .access_token_expiry
+            .write()
+            .expect("access token expiry rwlock poisoned") = access_token_expiry(token_response.expires_in);
 
         if let Some(refresh_token) = token_response.refresh_token {
             *self.inner.refresh_token.write().expect("refresh token rwlock poisoned") = refresh_token;
         }
+
+        if let Some(scopes) = token_response.scope.as_deref() {
+            *self
+                .inner
+                .granted_scopes
+                .write()
+                .expect("granted scopes rwlock poisoned") = Some(scope::parse_scopes_string(scopes));
+        }
+
+        // clone the callback out of the lock so the callback may register a new one without deadlocking
+        let callback = self
+            .inner
+            .token_refresh_callback
+            .read()
+            .expect("token refresh callback rwlock poisoned")
+            .clone();
+
+        if let Some(callback) = callback {
+            callback(&self.token_data());
+        }
     }
 }
 
@@ -301,11 +471,11 @@ impl AsyncAuthorizationCodeUserClient {
         debug!(
             "Attempting to create new authorization code flow client with existng refresh token: {} and client ID \
              (for PKCE): {:?}",
-            refresh_token, client_id
+            Redacted, client_id
         );
 
         let response = http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(&build_refresh_token_request_form(&refresh_token, client_id.as_deref()))
             .send()
             .await?;
@@ -335,11 +505,11 @@ impl SyncAuthorizationCodeUserClient {
         debug!(
             "Attempting to create new authorization code flow client with existng refresh token: {} and client ID \
              (for PKCE): {:?}",
-            refresh_token, client_id
+            Redacted, client_id
         );
 
         let response = http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(&build_refresh_token_request_form(&refresh_token, client_id.as_deref()))
             .send()?;
 
@@ -378,30 +548,26 @@ where
             query_params.push(("scope", scopes.as_str()));
         }
 
-        let authorize_url = if let Some(pkce_verifier) = self.pkce_verifier.as_deref() {
-            let mut hasher = sha2::Sha256::new();
-            hasher.update(pkce_verifier);
-            let pkce_challenge = hasher.finalize();
-            let pkce_challenge = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(pkce_challenge);
+        let pkce_challenge = self.pkce_verifier.as_deref().map(|pkce_verifier| {
+            let pkce_challenge = pkce_challenge(pkce_verifier);
 
             debug!(
                 "Using PKCE extension with verifier: {} and challenge: {}",
-                pkce_verifier, pkce_challenge
+                Redacted, pkce_challenge
             );
 
-            query_params.extend([("code_challenge_method", "S256"), ("code_challenge", &pkce_challenge)]);
+            pkce_challenge
+        });
 
-            // parsing the URL fails only if the base URL is invalid, not the parameters. if this method fails, there's
-            // a bug in the library
+        if let Some(pkce_challenge) = pkce_challenge.as_deref() {
+            query_params.extend([("code_challenge_method", "S256"), ("code_challenge", pkce_challenge)]);
+        }
 
-            // while both these branches end the same way, this one borrows the pkce_challenge string in query_params so
-            // the URL must be built before the string falls out of scope
-            Url::parse_with_params(ACCOUNTS_AUTHORIZE_ENDPOINT, &query_params)
-                .expect("failed to build authorize URL: invalid base URL (this is likely a bug)")
-        } else {
-            Url::parse_with_params(ACCOUNTS_AUTHORIZE_ENDPOINT, &query_params)
-                .expect("failed to build authorize URL: invalid base URL (this is likely a bug)")
-        };
+        let mut authorize_url = self
+            .http_client
+            .base_urls()
+            .accounts_endpoint(ACCOUNTS_AUTHORIZE_ENDPOINT);
+        authorize_url.query_pairs_mut().extend_pairs(&query_params);
 
         authorize_url.into()
     }
@@ -413,7 +579,7 @@ where
     ) -> Result<Vec<(&'a str, &'a str)>> {
         debug!(
             "Attempting to finalize authorization code flow user client with code: {} and state: {}",
-            code, state
+            Redacted, state
         );
 
         if state != self.state {
@@ -446,7 +612,10 @@ where
             inner: Arc::new(AuthorizationCodeUserClientRef {
                 access_token: RwLock::new(token_response.access_token),
                 refresh_token: RwLock::new(token_response.refresh_token),
+                access_token_expiry: RwLock::new(access_token_expiry(token_response.expires_in)),
+                granted_scopes: RwLock::new(token_response.scope.as_deref().map(scope::parse_scopes_string)),
                 client_id: self.pkce_verifier.and(Some(self.client_id)),
+                token_refresh_callback: RwLock::new(None),
             }),
         }
     }
@@ -464,7 +633,7 @@ impl AsyncIncompleteAuthorizationCodeUserClient {
         let token_request_form = self.build_authorization_code_token_request_form(code, state)?;
         let response = self
             .http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(&token_request_form)
             .send()
             .await?;
@@ -489,11 +658,7 @@ impl SyncIncompleteAuthorizationCodeUserClient {
     /// [AuthorizationCodeStateMismatch-error](Error::AuthorizationCodeStateMismatch).
     pub fn finalize(self, code: &str, state: &str) -> Result<SyncAuthorizationCodeUserClient> {
         let token_request_form = self.build_authorization_code_token_request_form(code, state)?;
-        let response = self
-            .http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
-            .form(&token_request_form)
-            .send()?;
+        let response = self.http_client.token_request().form(&token_request_form).send()?;
 
         let response = super::extract_authentication_error_sync(response).map_err(map_authentication_error)?;
         let token_response = response.json()?;
@@ -538,14 +703,8 @@ where
 {
     /// Generates a PKCE code verifier to be used in the authentication process.
     pub(super) fn with_pkce(self) -> Self {
-        let code_verifier = rand::thread_rng()
-            .sample_iter(&Alphanumeric)
-            .take(PKCE_VERIFIER_LENGTH)
-            .map(char::from)
-            .collect();
-
         Self {
-            pkce_verifier: Some(code_verifier),
+            pkce_verifier: Some(pkce_verifier()),
             ..self
         }
     }
@@ -606,6 +765,76 @@ where
 
 impl<C> crate::private::Sealed for AuthorizationCodeUserClient<C> where C: private::HttpClient + Clone {}
 
+impl<C> private::GrantedScopes for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn is_scope_granted(&self, scope: Scope) -> bool {
+        self.inner
+            .granted_scopes
+            .read()
+            .expect("granted scopes rwlock poisoned")
+            .as_ref()
+            .is_none_or(|granted_scopes| granted_scopes.contains(&scope))
+    }
+}
+
+impl<C> private::ApiBaseUrl for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn api_base_url(&self) -> &Url {
+        &self.http_client.base_urls().api
+    }
+}
+
+impl<C> private::RetryAfterState for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn retry_after_state(&self) -> &RwLock<Option<Duration>> {
+        self.http_client.last_retry_after()
+    }
+
+    fn max_rate_limit_retries(&self) -> u32 {
+        self.http_client.max_rate_limit_retries()
+    }
+}
+
+impl<C> private::ResponseCacheState for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn response_cache(&self) -> Option<&ResponseCache> {
+        self.http_client.response_cache()
+    }
+}
+
+impl<C> private::RetryPolicyState for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.http_client.retry_policy()
+    }
+}
+
+impl<C> private::DefaultMarketState for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    fn default_market(&self) -> Option<Market> {
+        self.http_client.default_market()
+    }
+}
+
+#[cfg(feature = "async")]
+impl private::ConcurrentRequestLimit for AsyncAuthorizationCodeUserClient {
+    fn concurrent_requests(&self) -> &tokio::sync::Semaphore {
+        self.http_client.concurrent_requests()
+    }
+}
+
 #[cfg(feature = "async")]
 impl private::BuildHttpRequestAsync for AsyncAuthorizationCodeUserClient {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
@@ -649,14 +878,14 @@ impl super::AccessTokenRefreshAsync for AsyncAuthorizationCodeUserClient {
             let refresh_token = self.inner.refresh_token.read().expect("refresh token rwlock poisoned");
             debug!(
                 "Attempting to refresh authorization code flow access token with refresh token: {}",
-                refresh_token
+                Redacted
             );
 
             // build the HTTP request straight from the client so it'll use the client credentials authorization header
             // instead of the access token
             let request = self
                 .http_client
-                .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+                .token_request()
                 .form(&build_refresh_token_request_form(
                     &refresh_token,
                     self.inner.client_id.as_deref(),
@@ -687,14 +916,14 @@ impl super::AccessTokenRefreshSync for SyncAuthorizationCodeUserClient {
         let refresh_token = self.inner.refresh_token.read().expect("refresh token rwlock poisoned");
         debug!(
             "Attempting to refresh authorization code flow access token with refresh token: {}",
-            refresh_token
+            Redacted
         );
 
         // build the HTTP request straight from the client so it'll use the client credentials authorization header
         // instead of the access token
         let response = self
             .http_client
-            .post(ACCOUNTS_API_TOKEN_ENDPOINT)
+            .token_request()
             .form(&build_refresh_token_request_form(
                 &refresh_token,
                 self.inner.client_id.as_deref(),
@@ -719,6 +948,10 @@ impl private::AccessTokenExpiryAsync for AsyncAuthorizationCodeUserClient {
         self.refresh_access_token().await?;
         Ok(private::AccessTokenExpiryResult::Ok)
     }
+
+    fn is_access_token_expired(&self) -> bool {
+        AuthorizationCodeUserClient::is_access_token_expired(self)
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -727,6 +960,14 @@ impl private::AccessTokenExpirySync for SyncAuthorizationCodeUserClient {
         self.refresh_access_token()?;
         Ok(private::AccessTokenExpiryResult::Ok)
     }
+
+    fn is_access_token_expired(&self) -> bool {
+        AuthorizationCodeUserClient::is_access_token_expired(self)
+    }
+}
+
+fn access_token_expiry(expires_in: u32) -> SystemTime {
+    SystemTime::now() + Duration::from_secs(expires_in.into())
 }
 
 fn build_refresh_token_request_form<'a>(refresh_token: &'a str, client_id: Option<&'a str>) -> Vec<(&'a str, &'a str)> {
@@ -740,7 +981,11 @@ fn build_refresh_token_request_form<'a>(refresh_token: &'a str, client_id: Optio
 }
 
 fn map_authentication_error(err: Error) -> Error {
-    if let Error::UnhandledAuthenticationError(AuthenticationErrorKind::InvalidGrant, _) = err {
+    if let Error::Auth(AuthError {
+        error: AuthenticationErrorKind::InvalidGrant,
+        ..
+    }) = err
+    {
         Error::InvalidAuthorizationCode
     } else {
         err
@@ -748,9 +993,367 @@ fn map_authentication_error(err: Error) -> Error {
 }
 
 fn map_refresh_token_error(err: Error) -> Error {
-    if let Error::UnhandledAuthenticationError(AuthenticationErrorKind::InvalidGrant, description) = err {
-        Error::InvalidRefreshToken(description)
+    if let Error::Auth(AuthError {
+        error: AuthenticationErrorKind::InvalidGrant,
+        error_description,
+    }) = err
+    {
+        Error::InvalidRefreshToken(error_description)
     } else {
         err
     }
 }
+
+/// Generates a random PKCE code verifier of the maximum length Spotify allows.
+fn pkce_verifier() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(PKCE_VERIFIER_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Derives the S256 PKCE code challenge from a code verifier: the URL-safe Base64 encoding of the verifier's SHA256
+/// hash, without padding.
+fn pkce_challenge(pkce_verifier: &str) -> String {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(pkce_verifier);
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hasher.finalize())
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use reqwest::Url;
+
+    use super::{
+        pkce_challenge, pkce_verifier, AsyncAuthorizationCodeUserClient, AuthorizeUserTokenResponse, TokenData,
+    };
+    use crate::{
+        client::{
+            private::GrantedScopes,
+            request_builder::AsyncRequestBuilder,
+            test_util::{json_response, serve_requests, TRACK_ID},
+            AccessTokenRefreshAsync, ScopedClient, SpotifyClientBuilder, ACCOUNTS_AUTHORIZE_ENDPOINT,
+            ACCOUNTS_BASE_URL, PKCE_VERIFIER_LENGTH,
+        },
+        error::Error,
+        model::id::{Id, IdFromBare, TrackId},
+        scope::Scope,
+    };
+
+    fn client_with_granted_scopes(scope: Option<&str>) -> AsyncAuthorizationCodeUserClient {
+        SpotifyClientBuilder::new("client ID")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build()
+            .build_client(AuthorizeUserTokenResponse {
+                access_token: "access token".to_owned(),
+                refresh_token: "refresh token".to_owned(),
+                scope: scope.map(str::to_owned),
+                expires_in: 3600,
+                token_type: "Bearer".to_owned(),
+            })
+    }
+
+    #[tokio::test]
+    async fn rotated_refresh_token_is_kept() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "200 OK",
+                r#"{"access_token":"access token 2","refresh_token":"refresh token 2","expires_in":3600,"token_type":"Bearer"}"#,
+            )
+        });
+        let refreshed_tokens = Arc::new(Mutex::new(Vec::new()));
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .accounts_base_url(url.parse().unwrap())
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build()
+            .build_client(AuthorizeUserTokenResponse {
+                access_token: "access token".to_owned(),
+                refresh_token: "refresh token".to_owned(),
+                scope: None,
+                expires_in: 3600,
+                token_type: "Bearer".to_owned(),
+            });
+
+        let callback_tokens = Arc::clone(&refreshed_tokens);
+        client.on_token_refresh(move |token_data| callback_tokens.lock().unwrap().push(token_data.clone()));
+        client.refresh_access_token().await.unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].body.contains("refresh_token=refresh+token"));
+        assert_eq!(client.get_refresh_token(), "refresh token 2");
+
+        let refreshed_tokens = refreshed_tokens.lock().unwrap();
+        assert_eq!(refreshed_tokens.len(), 1);
+        assert_eq!(refreshed_tokens[0].access_token, "access token 2");
+        assert_eq!(refreshed_tokens[0].refresh_token, "refresh token 2");
+    }
+
+    #[test]
+    fn pkce_verifier_is_alphanumeric_and_maximum_length() {
+        let verifier = pkce_verifier();
+
+        assert_eq!(verifier.len(), PKCE_VERIFIER_LENGTH);
+        assert!(verifier.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(verifier, pkce_verifier());
+    }
+
+    #[test]
+    fn pkce_challenge_is_s256() {
+        // base64url(sha256(verifier)) without the trailing padding
+        assert_eq!(
+            pkce_challenge("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789"),
+            "VANj0QcaACmXKQzY9KK986zQNV_607PyX1Kq1uutk2o"
+        );
+    }
+
+    #[test]
+    fn pkce_authorize_url() {
+        let client = SpotifyClientBuilder::new("client ID")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .scopes([Scope::UserReadPrivate, Scope::PlaylistReadPrivate])
+            .show_dialog(true)
+            .build();
+
+        let authorize_url = Url::parse(&client.get_authorize_url()).unwrap();
+        let query: HashMap<_, _> = authorize_url.query_pairs().into_owned().collect();
+        let verifier = client.pkce_verifier.as_deref().unwrap();
+
+        assert!(authorize_url
+            .as_str()
+            .starts_with(&format!("{ACCOUNTS_BASE_URL}{ACCOUNTS_AUTHORIZE_ENDPOINT}")));
+        assert_eq!(query["response_type"], "code");
+        assert_eq!(query["redirect_uri"], "http://localhost/callback");
+        assert_eq!(query["client_id"], "client ID");
+        assert_eq!(query["state"], client.state);
+        assert_eq!(query["show_dialog"], "true");
+        assert_eq!(query["scope"], "user-read-private playlist-read-private");
+        assert_eq!(query["code_challenge_method"], "S256");
+        assert_eq!(query["code_challenge"], pkce_challenge(verifier));
+    }
+
+    #[test]
+    fn authorize_url_without_pkce() {
+        let client = SpotifyClientBuilder::new("client ID")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build();
+        let client = super::IncompleteAuthorizationCodeUserClient {
+            pkce_verifier: None,
+            ..client
+        };
+
+        let authorize_url = Url::parse(&client.get_authorize_url()).unwrap();
+        let query: HashMap<_, _> = authorize_url.query_pairs().into_owned().collect();
+
+        assert_eq!(query["show_dialog"], "false");
+        assert!(!query.contains_key("scope"));
+        assert!(!query.contains_key("code_challenge_method"));
+        assert!(!query.contains_key("code_challenge"));
+    }
+
+    #[test]
+    fn pkce_token_request_form() {
+        let client = SpotifyClientBuilder::new("client ID")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build();
+
+        let form: HashMap<_, _> = client
+            .build_authorization_code_token_request_form("code", &client.state)
+            .unwrap()
+            .into_iter()
+            .collect();
+
+        assert_eq!(form["grant_type"], "authorization_code");
+        assert_eq!(form["code"], "code");
+        assert_eq!(form["redirect_uri"], "http://localhost/callback");
+        assert_eq!(form["client_id"], "client ID");
+        assert_eq!(form["code_verifier"], client.pkce_verifier.as_deref().unwrap());
+    }
+
+    #[test]
+    fn token_request_form_state_mismatch() {
+        let client = SpotifyClientBuilder::new("client ID")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build();
+
+        assert!(matches!(
+            client.build_authorization_code_token_request_form("code", "wrong state"),
+            Err(Error::AuthorizationCodeStateMismatch)
+        ));
+    }
+
+    #[test]
+    fn granted_scopes_from_token_response() {
+        let client = client_with_granted_scopes(Some("user-read-playback-state user-read-private"));
+
+        assert_eq!(
+            client.granted_scopes(),
+            Some([Scope::UserReadPlaybackState, Scope::UserReadPrivate].into())
+        );
+        assert!(client.is_scope_granted(Scope::UserReadPlaybackState));
+        assert!(!client.is_scope_granted(Scope::UserModifyPlaybackState));
+    }
+
+    #[test]
+    fn unknown_granted_scopes_allow_everything() {
+        let client = client_with_granted_scopes(None);
+
+        assert_eq!(client.granted_scopes(), None);
+        assert!(client.is_scope_granted(Scope::UserModifyPlaybackState));
+    }
+
+    #[tokio::test]
+    async fn missing_scope_fails_before_sending_request() {
+        // the client's HTTP client would fail to send any request with an invalid access token, so getting the
+        // MissingScope error means the request was never sent
+        let client = client_with_granted_scopes(Some("user-read-playback-state"));
+
+        assert!(matches!(
+            client.pause().send_async().await,
+            Err(Error::MissingScope(Scope::UserModifyPlaybackState))
+        ));
+        assert!(matches!(
+            client.currently_playing_item().send_async().await,
+            Err(Error::MissingScope(Scope::UserReadCurrentlyPlaying))
+        ));
+    }
+
+    #[tokio::test]
+    async fn missing_playlist_modify_scope_fails_before_sending_request() {
+        let client = client_with_granted_scopes(Some("playlist-read-private"));
+        let playlist = || Id::from_bare("37i9dQZF1DWZipvLjDtZYe").unwrap();
+        let tracks = || [Id::<TrackId>::from_bare(TRACK_ID).unwrap()];
+
+        assert!(matches!(
+            client.add_items_to_playlist(playlist(), tracks()).send_async().await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPublic))
+        ));
+        assert!(matches!(
+            client.replace_playlist_items(playlist(), tracks()).send_async().await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPublic))
+        ));
+        assert!(matches!(
+            client
+                .remove_items_from_playlist(playlist(), tracks())
+                .send_async()
+                .await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPublic))
+        ));
+        assert!(matches!(
+            client
+                .remove_playlist_items_by_position(playlist(), &[0], "snapshot")
+                .send_async()
+                .await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPublic))
+        ));
+        assert!(matches!(
+            client.reorder_playlist_items(playlist(), 0, 2).send_async().await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPublic))
+        ));
+    }
+
+    #[tokio::test]
+    async fn missing_scope_fails_changing_playlist_details() {
+        let client = client_with_granted_scopes(Some("playlist-read-private"));
+        let playlist = || Id::from_bare("37i9dQZF1DWZipvLjDtZYe").unwrap();
+
+        assert!(matches!(
+            client
+                .change_playlist_details(playlist())
+                .name("New name")
+                .send_async()
+                .await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPublic))
+        ));
+
+        // making the playlist private requires the private scope even if the public scope is granted
+        let client = client_with_granted_scopes(Some("playlist-modify-public"));
+
+        assert!(matches!(
+            client
+                .change_playlist_details(playlist())
+                .public(false)
+                .send_async()
+                .await,
+            Err(Error::MissingScope(Scope::PlaylistModifyPrivate))
+        ));
+    }
+
+    #[tokio::test]
+    async fn private_playlist_modify_scope_is_enough_for_playlist_items() {
+        let (url, server) = serve_requests(1, |_| json_response("201 Created", r#"{ "snapshot_id": "snapshot" }"#));
+        let client = SpotifyClientBuilder::new("client ID")
+            .api_base_url(url.parse().unwrap())
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build()
+            .build_client(AuthorizeUserTokenResponse {
+                access_token: "access token".to_owned(),
+                refresh_token: "refresh token".to_owned(),
+                scope: Some("playlist-modify-private".to_owned()),
+                expires_in: 3600,
+                token_type: "Bearer".to_owned(),
+            });
+
+        let snapshot_id = client
+            .add_items_to_playlist(
+                Id::from_bare("37i9dQZF1DWZipvLjDtZYe").unwrap(),
+                [Id::<TrackId>::from_bare(TRACK_ID).unwrap()],
+            )
+            .send_async()
+            .await
+            .unwrap();
+        server.join().unwrap();
+
+        assert_eq!(snapshot_id, "snapshot");
+    }
+
+    #[test]
+    fn token_data_json_round_trip() {
+        let client = client_with_granted_scopes(Some("user-read-playback-state user-read-private"));
+        let token_data = client.token_data();
+
+        assert_eq!(token_data.access_token, "access token");
+        assert_eq!(token_data.refresh_token, "refresh token");
+        assert_eq!(
+            token_data.scopes,
+            Some([Scope::UserReadPlaybackState, Scope::UserReadPrivate].into())
+        );
+
+        let serialized = serde_json::to_string(&token_data).unwrap();
+        let deserialized: TokenData = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, token_data);
+    }
+
+    #[test]
+    fn access_token_expiry() {
+        let client = client_with_granted_scopes(None);
+        assert!(!client.is_access_token_expired());
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build()
+            .build_client(AuthorizeUserTokenResponse {
+                access_token: "access token".to_owned(),
+                refresh_token: "refresh token".to_owned(),
+                scope: None,
+                expires_in: 0,
+                token_type: "Bearer".to_owned(),
+            });
+        assert!(client.is_access_token_expired());
+    }
+}
@@ -62,7 +62,11 @@
 //!     .expect("failed to finalize implicit grant flow client");
 //! # }
 
-use std::sync::Arc;
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use log::debug;
 use rand::{distributions::Alphanumeric, Rng};
@@ -74,11 +78,14 @@ use super::private::AsyncClient;
 use super::private::SyncClient;
 use super::{
     private::{self, HttpClient},
-    SpotifyClientRef, ACCOUNTS_AUTHORIZE_ENDPOINT, RANDOM_STATE_LENGTH,
+    response_cache::ResponseCache,
+    RetryPolicy, SpotifyClientRef, ACCOUNTS_AUTHORIZE_ENDPOINT, RANDOM_STATE_LENGTH,
 };
 use crate::{
     error::{Error, Result},
+    model::Market,
     scope::ToScopesString,
+    util::redacted::Redacted,
 };
 
 /// Type alias for an asynchronous implicit grant user client. See [ImplicitGrantUserClient](ImplicitGrantUserClient).
@@ -130,11 +137,18 @@ where
     http_client: C,
 }
 
-#[derive(Debug)]
 struct ImplicitGrantUserClientRef {
     access_token: String,
 }
 
+impl fmt::Debug for ImplicitGrantUserClientRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImplicitGrantUserClientRef")
+            .field("access_token", &Redacted)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IncompleteImplicitGrantUserClient<C>
 where
@@ -182,10 +196,11 @@ where
             query_params.push(("show_dialog", "true"));
         }
 
-        // parsing the URL fails only if the base URL is invalid, not the parameters. if this method fails, there's a
-        // bug in the library
-        let authorize_url = Url::parse_with_params(ACCOUNTS_AUTHORIZE_ENDPOINT, &query_params)
-            .expect("failed to build authorize URL: invalid base URL (this is likely a bug)");
+        let mut authorize_url = self
+            .http_client
+            .base_urls()
+            .accounts_endpoint(ACCOUNTS_AUTHORIZE_ENDPOINT);
+        authorize_url.query_pairs_mut().extend_pairs(&query_params);
 
         authorize_url.into()
     }
@@ -197,7 +212,7 @@ where
         let access_token = access_token.into();
         debug!(
             "Attempting to finalize implicit grant flow user client with access_token: {} and state: {}",
-            access_token, state
+            Redacted, state
         );
 
         if state != self.state {
@@ -261,6 +276,65 @@ where
 
 impl<C> crate::private::Sealed for ImplicitGrantUserClient<C> where C: HttpClient + Clone {}
 
+// the implicit grant flow doesn't return the granted scopes, so let Spotify decide whether they're granted
+impl<C> private::GrantedScopes for ImplicitGrantUserClient<C> where C: HttpClient + Clone {}
+
+impl<C> private::ApiBaseUrl for ImplicitGrantUserClient<C>
+where
+    C: HttpClient + Clone,
+{
+    fn api_base_url(&self) -> &Url {
+        &self.http_client.base_urls().api
+    }
+}
+
+impl<C> private::RetryAfterState for ImplicitGrantUserClient<C>
+where
+    C: HttpClient + Clone,
+{
+    fn retry_after_state(&self) -> &RwLock<Option<Duration>> {
+        self.http_client.last_retry_after()
+    }
+
+    fn max_rate_limit_retries(&self) -> u32 {
+        self.http_client.max_rate_limit_retries()
+    }
+}
+
+impl<C> private::ResponseCacheState for ImplicitGrantUserClient<C>
+where
+    C: HttpClient + Clone,
+{
+    fn response_cache(&self) -> Option<&ResponseCache> {
+        self.http_client.response_cache()
+    }
+}
+
+impl<C> private::RetryPolicyState for ImplicitGrantUserClient<C>
+where
+    C: HttpClient + Clone,
+{
+    fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.http_client.retry_policy()
+    }
+}
+
+impl<C> private::DefaultMarketState for ImplicitGrantUserClient<C>
+where
+    C: HttpClient + Clone,
+{
+    fn default_market(&self) -> Option<Market> {
+        self.http_client.default_market()
+    }
+}
+
+#[cfg(feature = "async")]
+impl private::ConcurrentRequestLimit for AsyncImplicitGrantUserClient {
+    fn concurrent_requests(&self) -> &tokio::sync::Semaphore {
+        self.http_client.concurrent_requests()
+    }
+}
+
 #[cfg(feature = "async")]
 impl private::BuildHttpRequestAsync for AsyncImplicitGrantUserClient {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
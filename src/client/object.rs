@@ -4,9 +4,23 @@ use crate::{
     client::request_builder::TryFromEmptyResponse,
     error::ConversionError,
     model::{
-        playback::{CurrentlyPlayingItem, Device, PlaybackState},
+        album::{AlbumObject, AlbumTracks, ArtistAlbums, FullAlbum},
+        artist::{ArtistObject, FullArtist},
+        audio_analysis::AudioAnalysis,
+        audio_features::AudioFeatures,
+        audiobook::{AudiobookChapters, FullAudiobook},
+        browse::{BrowsePlaylists, Categories, FeaturedPlaylists, NewReleases},
+        chapter::FullChapter,
+        episode::{EpisodeObject, FullEpisode},
+        id::IdTrait,
+        library::{SavedAlbums, SavedShows, SavedTracks},
+        playback::{CurrentlyPlayingItem, Device, NowPlaying, PlaybackOffset, PlaybackQueue, PlaybackState},
+        playlist::{FullPlaylist, PlaylistItems, Playlists},
+        recommendations::Recommendations,
+        show::{FullShow, PartialShow, ShowEpisodes},
         track::{FullTrack, TrackObject},
         user::{PublicUser, User},
+        Market,
     },
 };
 
@@ -17,25 +31,147 @@ pub const VOLUME_PERCENT_QUERY: &str = "volume_percent";
 pub const SEEK_POSITION_QUERY: &str = "position_ms";
 pub const QUEUE_URI_QUERY: &str = "uri";
 
-pub const TRACKS_IDS_QUERY: &str = "ids";
+pub const IDS_QUERY: &str = "ids";
 pub const MARKET_QUERY: &str = "market";
+pub const FOLLOW_TYPE_QUERY: &str = "type";
+pub const FOLLOW_TYPE_ARTIST: &str = "artist";
+pub const FOLLOW_TYPE_USER: &str = "user";
+
+pub const PAGE_LIMIT_QUERY: &str = "limit";
+pub const PAGE_OFFSET_QUERY: &str = "offset";
+
+/// The largest page limit and offset a paginated endpoint accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageBounds {
+    pub max_limit: u32,
+    /// `None` if the endpoint doesn't limit the offset, or doesn't take one.
+    pub max_offset: Option<u32>,
+}
+
+// the page bounds of each paginated endpoint. Most endpoints share the default bounds
+pub const DEFAULT_PAGE_BOUNDS: PageBounds = PageBounds {
+    max_limit: 50,
+    max_offset: None,
+};
+pub const SEARCH_PAGE_BOUNDS: PageBounds = PageBounds {
+    max_limit: 50,
+    max_offset: Some(1000),
+};
+pub const PLAYLISTS_PAGE_BOUNDS: PageBounds = PageBounds {
+    max_limit: 50,
+    max_offset: Some(100_000),
+};
+pub const RECOMMENDATIONS_PAGE_BOUNDS: PageBounds = PageBounds {
+    max_limit: 100,
+    max_offset: None,
+};
+
+pub const MAX_TRACKS_PER_REQUEST: usize = 50;
+pub const MAX_ARTISTS_PER_REQUEST: usize = 50;
+pub const MAX_ALBUMS_PER_REQUEST: usize = 20;
+pub const MAX_SHOWS_PER_REQUEST: usize = 50;
+pub const MAX_EPISODES_PER_REQUEST: usize = 50;
+pub const MAX_AUDIOBOOKS_PER_REQUEST: usize = 50;
+pub const MAX_FOLLOWS_PER_REQUEST: usize = 50;
+pub const MAX_PLAYLIST_ITEMS_PER_REQUEST: usize = 100;
+pub const MAX_AUDIO_FEATURES_PER_REQUEST: usize = 100;
+/// The maximum size of a base64-encoded playlist cover image.
+pub const MAX_PLAYLIST_COVER_IMAGE_SIZE: usize = 256 * 1024;
 
 #[derive(Debug, Serialize)]
 pub struct PlayItemsBody {
     pub uris: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransferPlaybackBody {
+    pub device_ids: [String; 1],
+    pub play: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePlaylistBody {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ChangePlaylistDetailsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddPlaylistItemsBody {
+    pub uris: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RemovePlaylistItemsBody {
+    pub tracks: Vec<PlaylistItemUri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RemovePlaylistItemsByPositionBody {
+    pub positions: Vec<u32>,
+    pub snapshot_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlaylistItemUri {
+    pub uri: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReorderPlaylistItemsBody {
+    pub range_start: u32,
+    pub insert_before: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct PlayContextBody {
     pub context_uri: String,
-    pub offset: PlayContextOffset,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<PlayContextOffset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ms: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
-pub struct PlayContextOffset {
-    pub position: Option<u32>,
-    // TODO: support URI offsets
-    pub uri: Option<String>,
+#[serde(rename_all = "snake_case")]
+pub enum PlayContextOffset {
+    Position(u32),
+    Uri(String),
+}
+
+impl From<PlaybackOffset<'_>> for PlayContextOffset {
+    fn from(offset: PlaybackOffset<'_>) -> Self {
+        match offset {
+            PlaybackOffset::Position(position) => Self::Position(position),
+            PlaybackOffset::Item(item) => Self::Uri(item.as_uri().into_owned()),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,6 +179,60 @@ pub struct DevicesResponse {
     pub devices: Vec<Device>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct NowPlayingResponse(Option<PlaybackState>);
+
+/// A response whose body is ignored, for requests that only check whether Spotify accepts them.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct IgnoredResponse(serde::de::IgnoredAny);
+
+#[derive(Debug, Deserialize)]
+pub struct SnapshotIdResponse {
+    snapshot_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumsResponse {
+    albums: Vec<Option<AlbumObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArtistsResponse {
+    artists: Vec<Option<ArtistObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AudiobooksResponse {
+    audiobooks: Vec<Option<FullAudiobook>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AudioFeaturesResponse {
+    audio_features: Vec<Option<AudioFeatures>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EpisodesResponse {
+    episodes: Vec<Option<EpisodeObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenresResponse {
+    genres: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarketsResponse {
+    markets: Vec<Market>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ShowsResponse {
+    shows: Vec<Option<PartialShow>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TracksResponse {
     tracks: Vec<Option<TrackObject>>,
@@ -69,18 +259,373 @@ impl TryFrom<TracksResponse> for Vec<FullTrack> {
     }
 }
 
+impl TryFrom<AlbumsResponse> for Vec<Option<FullAlbum>> {
+    type Error = ConversionError;
+
+    fn try_from(value: AlbumsResponse) -> Result<Self, Self::Error> {
+        value
+            .albums
+            .into_iter()
+            .map(|obj| obj.map(FullAlbum::try_from).transpose())
+            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+    }
+}
+
+impl TryFrom<ArtistsResponse> for Vec<FullArtist> {
+    type Error = ConversionError;
+
+    fn try_from(value: ArtistsResponse) -> Result<Self, Self::Error> {
+        value
+            .artists
+            .into_iter()
+            .filter_map(|obj| obj.map(|obj| FullArtist::try_from(obj).map_err(ConversionError::from)))
+            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+    }
+}
+
+impl TryFrom<EpisodesResponse> for Vec<Option<FullEpisode>> {
+    type Error = ConversionError;
+
+    fn try_from(value: EpisodesResponse) -> Result<Self, Self::Error> {
+        value
+            .episodes
+            .into_iter()
+            .map(|obj| obj.map(FullEpisode::try_from).transpose())
+            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+    }
+}
+
+impl From<GenresResponse> for Vec<String> {
+    fn from(response: GenresResponse) -> Self {
+        response.genres
+    }
+}
+
+impl From<MarketsResponse> for Vec<Market> {
+    fn from(response: MarketsResponse) -> Self {
+        response.markets
+    }
+}
+
+impl From<ShowsResponse> for Vec<Option<PartialShow>> {
+    fn from(response: ShowsResponse) -> Self {
+        response.shows
+    }
+}
+
+impl From<AudiobooksResponse> for Vec<Option<FullAudiobook>> {
+    fn from(response: AudiobooksResponse) -> Self {
+        response.audiobooks
+    }
+}
+
+impl From<AudioFeaturesResponse> for Vec<Option<AudioFeatures>> {
+    fn from(response: AudioFeaturesResponse) -> Self {
+        response.audio_features
+    }
+}
+
 impl From<DevicesResponse> for Vec<Device> {
     fn from(response: DevicesResponse) -> Self {
         response.devices
     }
 }
 
+impl From<NowPlayingResponse> for Option<NowPlaying> {
+    fn from(response: NowPlayingResponse) -> Self {
+        response.0?.currently_playing_item().now_playing()
+    }
+}
+
+impl From<IgnoredResponse> for () {
+    fn from(_: IgnoredResponse) -> Self {}
+}
+
+impl From<SnapshotIdResponse> for String {
+    fn from(response: SnapshotIdResponse) -> Self {
+        response.snapshot_id
+    }
+}
+
+#[cfg(feature = "raw")]
+impl TryFromEmptyResponse for serde_json::Value {}
 impl TryFromEmptyResponse for DevicesResponse {}
-impl TryFromEmptyResponse for Option<PlaybackState> {}
-impl TryFromEmptyResponse for Option<CurrentlyPlayingItem> {}
+impl TryFromEmptyResponse for SnapshotIdResponse {}
+impl TryFromEmptyResponse for AlbumsResponse {}
+impl TryFromEmptyResponse for ArtistsResponse {}
+impl TryFromEmptyResponse for AudioAnalysis {}
+impl TryFromEmptyResponse for AudioFeatures {}
+impl TryFromEmptyResponse for AudioFeaturesResponse {}
+impl TryFromEmptyResponse for AudiobooksResponse {}
+impl TryFromEmptyResponse for FullAudiobook {}
+impl TryFromEmptyResponse for AudiobookChapters {}
+impl TryFromEmptyResponse for FullChapter {}
+impl TryFromEmptyResponse for EpisodesResponse {}
+impl TryFromEmptyResponse for GenresResponse {}
+impl TryFromEmptyResponse for MarketsResponse {}
+impl TryFromEmptyResponse for ShowsResponse {}
 impl TryFromEmptyResponse for TracksResponse {}
 impl TryFromEmptyResponse for FullTrack {}
 impl TryFromEmptyResponse for TrackObject {}
 impl TryFromEmptyResponse for Vec<FullTrack> {}
 impl TryFromEmptyResponse for User {}
 impl TryFromEmptyResponse for PublicUser {}
+impl TryFromEmptyResponse for SavedTracks {}
+impl TryFromEmptyResponse for SavedAlbums {}
+impl TryFromEmptyResponse for SavedShows {}
+impl TryFromEmptyResponse for FullShow {}
+impl TryFromEmptyResponse for ShowEpisodes {}
+impl TryFromEmptyResponse for AlbumTracks {}
+impl TryFromEmptyResponse for ArtistAlbums {}
+impl TryFromEmptyResponse for FullEpisode {}
+impl TryFromEmptyResponse for Playlists {}
+impl TryFromEmptyResponse for FullPlaylist {}
+impl TryFromEmptyResponse for PlaylistItems {}
+impl TryFromEmptyResponse for Recommendations {}
+impl TryFromEmptyResponse for NewReleases {}
+impl TryFromEmptyResponse for FeaturedPlaylists {}
+impl TryFromEmptyResponse for Categories {}
+impl TryFromEmptyResponse for BrowsePlaylists {}
+impl TryFromEmptyResponse for Vec<bool> {}
+impl TryFromEmptyResponse for PlaybackQueue {}
+
+// Spotify responds with 204 No Content when there is no active playback
+impl TryFromEmptyResponse for Option<PlaybackState> {
+    fn try_from_empty_response() -> crate::error::Result<Self> {
+        Ok(None)
+    }
+}
+
+impl TryFromEmptyResponse for Option<CurrentlyPlayingItem> {
+    fn try_from_empty_response() -> crate::error::Result<Self> {
+        Ok(None)
+    }
+}
+
+impl TryFromEmptyResponse for IgnoredResponse {
+    fn try_from_empty_response() -> crate::error::Result<Self> {
+        Ok(Self(serde::de::IgnoredAny))
+    }
+}
+
+impl TryFromEmptyResponse for NowPlayingResponse {
+    fn try_from_empty_response() -> crate::error::Result<Self> {
+        Ok(Self(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{track::CommonTrackInformation, CountryCode};
+
+    #[test]
+    fn audio_features_response_with_null() {
+        let response: AudioFeaturesResponse = serde_json::from_str(
+            r#"{
+                "audio_features": [
+                    {
+                        "acousticness": 0.00242,
+                        "analysis_url": "https://api.spotify.com/v1/audio-analysis/2takcwOaAZWiXQijPHIx7B",
+                        "danceability": 0.585,
+                        "duration_ms": 237040,
+                        "energy": 0.842,
+                        "id": "2takcwOaAZWiXQijPHIx7B",
+                        "instrumentalness": 0.00686,
+                        "key": 9,
+                        "liveness": 0.0866,
+                        "loudness": -5.883,
+                        "mode": 1,
+                        "speechiness": 0.0556,
+                        "tempo": 118.211,
+                        "time_signature": 4,
+                        "track_href": "https://api.spotify.com/v1/tracks/2takcwOaAZWiXQijPHIx7B",
+                        "type": "audio_features",
+                        "uri": "spotify:track:2takcwOaAZWiXQijPHIx7B",
+                        "valence": 0.428
+                    },
+                    null
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let features = Vec::<Option<AudioFeatures>>::from(response);
+
+        assert_eq!(features.len(), 2);
+        assert_eq!(
+            features[0].as_ref().unwrap().mode,
+            crate::model::audio_features::Mode::Major
+        );
+        assert!(features[1].is_none());
+    }
+
+    #[test]
+    fn empty_artists_response() {
+        let response: ArtistsResponse = serde_json::from_str(r#"{ "artists": [] }"#).unwrap();
+        let artists = Vec::<FullArtist>::try_from(response).unwrap();
+
+        assert!(artists.is_empty());
+    }
+
+    #[test]
+    fn genres_response() {
+        let response: GenresResponse = serde_json::from_str(r#"{ "genres": ["acoustic", "anime", "j-pop"] }"#).unwrap();
+        let genres = Vec::<String>::from(response);
+
+        assert_eq!(genres, ["acoustic", "anime", "j-pop"]);
+    }
+
+    #[test]
+    fn markets_response() {
+        let response: MarketsResponse = serde_json::from_str(r#"{ "markets": ["FI", "JP", "US"] }"#).unwrap();
+        let markets = Vec::<Market>::from(response);
+
+        assert_eq!(
+            markets,
+            [
+                Market::Country(CountryCode::FI),
+                Market::Country(CountryCode::JP),
+                Market::Country(CountryCode::US)
+            ]
+        );
+    }
+
+    #[test]
+    fn markets_response_with_unknown_market() {
+        let result = serde_json::from_str::<MarketsResponse>(r#"{ "markets": ["FI", "QQ"] }"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn artist_top_tracks_response() {
+        let response: TracksResponse = serde_json::from_str(
+            r#"{
+                "tracks": [
+                    {
+                        "album": {
+                            "album_type": "single",
+                            "artists": [
+                                {
+                                    "external_urls": {
+                                        "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                                    },
+                                    "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                                    "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                                    "name": "Hatsune Miku",
+                                    "type": "artist",
+                                    "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                                }
+                            ],
+                            "external_urls": {
+                                "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P"
+                            },
+                            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "World is Mine",
+                            "release_date": "2008-05-31",
+                            "release_date_precision": "day",
+                            "total_tracks": 2,
+                            "type": "album",
+                            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+                        },
+                        "artists": [
+                            {
+                                "external_urls": {
+                                    "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                                },
+                                "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                                "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                                "name": "Hatsune Miku",
+                                "type": "artist",
+                                "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                            }
+                        ],
+                        "disc_number": 1,
+                        "duration_ms": 254000,
+                        "explicit": false,
+                        "external_ids": { "isrc": "JPU900800002" },
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu"
+                        },
+                        "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "is_playable": true,
+                        "name": "World is Mine",
+                        "popularity": 60,
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track",
+                        "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+                    },
+                    {
+                        "album": {
+                            "album_type": "single",
+                            "artists": [
+                                {
+                                    "external_urls": {
+                                        "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                                    },
+                                    "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                                    "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                                    "name": "Hatsune Miku",
+                                    "type": "artist",
+                                    "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                                }
+                            ],
+                            "external_urls": {
+                                "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P"
+                            },
+                            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "World is Mine",
+                            "release_date": "2008-05-31",
+                            "release_date_precision": "day",
+                            "total_tracks": 2,
+                            "type": "album",
+                            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+                        },
+                        "artists": [
+                            {
+                                "external_urls": {
+                                    "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                                },
+                                "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                                "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                                "name": "Hatsune Miku",
+                                "type": "artist",
+                                "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                            }
+                        ],
+                        "disc_number": 1,
+                        "duration_ms": 230000,
+                        "explicit": false,
+                        "external_ids": { "isrc": "JPU900800003" },
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/track/3mXLyNsVeLelMakgpGUp1f"
+                        },
+                        "href": "https://api.spotify.com/v1/tracks/3mXLyNsVeLelMakgpGUp1f",
+                        "id": "3mXLyNsVeLelMakgpGUp1f",
+                        "is_local": false,
+                        "is_playable": true,
+                        "name": "Melt",
+                        "popularity": 55,
+                        "preview_url": null,
+                        "track_number": 2,
+                        "type": "track",
+                        "uri": "spotify:track:3mXLyNsVeLelMakgpGUp1f"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let tracks = Vec::<FullTrack>::try_from(response).unwrap();
+
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0].name(), "World is Mine");
+    }
+}
@@ -1,13 +1,151 @@
 #[cfg(feature = "async")]
 mod async_client {
-    use std::ops::Deref;
+    use std::{
+        ops::Deref,
+        sync::{Arc, RwLock},
+        time::Duration,
+    };
+
+    use reqwest::{
+        header::{self, HeaderValue},
+        IntoUrl, Method,
+    };
+    use tokio::sync::Semaphore;
+
+    use super::BaseUrls;
+    use crate::client::response_cache::ResponseCache;
+    use crate::client::{
+        RetryPolicy, ACCOUNTS_API_TOKEN_ENDPOINT, DEFAULT_MAX_CONCURRENT_REQUESTS, DEFAULT_MAX_RATE_LIMIT_RETRIES,
+        DEFAULT_USER_AGENT,
+    };
+    use crate::model::Market;
 
     #[derive(Clone, Debug)]
-    pub struct AsyncClient(pub(crate) reqwest::Client);
+    pub struct AsyncClient {
+        http_client: reqwest::Client,
+        // the client credentials authorization sent with token requests, if the client has a client secret
+        client_authorization: Option<HeaderValue>,
+        base_urls: Arc<BaseUrls>,
+        last_retry_after: Arc<RwLock<Option<Duration>>>,
+        response_cache: Option<Arc<ResponseCache>>,
+        retry_policy: Option<Arc<RetryPolicy>>,
+        max_rate_limit_retries: u32,
+        default_market: Option<Market>,
+        user_agent: Option<HeaderValue>,
+        // bounds how many chunked sub-requests all clones of the client send at once
+        concurrent_requests: Arc<Semaphore>,
+    }
+
+    impl AsyncClient {
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn from_http_client(
+            http_client: reqwest::Client,
+            client_authorization: Option<HeaderValue>,
+            base_urls: BaseUrls,
+            max_concurrent_requests: usize,
+            response_cache: Option<ResponseCache>,
+            retry_policy: Option<RetryPolicy>,
+            max_rate_limit_retries: u32,
+            default_market: Option<Market>,
+            user_agent: Option<HeaderValue>,
+        ) -> Self {
+            Self {
+                http_client,
+                client_authorization,
+                base_urls: Arc::new(base_urls),
+                last_retry_after: Arc::new(RwLock::new(None)),
+                response_cache: response_cache.map(Arc::new),
+                retry_policy: retry_policy.map(Arc::new),
+                max_rate_limit_retries,
+                default_market,
+                user_agent,
+                concurrent_requests: Arc::new(Semaphore::new(max_concurrent_requests.max(1))),
+            }
+        }
+
+        /// Returns the semaphore whose permits the chunked sub-requests hold while they're being sent.
+        pub(crate) fn concurrent_requests(&self) -> &Semaphore {
+            &self.concurrent_requests
+        }
+
+        /// Returns a clone of the client with its own, empty response cache if the cache is enabled. The cache is keyed
+        /// only by URL, so every user client gets its own to never serve one user's responses to another.
+        pub(crate) fn with_own_response_cache(&self) -> Self {
+            Self {
+                response_cache: self
+                    .response_cache
+                    .as_ref()
+                    .map(|cache| Arc::new(ResponseCache::new(cache.capacity()))),
+                ..self.clone()
+            }
+        }
+
+        /// Returns a new request to Spotify's token endpoint with the client credentials authorization, if any, filled
+        /// in.
+        pub(crate) fn token_request(&self) -> reqwest::RequestBuilder {
+            let request = self.request(
+                Method::POST,
+                self.base_urls.accounts_endpoint(ACCOUNTS_API_TOKEN_ENDPOINT),
+            );
+
+            match &self.client_authorization {
+                Some(authorization) => request.header(header::AUTHORIZATION, authorization.clone()),
+                None => request,
+            }
+        }
+
+        /// Returns a new request with the client's user agent, if any, filled in. Every request the client sends is built
+        /// with this function.
+        pub(crate) fn request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
+        where
+            U: IntoUrl,
+        {
+            let request = self.http_client.request(method, url);
+
+            match &self.user_agent {
+                Some(user_agent) => request.header(header::USER_AGENT, user_agent.clone()),
+                None => request,
+            }
+        }
+    }
 
     impl super::HttpClient for AsyncClient {
         fn new() -> Self {
-            Self(reqwest::Client::new())
+            Self::from_http_client(
+                reqwest::Client::new(),
+                None,
+                BaseUrls::default(),
+                DEFAULT_MAX_CONCURRENT_REQUESTS,
+                None,
+                None,
+                DEFAULT_MAX_RATE_LIMIT_RETRIES,
+                None,
+                Some(HeaderValue::from_static(DEFAULT_USER_AGENT)),
+            )
+        }
+
+        fn base_urls(&self) -> &BaseUrls {
+            &self.base_urls
+        }
+
+        fn last_retry_after(&self) -> &RwLock<Option<Duration>> {
+            &self.last_retry_after
+        }
+
+        fn response_cache(&self) -> Option<&ResponseCache> {
+            self.response_cache.as_deref()
+        }
+
+        fn retry_policy(&self) -> Option<&RetryPolicy> {
+            self.retry_policy.as_deref()
+        }
+
+        fn max_rate_limit_retries(&self) -> u32 {
+            self.max_rate_limit_retries
+        }
+
+        fn default_market(&self) -> Option<Market> {
+            self.default_market
         }
     }
 
@@ -15,21 +153,145 @@ mod async_client {
         type Target = reqwest::Client;
 
         fn deref(&self) -> &Self::Target {
-            &self.0
+            &self.http_client
         }
     }
 }
 
 #[cfg(feature = "sync")]
 mod sync_client {
-    use std::ops::Deref;
+    use std::{
+        ops::Deref,
+        sync::{Arc, RwLock},
+        time::Duration,
+    };
+
+    use reqwest::{
+        header::{self, HeaderValue},
+        IntoUrl, Method,
+    };
+
+    use super::BaseUrls;
+    use crate::client::response_cache::ResponseCache;
+    use crate::client::{RetryPolicy, ACCOUNTS_API_TOKEN_ENDPOINT, DEFAULT_MAX_RATE_LIMIT_RETRIES, DEFAULT_USER_AGENT};
+    use crate::model::Market;
 
     #[derive(Clone, Debug)]
-    pub struct SyncClient(pub(crate) reqwest::blocking::Client);
+    pub struct SyncClient {
+        http_client: reqwest::blocking::Client,
+        // the client credentials authorization sent with token requests, if the client has a client secret
+        client_authorization: Option<HeaderValue>,
+        base_urls: Arc<BaseUrls>,
+        last_retry_after: Arc<RwLock<Option<Duration>>>,
+        response_cache: Option<Arc<ResponseCache>>,
+        retry_policy: Option<Arc<RetryPolicy>>,
+        max_rate_limit_retries: u32,
+        default_market: Option<Market>,
+        user_agent: Option<HeaderValue>,
+    }
+
+    impl SyncClient {
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn from_http_client(
+            http_client: reqwest::blocking::Client,
+            client_authorization: Option<HeaderValue>,
+            base_urls: BaseUrls,
+            response_cache: Option<ResponseCache>,
+            retry_policy: Option<RetryPolicy>,
+            max_rate_limit_retries: u32,
+            default_market: Option<Market>,
+            user_agent: Option<HeaderValue>,
+        ) -> Self {
+            Self {
+                http_client,
+                client_authorization,
+                base_urls: Arc::new(base_urls),
+                last_retry_after: Arc::new(RwLock::new(None)),
+                response_cache: response_cache.map(Arc::new),
+                retry_policy: retry_policy.map(Arc::new),
+                max_rate_limit_retries,
+                default_market,
+                user_agent,
+            }
+        }
+
+        /// Returns a clone of the client with its own, empty response cache if the cache is enabled. The cache is keyed
+        /// only by URL, so every user client gets its own to never serve one user's responses to another.
+        pub(crate) fn with_own_response_cache(&self) -> Self {
+            Self {
+                response_cache: self
+                    .response_cache
+                    .as_ref()
+                    .map(|cache| Arc::new(ResponseCache::new(cache.capacity()))),
+                ..self.clone()
+            }
+        }
+
+        /// Returns a new request to Spotify's token endpoint with the client credentials authorization, if any, filled
+        /// in.
+        pub(crate) fn token_request(&self) -> reqwest::blocking::RequestBuilder {
+            let request = self.request(
+                Method::POST,
+                self.base_urls.accounts_endpoint(ACCOUNTS_API_TOKEN_ENDPOINT),
+            );
+
+            match &self.client_authorization {
+                Some(authorization) => request.header(header::AUTHORIZATION, authorization.clone()),
+                None => request,
+            }
+        }
+
+        /// Returns a new request with the client's user agent, if any, filled in. Every request the client sends is built
+        /// with this function.
+        pub(crate) fn request<U>(&self, method: Method, url: U) -> reqwest::blocking::RequestBuilder
+        where
+            U: IntoUrl,
+        {
+            let request = self.http_client.request(method, url);
+
+            match &self.user_agent {
+                Some(user_agent) => request.header(header::USER_AGENT, user_agent.clone()),
+                None => request,
+            }
+        }
+    }
 
     impl super::HttpClient for SyncClient {
         fn new() -> Self {
-            Self(reqwest::blocking::Client::new())
+            Self::from_http_client(
+                reqwest::blocking::Client::new(),
+                None,
+                BaseUrls::default(),
+                None,
+                None,
+                DEFAULT_MAX_RATE_LIMIT_RETRIES,
+                None,
+                Some(HeaderValue::from_static(DEFAULT_USER_AGENT)),
+            )
+        }
+
+        fn base_urls(&self) -> &BaseUrls {
+            &self.base_urls
+        }
+
+        fn last_retry_after(&self) -> &RwLock<Option<Duration>> {
+            &self.last_retry_after
+        }
+
+        fn response_cache(&self) -> Option<&ResponseCache> {
+            self.response_cache.as_deref()
+        }
+
+        fn retry_policy(&self) -> Option<&RetryPolicy> {
+            self.retry_policy.as_deref()
+        }
+
+        fn max_rate_limit_retries(&self) -> u32 {
+            self.max_rate_limit_retries
+        }
+
+        fn default_market(&self) -> Option<Market> {
+            self.default_market
         }
     }
 
@@ -37,26 +299,122 @@ mod sync_client {
         type Target = reqwest::blocking::Client;
 
         fn deref(&self) -> &Self::Target {
-            &self.0
+            &self.http_client
         }
     }
 }
 
-use reqwest::{IntoUrl, Method};
+use std::{sync::RwLock, time::Duration};
+
+use reqwest::{IntoUrl, Method, Url};
 
 #[cfg(feature = "async")]
 pub use self::async_client::AsyncClient;
 #[cfg(feature = "sync")]
 pub use self::sync_client::SyncClient;
-use crate::error::Result;
+use super::{response_cache::ResponseCache, RetryPolicy, ACCOUNTS_BASE_URL, API_BASE_URL};
+use crate::{error::Result, model::Market, scope::Scope};
 
 pub trait HttpClient {
     fn new() -> Self;
+
+    fn base_urls(&self) -> &BaseUrls;
+
+    fn last_retry_after(&self) -> &RwLock<Option<Duration>>;
+
+    fn response_cache(&self) -> Option<&ResponseCache>;
+
+    fn retry_policy(&self) -> Option<&RetryPolicy>;
+
+    fn max_rate_limit_retries(&self) -> u32;
+
+    fn default_market(&self) -> Option<Market>;
+}
+
+/// The base URLs the API and the accounts endpoints are relative to.
+#[derive(Debug, Clone)]
+pub struct BaseUrls {
+    pub api: Url,
+    pub accounts: Url,
+}
+
+impl BaseUrls {
+    /// Returns the URL of the given accounts endpoint.
+    pub fn accounts_endpoint(&self, endpoint: &str) -> Url {
+        self.accounts
+            .join(endpoint)
+            .unwrap_or_else(|_| panic!("failed to build URL from accounts base URL: {}", self.accounts))
+    }
+}
+
+impl Default for BaseUrls {
+    fn default() -> Self {
+        Self {
+            api: Url::parse(API_BASE_URL).expect("failed to parse API base URL (this is likely a bug)"),
+            accounts: Url::parse(ACCOUNTS_BASE_URL).expect("failed to parse accounts base URL (this is likely a bug)"),
+        }
+    }
+}
+
+/// Every Spotify client that may send requests to the API implements this trait.
+pub trait ApiBaseUrl: crate::private::Sealed {
+    /// Returns the base URL the API endpoints are relative to.
+    fn api_base_url(&self) -> &Url;
+}
+
+/// Every Spotify client that may send requests to the API implements this trait.
+pub trait RetryAfterState: crate::private::Sealed {
+    /// Returns the wait time from the latest rate limited response the client received.
+    fn retry_after_state(&self) -> &RwLock<Option<Duration>>;
+
+    /// Returns the maximum amount of times a request is retried after being rate limited, unless the request overrides
+    /// it.
+    fn max_rate_limit_retries(&self) -> u32;
+
+    fn save_retry_after(&self, retry_after: Duration) {
+        *self
+            .retry_after_state()
+            .write()
+            .expect("last retry after rwlock poisoned") = Some(retry_after);
+    }
+}
+
+/// Every Spotify client that may send requests to the API implements this trait.
+pub trait ResponseCacheState: crate::private::Sealed {
+    /// Returns the cache of response bodies for conditional requests, if it's enabled.
+    fn response_cache(&self) -> Option<&ResponseCache>;
+}
+
+/// Every Spotify client that may send requests to the API implements this trait.
+pub trait RetryPolicyState: crate::private::Sealed {
+    /// Returns the policy for retrying requests that fail because of transient errors, if one is configured.
+    fn retry_policy(&self) -> Option<&RetryPolicy>;
+}
+
+/// Every Spotify client that may send requests to the API implements this trait.
+pub trait DefaultMarketState: crate::private::Sealed {
+    /// Returns the market applied to requests that accept a market but weren't given one, if one is configured.
+    fn default_market(&self) -> Option<Market>;
+}
+
+/// Every asynchronous Spotify client that may send requests to the API implements this trait.
+#[cfg(feature = "async")]
+pub trait ConcurrentRequestLimit: crate::private::Sealed {
+    /// Returns the semaphore that bounds how many chunked sub-requests the client sends concurrently.
+    fn concurrent_requests(&self) -> &tokio::sync::Semaphore;
 }
 
 /// Every Spotify client implements this trait.
 #[cfg(feature = "async")]
-pub trait BuildHttpRequestAsync: crate::private::Sealed {
+pub trait BuildHttpRequestAsync:
+    crate::private::Sealed
+    + ApiBaseUrl
+    + RetryAfterState
+    + ResponseCacheState
+    + RetryPolicyState
+    + DefaultMarketState
+    + ConcurrentRequestLimit
+{
     /// Returns a new async [RequestBuilder](reqwest::RequestBuilder) with any necessary information (e.g.
     /// authentication headers) filled in. This method doesn't account for any known Spotify error responses
     /// automatically; for that you should use [send_http_request](SendHttpRequestAsync::send_http_request)
@@ -67,7 +425,9 @@ pub trait BuildHttpRequestAsync: crate::private::Sealed {
 
 /// Every Spotify client implements this trait.
 #[cfg(feature = "sync")]
-pub trait BuildHttpRequestSync: crate::private::Sealed {
+pub trait BuildHttpRequestSync:
+    crate::private::Sealed + ApiBaseUrl + RetryAfterState + ResponseCacheState + RetryPolicyState + DefaultMarketState
+{
     /// Returns a new async [RequestBuilder](reqwest::blocking::RequestBuilder) with any necessary information (e.g.
     /// authentication headers) filled in. This method doesn't account for any known Spotify error responses
     /// automatically; for that you should use [send_http_request](SendHttpRequestAsync::send_http_request)
@@ -82,12 +442,33 @@ pub trait BuildHttpRequestSync: crate::private::Sealed {
 pub trait AccessTokenExpiryAsync: crate::private::Sealed {
     // If specialization was a thing, this function could be refactored into two generic trait impls
     async fn handle_access_token_expired(&self) -> Result<AccessTokenExpiryResult>;
+
+    /// Returns whether the client knows its access token has expired, or is about to expire, before sending a request.
+    /// Clients that don't track their access token's expiry return false.
+    fn is_access_token_expired(&self) -> bool {
+        false
+    }
 }
 
 /// Every Spotify client implements this trait.
 #[cfg(feature = "sync")]
 pub trait AccessTokenExpirySync: crate::private::Sealed {
     fn handle_access_token_expired(&self) -> Result<AccessTokenExpiryResult>;
+
+    /// Returns whether the client knows its access token has expired, or is about to expire, before sending a request.
+    /// Clients that don't track their access token's expiry return false.
+    fn is_access_token_expired(&self) -> bool {
+        false
+    }
+}
+
+/// Every Spotify client implements this trait.
+pub trait GrantedScopes: crate::private::Sealed {
+    /// Returns whether the user has granted the given scope to the application. Clients that don't know which scopes
+    /// have been granted return true and let Spotify decide.
+    fn is_scope_granted(&self, _scope: Scope) -> bool {
+        true
+    }
 }
 
 /// Result of having tried to refresh a client's access token.
@@ -8,10 +8,14 @@ mod private {
 
     #[cfg(feature = "async")]
     use super::AsyncResponseHandler;
-    use super::RequestBuilder;
     #[cfg(feature = "sync")]
     use super::SyncResponseHandler;
-    use crate::error::{Error, Result};
+    use super::{RawBody, RequestBuilder};
+    use crate::{
+        client::object::{self, PageBounds},
+        error::{Error, Result},
+        scope::Scope,
+    };
 
     pub trait BaseRequestBuilderContainer<TClient, TResponse, TBody = (), TReturn = TResponse>
     where
@@ -28,16 +32,72 @@ mod private {
         fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, TBody, TReturn>;
         fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, TBody, TReturn>;
 
-        fn replace_body<F>(self, replacer: F) -> Self
+        fn replace_body<F>(mut self, replacer: F) -> Self
         where
             F: FnOnce(TBody) -> TBody,
         {
-            let common = self.take_base_builder();
-            if let Some(body) = common.body {
-                let new_body = (replacer)(body);
-                Self::new_with_body(common.method, common.base_url, new_body, common.client)
+            let common = self.get_base_builder_mut();
+            common.body = common.body.take().map(replacer);
+            self
+        }
+
+        /// Send the given data as the request's body as-is with the given content type, instead of the JSON body.
+        fn with_raw_body(mut self, content_type: &'static str, data: Vec<u8>) -> Self {
+            self.get_base_builder_mut().raw_body = Some(RawBody { content_type, data });
+            self
+        }
+
+        fn with_required_scope(mut self, scope: Scope) -> Self {
+            let common = self.get_base_builder_mut();
+            common.required_scope = Some(scope);
+            common.alternative_scope = None;
+            self
+        }
+
+        /// Require either of the given scopes, such as when an endpoint requires a different scope for public and
+        /// private playlists. If neither is granted, the first one is reported missing.
+        fn with_either_required_scope(mut self, scope: Scope, alternative: Scope) -> Self {
+            let common = self.get_base_builder_mut();
+            common.required_scope = Some(scope);
+            common.alternative_scope = Some(alternative);
+            self
+        }
+
+        /// Fail the request with an [Error::InvalidArgument](crate::error::Error::InvalidArgument) before it is sent.
+        fn with_invalid_argument<S>(self, message: S) -> Self
+        where
+            S: Into<Cow<'static, str>>,
+        {
+            self.with_argument_error(Error::InvalidArgument(message.into()))
+        }
+
+        /// Fail the request with the given error before it is sent.
+        fn with_argument_error(mut self, error: Error) -> Self {
+            self.get_base_builder_mut().argument_error = Some(error);
+            self
+        }
+
+        /// Set the page limit, failing the request before it is sent if the limit is out of the endpoint's bounds.
+        fn with_page_limit(self, limit: u32, bounds: PageBounds) -> Self {
+            let builder = self.append_query(object::PAGE_LIMIT_QUERY, limit.to_string());
+
+            if (1..=bounds.max_limit).contains(&limit) {
+                builder
             } else {
-                Self::new(common.method, common.base_url, common.client)
+                builder.with_argument_error(Error::InvalidPageLimit {
+                    limit,
+                    max: bounds.max_limit,
+                })
+            }
+        }
+
+        /// Set the page offset, failing the request before it is sent if the offset is out of the endpoint's bounds.
+        fn with_page_offset(self, offset: u32, bounds: PageBounds) -> Self {
+            let builder = self.append_query(object::PAGE_OFFSET_QUERY, offset.to_string());
+
+            match bounds.max_offset {
+                Some(max) if offset > max => builder.with_argument_error(Error::InvalidPageOffset { offset, max }),
+                _ => builder,
             }
         }
 
@@ -86,31 +146,62 @@ mod private {
     }
 }
 
+mod browse_builder;
 mod catalog_item_builder;
+mod chunked_builder;
+mod followed_artists_builder;
+mod paged_builder;
 mod player_control_builder;
+mod playlist_builder;
+mod recently_played_builder;
+mod recommendations_builder;
 mod search_builder;
 
-use std::{borrow::Cow, collections::HashMap, fmt::Debug, marker::PhantomData};
+use std::{borrow::Cow, collections::HashMap, fmt::Debug, marker::PhantomData, time::Duration};
 #[cfg(feature = "async")]
 use std::{future::Future, pin::Pin};
 
-use log::{error, info, trace, warn};
-use reqwest::{header, header::HeaderMap, Method, StatusCode, Url};
+use log::{debug, error, info, trace, warn};
+use reqwest::{
+    header,
+    header::{HeaderMap, HeaderValue},
+    Method, StatusCode, Url,
+};
 use serde::{de::DeserializeOwned, Serialize};
 
 pub(crate) use self::private::{BaseRequestBuilderContainer, TryFromEmptyResponse};
 pub use self::{
+    browse_builder::{
+        BaseBrowseRequestBuilder, CategoriesRequestBuilder, CategoryPlaylistsRequestBuilder,
+        FeaturedPlaylistsRequestBuilder, NewReleasesRequestBuilder,
+    },
     catalog_item_builder::CatalogItemRequestBuilder,
+    chunked_builder::{ChunkedCatalogItemRequestBuilder, ChunkedRequestBuilder},
+    followed_artists_builder::FollowedArtistsRequestBuilder,
+    paged_builder::{ArtistAlbumsRequestBuilder, PagedRequestBuilder},
     player_control_builder::{
         BasePlayerControlRequestBuilder, PlayContextRequestBuilder, PlayItemsRequestBuilder,
         PlayerControlRequestBuilder,
     },
+    playlist_builder::{
+        AddPlaylistItemsRequestBuilder, BasePlaylistItemsRequestBuilder, ChangePlaylistDetailsRequestBuilder,
+        CreatePlaylistRequestBuilder, RemovePlaylistItemsByPositionRequestBuilder, RemovePlaylistItemsRequestBuilder,
+        ReorderPlaylistItemsRequestBuilder, ReplacePlaylistItemsRequestBuilder,
+    },
+    recently_played_builder::RecentlyPlayedRequestBuilder,
+    recommendations_builder::RecommendationsBuilder,
     search_builder::SearchBuilder,
 };
 use crate::{
-    client::private::AccessTokenExpiryResult,
+    client::{
+        object,
+        private::AccessTokenExpiryResult,
+        response_cache::{CachedResponse, ResponseCache},
+        retry_policy::RetryCause,
+    },
     error::{Error, Result},
     model::error::{ApiErrorMessage, ApiErrorResponse},
+    scope::Scope,
 };
 
 #[cfg(feature = "async")]
@@ -126,25 +217,58 @@ fn async_response_handler_noop(
     resp: reqwest::Response,
 ) -> Pin<Box<dyn Future<Output = Result<reqwest::Response>> + Send>> {
     Box::pin(async move {
-        match resp.error_for_status() {
-            Ok(resp) => Ok(resp),
-            Err(e) => Err(response_error_to_unhandled_code(e)),
+        if resp.status().is_client_error() || resp.status().is_server_error() {
+            Err(api_error_from_response_async(resp).await)
+        } else {
+            Ok(resp)
         }
     })
 }
 
 #[cfg(feature = "sync")]
 fn sync_response_handler_noop(resp: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
-    match resp.error_for_status() {
-        Ok(resp) => Ok(resp),
-        Err(e) => Err(response_error_to_unhandled_code(e)),
+    if resp.status().is_client_error() || resp.status().is_server_error() {
+        Err(api_error_from_response_sync(resp))
+    } else {
+        Ok(resp)
     }
 }
 
-fn response_error_to_unhandled_code(err: reqwest::Error) -> Error {
-    match err.status() {
-        Some(status) => Error::UnhandledSpotifyResponseStatusCode(status.as_u16()),
-        None => err.into(),
+/// Returns an [Error::Api] from an unexpected error response's body. If the body isn't an API error, returns an
+/// [Error::UnhandledSpotifyResponseStatusCode] instead.
+#[cfg(feature = "async")]
+pub(crate) async fn api_error_from_response_async(response: reqwest::Response) -> Error {
+    let status = response.status().as_u16();
+
+    match response.json::<ApiErrorResponse>().await {
+        Ok(error_response) => {
+            warn!("Error response: {error_response:?}");
+            Error::Api(error_response.error)
+        }
+
+        Err(e) => {
+            warn!("Failed to parse error response with status {status}: {e}");
+            Error::UnhandledSpotifyResponseStatusCode(status)
+        }
+    }
+}
+
+/// Returns an [Error::Api] from an unexpected error response's body. If the body isn't an API error, returns an
+/// [Error::UnhandledSpotifyResponseStatusCode] instead.
+#[cfg(feature = "sync")]
+pub(crate) fn api_error_from_response_sync(response: reqwest::blocking::Response) -> Error {
+    let status = response.status().as_u16();
+
+    match response.json::<ApiErrorResponse>() {
+        Ok(error_response) => {
+            warn!("Error response: {error_response:?}");
+            Error::Api(error_response.error)
+        }
+
+        Err(e) => {
+            warn!("Failed to parse error response with status {status}: {e}");
+            Error::UnhandledSpotifyResponseStatusCode(status)
+        }
     }
 }
 
@@ -153,12 +277,22 @@ pub trait BaseRequestBuilder<TClient, TResponse, TBody, TReturn>
 where
     Self: private::BaseRequestBuilderContainer<TClient, TResponse, TBody, TReturn> + Sized,
 {
-    /// Whether or not to react to being rate limited by waiting the wanted time in the response. Defaults to `true`.
+    /// Whether or not to react to being rate limited by waiting the wanted time in the response and retrying the
+    /// request. Defaults to `true`.
     fn react_to_rate_limit(mut self, react_to_rate_limit: bool) -> Self {
         self.get_base_builder_mut().react_to_rate_limit = react_to_rate_limit;
         self
     }
 
+    /// The maximum amount of times to retry the request after being rate limited, if [reacting to rate
+    /// limits](BaseRequestBuilder::react_to_rate_limit) is enabled. Once the retries are exhausted, the request fails
+    /// with [Error::RateLimit]. By default, the client's
+    /// [maximum](crate::client::SpotifyClientBuilder::max_rate_limit_retries) is used.
+    fn max_rate_limit_retries(mut self, max_rate_limit_retries: u32) -> Self {
+        self.get_base_builder_mut().max_rate_limit_retries = Some(max_rate_limit_retries);
+        self
+    }
+
     /// Whether or not to automatically refresh the client's access token, if applicable, when it expires. Defaults to
     /// `true`.
     fn auto_refresh_access_token(mut self, auto_refresh_access_token: bool) -> Self {
@@ -170,7 +304,7 @@ where
 fn handle_403_forbidden_api_response(error_response: ApiErrorResponse) -> Result<()> {
     warn!("Error response: {error_response:?}");
 
-    match error_response.error.message {
+    match error_response.error.message_kind() {
         ApiErrorMessage::RestrictionViolated => Err(Error::Restricted),
         ApiErrorMessage::PremiumRequired => Err(Error::PremiumRequired),
 
@@ -182,26 +316,87 @@ fn handle_403_forbidden_api_response(error_response: ApiErrorResponse) -> Result
 
 /// Returns Ok if the given API error response is because of an expired token. Else, returns an error based on the API
 /// error response.
-fn is_api_error_expired_access_token(error_response: ApiErrorResponse) -> Result<()> {
-    match error_response.error.message {
+fn is_api_error_expired_access_token(error_response: ApiErrorResponse, required_scope: Option<Scope>) -> Result<()> {
+    match error_response.error.message_kind() {
         ApiErrorMessage::TokenExpired => {
             warn!("Access token expired, attempting to refresh");
             Ok(())
         }
 
-        ApiErrorMessage::PermissionsMissing => {
-            error!("Missing required scope for the endpoint");
-            Err(Error::MissingScope)
-        }
+        ApiErrorMessage::PermissionsMissing => match required_scope {
+            Some(scope) => {
+                error!("Missing required scope for the endpoint: {scope}");
+                Err(Error::MissingScope(scope))
+            }
+
+            None => {
+                error!("Missing permissions for an endpoint that doesn't require any scope");
+                Err(Error::Api(error_response.error))
+            }
+        },
 
         other => {
-            error!("Unexpected Spotify error: {:?}", other);
-            Err(Error::UnhandledSpotifyResponseStatusCode(401))
+            error!("Access token rejected: {:?}", other);
+            Err(Error::Unauthorized(error_response.error.message))
         }
     }
 }
 
-fn extract_rate_limit_retry_after(headers: &HeaderMap) -> Result<u64> {
+/// Returns a span for sending a request to the given URL. Only the URL's path is recorded in the span, and the
+/// request's headers, which include the access token, are never recorded.
+#[cfg(feature = "tracing")]
+fn request_span(method: &Method, url: &Url) -> tracing::Span {
+    tracing::debug_span!(
+        "request",
+        method = %method,
+        path = url.path(),
+        status = tracing::field::Empty
+    )
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+async fn send_http_request_async(
+    request: reqwest::RequestBuilder,
+    method: &Method,
+    url: &Url,
+) -> reqwest::Result<reqwest::Response> {
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+
+        let span = request_span(method, url);
+        let response = request.send().instrument(span.clone()).await?;
+        span.record("status", response.status().as_u16());
+
+        Ok(response)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    request.send().await
+}
+
+#[cfg(feature = "sync")]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn send_http_request_sync(
+    request: reqwest::blocking::RequestBuilder,
+    method: &Method,
+    url: &Url,
+) -> reqwest::Result<reqwest::blocking::Response> {
+    #[cfg(feature = "tracing")]
+    {
+        let span = request_span(method, url);
+        let response = span.in_scope(|| request.send())?;
+        span.record("status", response.status().as_u16());
+
+        Ok(response)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    request.send()
+}
+
+fn extract_rate_limit_retry_after(headers: &HeaderMap) -> Result<Duration> {
     if let Some(wait_time) = headers
         .get(header::RETRY_AFTER)
         .and_then(|header| header.to_str().ok())
@@ -212,13 +407,58 @@ fn extract_rate_limit_retry_after(headers: &HeaderMap) -> Result<u64> {
             wait_time
         );
 
-        Ok(wait_time)
+        Ok(Duration::from_secs(wait_time))
     } else {
         warn!("Invalid rate-limit response");
         Err(Error::InvalidRateLimitResponse)
     }
 }
 
+/// Returns how long to wait before retrying a request that failed for the given cause, if the client's retry policy
+/// allows retrying it after it has already been retried the given amount of times.
+fn retry_delay<C>(client: &C, method: &Method, cause: Option<RetryCause>, retries: u32) -> Option<Duration>
+where
+    C: super::private::RetryPolicyState,
+{
+    let policy = client.retry_policy()?;
+    let attempts = retries + 1;
+
+    policy
+        .should_retry(method, cause?, attempts)
+        .then(|| policy.delay(attempts))
+}
+
+/// Returns the client's cached response for the given URL, if the request may be answered from the cache.
+fn cached_response<C>(client: &C, method: &Method, url: &Url) -> Option<CachedResponse>
+where
+    C: super::private::ResponseCacheState,
+{
+    if method != Method::GET {
+        return None;
+    }
+
+    client.response_cache()?.get(url)
+}
+
+/// Returns the client's response cache and the response's entity tag, if the response should be cached.
+fn response_cache_entry<'a, C>(
+    client: &'a C,
+    method: &Method,
+    headers: &HeaderMap,
+) -> Option<(&'a ResponseCache, HeaderValue)>
+where
+    C: super::private::ResponseCacheState,
+{
+    if method != Method::GET {
+        return None;
+    }
+
+    let cache = client.response_cache()?;
+    let etag = headers.get(header::ETAG)?.clone();
+
+    Some((cache, etag))
+}
+
 /// Asynchronous request builder functionality, namely sending the request and processing its response asynchronously.
 #[cfg(feature = "async")]
 #[async_trait::async_trait]
@@ -228,18 +468,40 @@ where
     TBody: Debug + Serialize + Send,
     TResponse: Debug + DeserializeOwned + TryFromEmptyResponse + Send + Sync,
     TReturn: TryFrom<TResponse> + Send + Sync,
-    TClient: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    TClient: super::private::BuildHttpRequestAsync
+        + super::private::AccessTokenExpiryAsync
+        + super::private::GrantedScopes
+        + Send
+        + Sync,
     Error: From<<TReturn as TryFrom<TResponse>>::Error>,
 {
     /// Send the request asynchronously and process the response, extracting the result object from the body.
     async fn send_async(self) -> Result<TReturn> {
-        let common = self.take_base_builder();
-        let url = common.build_url();
+        let mut common = self.take_base_builder();
+        common.check_arguments()?;
+        common.check_required_scope()?;
+        let url = common.build_request_url();
+        let mut rate_limit_retries = 0;
+        let mut retries = 0;
 
         loop {
+            if common.auto_refresh_access_token && common.client.is_access_token_expired() {
+                info!("Access token expired or about to expire, refreshing before sending request");
+                common.client.handle_access_token_expired().await?;
+            }
+
             let mut request = common.client.build_http_request(common.method.clone(), url.clone());
 
-            if let Some(body) = &common.body {
+            if let Some(raw_body) = &common.raw_body {
+                trace!(
+                    "Raw request body: {} bytes of {}",
+                    raw_body.data.len(),
+                    raw_body.content_type
+                );
+                request = request
+                    .header(header::CONTENT_TYPE, raw_body.content_type)
+                    .body(raw_body.data.clone());
+            } else if let Some(body) = &common.body {
                 trace!("Request body: {:?}", body);
                 request = request.json(body);
             } else {
@@ -254,15 +516,57 @@ where
                 }
             }
 
-            let response = request.send().await?;
+            let cached = cached_response(&common.client, &common.method, &url);
+
+            if let Some(cached) = &cached {
+                request = request.header(header::IF_NONE_MATCH, cached.etag.clone());
+            }
+
+            let response = match send_http_request_async(request, &common.method, &url).await {
+                Ok(response) => response,
+                Err(e) => {
+                    let cause = RetryCause::from_error(&e);
+
+                    if let Some(delay) = retry_delay(&common.client, &common.method, cause, retries) {
+                        if super::retry_sleep_async(delay).await {
+                            warn!(
+                                "Sending request failed, retrying after waiting {} ms: {e}",
+                                delay.as_millis()
+                            );
+                            retries += 1;
+                            continue;
+                        }
+                    }
+
+                    return Err(e.into());
+                }
+            };
+
+            if let Some(cached) = cached.filter(|_| response.status() == StatusCode::NOT_MODIFIED) {
+                debug!("Got 304 Not Modified response, using cached response body");
+
+                let response_body: TResponse = serde_json::from_slice(&cached.body)?;
+                trace!("Body: {response_body:?}");
+                return Ok(response_body.try_into()?);
+            }
+
+            let cause = Some(RetryCause::Status(response.status()));
+            if let Some(delay) = retry_delay(&common.client, &common.method, cause, retries) {
+                if super::retry_sleep_async(delay).await {
+                    warn!(
+                        "Got {} response, retrying after waiting {} ms",
+                        response.status(),
+                        delay.as_millis()
+                    );
+                    retries += 1;
+                    continue;
+                }
+            }
 
             match response.status() {
                 StatusCode::BAD_REQUEST => {
                     error!("Got 400 Bad Request response");
-                    let error_response = response.text().await?;
-                    warn!("Error response: {error_response}");
-
-                    return Err(Error::UnhandledSpotifyResponseStatusCode(400));
+                    return Err(api_error_from_response_async(response).await);
                 }
 
                 StatusCode::FORBIDDEN => {
@@ -274,7 +578,7 @@ where
                 StatusCode::UNAUTHORIZED => {
                     warn!("Got 401 Unauthorized response");
                     let error_response = response.json().await?;
-                    is_api_error_expired_access_token(error_response)?;
+                    is_api_error_expired_access_token(error_response, common.required_scope)?;
 
                     // is_api_error_expired_access_token handles all other errors except the access token being expired
                     if !common.auto_refresh_access_token
@@ -291,12 +595,12 @@ where
                 StatusCode::TOO_MANY_REQUESTS => {
                     let headers = response.headers();
                     let retry_after = extract_rate_limit_retry_after(headers)?;
+                    common.client.save_retry_after(retry_after);
 
-                    if common.react_to_rate_limit {
-                        info!("Got rate limited, waiting {retry_after} seconds...");
+                    if common.should_retry_rate_limit(&mut rate_limit_retries) {
+                        info!("Got rate limited, waiting {} seconds...", retry_after.as_secs());
                         super::rate_limit_sleep_async(retry_after).await?;
                     } else {
-                        warn!("Got rate limited {retry_after} seconds and reacting to rate limits is disabled");
                         return Err(Error::RateLimit(retry_after));
                     }
                 }
@@ -307,14 +611,23 @@ where
 
                     let response = response?;
 
-                    // bypass serialization for 204 responses, since it's possible the return type is the unit type, but
-                    // serde/serde_json won't deserialize the unit type from an empty string, instead failing with an
-                    // EOF error
-                    let response_body = if response.status() == StatusCode::NO_CONTENT {
-                        TResponse::try_from_empty_response()?
-                    } else {
-                        response.json().await?
-                    };
+                    // bypass serialization for 204 responses and other empty responses, since it's possible the return
+                    // type is the unit type, but serde/serde_json won't deserialize the unit type from an empty string,
+                    // instead failing with an EOF error
+                    let response_body =
+                        if response.status() == StatusCode::NO_CONTENT || response.content_length() == Some(0) {
+                            TResponse::try_from_empty_response()?
+                        } else if let Some((cache, etag)) =
+                            response_cache_entry(&common.client, &common.method, response.headers())
+                        {
+                            let body = response.bytes().await?.to_vec();
+                            let response_body = serde_json::from_slice(&body)?;
+                            cache.insert(url.clone(), CachedResponse { etag, body });
+
+                            response_body
+                        } else {
+                            response.json().await?
+                        };
 
                     trace!("Body: {response_body:?}");
                     return Ok(response_body.try_into()?);
@@ -332,18 +645,37 @@ where
     TBody: Debug + Serialize,
     TResponse: Debug + DeserializeOwned + TryFromEmptyResponse,
     TReturn: TryFrom<TResponse>,
-    TClient: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    TClient:
+        super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync + super::private::GrantedScopes,
     Error: From<<TReturn as TryFrom<TResponse>>::Error>,
 {
     /// Send the request synchronously and process the response, extracting the result object from the body.
     fn send_sync(self) -> Result<TReturn> {
-        let common = self.take_base_builder();
-        let url = common.build_url();
+        let mut common = self.take_base_builder();
+        common.check_arguments()?;
+        common.check_required_scope()?;
+        let url = common.build_request_url();
+        let mut rate_limit_retries = 0;
+        let mut retries = 0;
 
         loop {
+            if common.auto_refresh_access_token && common.client.is_access_token_expired() {
+                info!("Access token expired or about to expire, refreshing before sending request");
+                common.client.handle_access_token_expired()?;
+            }
+
             let mut request = common.client.build_http_request(common.method.clone(), url.clone());
 
-            if let Some(body) = &common.body {
+            if let Some(raw_body) = &common.raw_body {
+                trace!(
+                    "Raw request body: {} bytes of {}",
+                    raw_body.data.len(),
+                    raw_body.content_type
+                );
+                request = request
+                    .header(header::CONTENT_TYPE, raw_body.content_type)
+                    .body(raw_body.data.clone());
+            } else if let Some(body) = &common.body {
                 trace!("Request body: {:?}", body);
                 request = request.json(body);
             } else {
@@ -358,15 +690,57 @@ where
                 }
             }
 
-            let response = request.send()?;
+            let cached = cached_response(&common.client, &common.method, &url);
+
+            if let Some(cached) = &cached {
+                request = request.header(header::IF_NONE_MATCH, cached.etag.clone());
+            }
+
+            let response = match send_http_request_sync(request, &common.method, &url) {
+                Ok(response) => response,
+                Err(e) => {
+                    let cause = RetryCause::from_error(&e);
+
+                    if let Some(delay) = retry_delay(&common.client, &common.method, cause, retries) {
+                        if super::retry_sleep_sync(delay) {
+                            warn!(
+                                "Sending request failed, retrying after waiting {} ms: {e}",
+                                delay.as_millis()
+                            );
+                            retries += 1;
+                            continue;
+                        }
+                    }
+
+                    return Err(e.into());
+                }
+            };
+
+            if let Some(cached) = cached.filter(|_| response.status() == StatusCode::NOT_MODIFIED) {
+                debug!("Got 304 Not Modified response, using cached response body");
+
+                let response_body: TResponse = serde_json::from_slice(&cached.body)?;
+                trace!("Body: {response_body:?}");
+                return Ok(response_body.try_into()?);
+            }
+
+            let cause = Some(RetryCause::Status(response.status()));
+            if let Some(delay) = retry_delay(&common.client, &common.method, cause, retries) {
+                if super::retry_sleep_sync(delay) {
+                    warn!(
+                        "Got {} response, retrying after waiting {} ms",
+                        response.status(),
+                        delay.as_millis()
+                    );
+                    retries += 1;
+                    continue;
+                }
+            }
 
             match response.status() {
                 StatusCode::BAD_REQUEST => {
                     error!("Got 400 Bad Request response");
-                    let error_response = response.text()?;
-                    warn!("Error response: {error_response}");
-
-                    return Err(Error::UnhandledSpotifyResponseStatusCode(400));
+                    return Err(api_error_from_response_sync(response));
                 }
 
                 StatusCode::FORBIDDEN => {
@@ -380,7 +754,7 @@ where
                     let error_response = response.json()?;
                     warn!("Error response: {error_response:?}");
 
-                    is_api_error_expired_access_token(error_response)?;
+                    is_api_error_expired_access_token(error_response, common.required_scope)?;
 
                     // is_api_error_expired_access_token handles all other errors except the access token being expired
                     if !common.auto_refresh_access_token
@@ -397,12 +771,12 @@ where
                 StatusCode::TOO_MANY_REQUESTS => {
                     let headers = response.headers();
                     let retry_after = extract_rate_limit_retry_after(headers)?;
+                    common.client.save_retry_after(retry_after);
 
-                    if common.react_to_rate_limit {
-                        info!("Got rate limited, waiting {retry_after} seconds...");
+                    if common.should_retry_rate_limit(&mut rate_limit_retries) {
+                        info!("Got rate limited, waiting {} seconds...", retry_after.as_secs());
                         super::rate_limit_sleep_sync(retry_after)?;
                     } else {
-                        warn!("Got rate limited ({retry_after}) and reacting to rate limits is disabled");
                         return Err(Error::RateLimit(retry_after));
                     }
                 }
@@ -413,14 +787,23 @@ where
 
                     let response = response?;
 
-                    // bypass serialization for 204 responses, since it's possible the return type is the unit type, but
-                    // serde/serde_json won't deserialize the unit type from an empty string, instead failing with an
-                    // EOF error
-                    let response_body = if response.status() == StatusCode::NO_CONTENT {
-                        TResponse::try_from_empty_response()?
-                    } else {
-                        response.json()?
-                    };
+                    // bypass serialization for 204 responses and other empty responses, since it's possible the return
+                    // type is the unit type, but serde/serde_json won't deserialize the unit type from an empty string,
+                    // instead failing with an EOF error
+                    let response_body =
+                        if response.status() == StatusCode::NO_CONTENT || response.content_length() == Some(0) {
+                            TResponse::try_from_empty_response()?
+                        } else if let Some((cache, etag)) =
+                            response_cache_entry(&common.client, &common.method, response.headers())
+                        {
+                            let body = response.bytes()?.to_vec();
+                            let response_body = serde_json::from_slice(&body)?;
+                            cache.insert(url.clone(), CachedResponse { etag, body });
+
+                            response_body
+                        } else {
+                            response.json()?
+                        };
 
                     trace!("Body: {response_body:?}");
                     return Ok(response_body.try_into()?);
@@ -440,6 +823,7 @@ pub struct RequestBuilder<TClient, TResponse, TBody = (), TReturn = TResponse> {
     base_url: Cow<'static, str>,
     query_params: HashMap<&'static str, Cow<'static, str>>,
     body: Option<TBody>,
+    raw_body: Option<RawBody>,
 
     #[cfg(feature = "async")]
     async_response_handler: AsyncResponseHandler,
@@ -447,15 +831,139 @@ pub struct RequestBuilder<TClient, TResponse, TBody = (), TReturn = TResponse> {
     sync_response_handler: SyncResponseHandler,
 
     react_to_rate_limit: bool,
+    max_rate_limit_retries: Option<u32>,
     auto_refresh_access_token: bool,
+    required_scope: Option<Scope>,
+    // a scope that may be granted instead of the required scope
+    alternative_scope: Option<Scope>,
+    argument_error: Option<Error>,
+    // whether the endpoint accepts a market, in which case the client's default market is applied if none is given
+    default_market: bool,
 
     phantom: PhantomData<(TReturn, TResponse)>,
 }
 
+/// A request body that is sent as-is instead of being serialized into JSON.
+struct RawBody {
+    content_type: &'static str,
+    data: Vec<u8>,
+}
+
+impl<TClient, TResponse, TBody, TReturn> RequestBuilder<TClient, TResponse, TBody, TReturn>
+where
+    TClient: super::private::ApiBaseUrl,
+{
+    pub(crate) fn build_url(&self) -> Url {
+        // endpoint URLs are relative to the client's API base URL, while e.g. next page URLs from Spotify are absolute
+        let mut url = self
+            .client
+            .api_base_url()
+            .join(&self.base_url)
+            .unwrap_or_else(|_| panic!("failed to build URL from base: {}", self.base_url));
+
+        if !self.query_params.is_empty() {
+            url.query_pairs_mut().extend_pairs(&self.query_params);
+        }
+
+        url
+    }
+
+    /// Builds the URL the request is sent to. If the endpoint accepts a market and none was given, the client's default
+    /// market is applied.
+    fn build_request_url(&self) -> Url
+    where
+        TClient: super::private::DefaultMarketState,
+    {
+        let mut url = self.build_url();
+
+        if self.default_market && !url.query_pairs().any(|(key, _)| key == object::MARKET_QUERY) {
+            if let Some(market) = self.client.default_market() {
+                url.query_pairs_mut().append_pair(object::MARKET_QUERY, market.as_str());
+            }
+        }
+
+        url
+    }
+}
+
 impl<TClient, TResponse, TBody, TReturn> RequestBuilder<TClient, TResponse, TBody, TReturn> {
-    fn build_url(&self) -> Url {
-        Url::parse_with_params(&self.base_url, &self.query_params)
-            .unwrap_or_else(|_| panic!("failed to build URL from base: {}", self.base_url))
+    /// Apply the client's default market to the request if it isn't given a market.
+    pub(crate) fn with_default_market(self) -> Self {
+        Self {
+            default_market: true,
+            ..self
+        }
+    }
+
+    #[cfg(all(test, feature = "async"))]
+    pub(crate) fn body(&self) -> Option<&TBody> {
+        self.body.as_ref()
+    }
+
+    #[cfg(all(test, feature = "async"))]
+    pub(crate) fn raw_body(&self) -> Option<(&str, &[u8])> {
+        self.raw_body
+            .as_ref()
+            .map(|raw_body| (raw_body.content_type, raw_body.data.as_slice()))
+    }
+
+    #[cfg(all(test, feature = "async"))]
+    pub(crate) fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Returns whether the request should be retried after being rate limited, counting the retry if so.
+    fn should_retry_rate_limit(&self, rate_limit_retries: &mut u32) -> bool
+    where
+        TClient: super::private::RetryAfterState,
+    {
+        if !self.react_to_rate_limit {
+            warn!("Got rate limited and reacting to rate limits is disabled");
+            return false;
+        }
+
+        let max_rate_limit_retries = self
+            .max_rate_limit_retries
+            .unwrap_or_else(|| self.client.max_rate_limit_retries());
+
+        if *rate_limit_retries >= max_rate_limit_retries {
+            warn!("Got rate limited and the maximum amount of rate limit retries has been reached");
+            return false;
+        }
+
+        *rate_limit_retries += 1;
+        true
+    }
+
+    /// Returns an error if one of the arguments given to the endpoint function is invalid.
+    fn check_arguments(&mut self) -> Result<()> {
+        match self.argument_error.take() {
+            Some(error) => {
+                error!("Invalid argument for the endpoint: {error}");
+                Err(error)
+            }
+
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if the endpoint requires a scope the client knows the user hasn't granted.
+    fn check_required_scope(&self) -> Result<()>
+    where
+        TClient: super::private::GrantedScopes,
+    {
+        let alternative_granted = self
+            .alternative_scope
+            .is_some_and(|alternative| self.client.is_scope_granted(alternative));
+
+        match self.required_scope {
+            Some(scope) if !self.client.is_scope_granted(scope) && !alternative_granted => {
+                error!("Missing required scope for the endpoint: {scope}");
+                Err(Error::MissingScope(scope))
+            }
+
+            _ => Ok(()),
+        }
     }
 }
 
@@ -472,6 +980,7 @@ impl<TClient, TResponse, TBody, TReturn> private::BaseRequestBuilderContainer<TC
             base_url: base_url.into(),
             query_params: HashMap::new(),
             body: None,
+            raw_body: None,
 
             #[cfg(feature = "async")]
             async_response_handler: Box::new(async_response_handler_noop),
@@ -479,7 +988,12 @@ impl<TClient, TResponse, TBody, TReturn> private::BaseRequestBuilderContainer<TC
             sync_response_handler: Box::new(sync_response_handler_noop),
 
             react_to_rate_limit: true,
+            max_rate_limit_retries: None,
             auto_refresh_access_token: true,
+            required_scope: None,
+            alternative_scope: None,
+            argument_error: None,
+            default_market: false,
 
             phantom: PhantomData,
         }
@@ -517,7 +1031,11 @@ where
     TBody: Debug + Serialize + Send,
     TResponse: Debug + DeserializeOwned + TryFromEmptyResponse + Send + Sync,
     TReturn: TryFrom<TResponse> + Send + Sync,
-    TClient: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    TClient: super::private::BuildHttpRequestAsync
+        + super::private::AccessTokenExpiryAsync
+        + super::private::GrantedScopes
+        + Send
+        + Sync,
     Error: From<<TReturn as TryFrom<TResponse>>::Error>,
 {
 }
@@ -529,7 +1047,352 @@ where
     TBody: Debug + Serialize,
     TResponse: Debug + DeserializeOwned + TryFromEmptyResponse,
     TReturn: TryFrom<TResponse>,
-    TClient: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    TClient:
+        super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync + super::private::GrantedScopes,
     Error: From<<TReturn as TryFrom<TResponse>>::Error>,
 {
 }
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::{Method, Url};
+
+    use super::{AsyncRequestBuilder, BaseRequestBuilder, BaseRequestBuilderContainer, RequestBuilder};
+    use crate::{
+        client::{
+            implicit_grant::AsyncImplicitGrantUserClient,
+            object,
+            test_util::{
+                serve_requests, serve_responses, user_client, user_client_from, user_client_with_token, TRACK_ID,
+                TRACK_JSON,
+            },
+            RateLimitInformation, RetryPolicy, ScopedClient, SpotifyClientBuilder, UnscopedClient,
+        },
+        error::Error,
+        model::{
+            artist::FullArtist,
+            error::ApiError,
+            id::{Id, IdFromBare, IdTrait},
+            playback::PlaybackState,
+            track::{CommonTrackInformation, NonLocalTrackInformation},
+            user::{CommonUserInformation, User},
+        },
+    };
+
+    const RATE_LIMITED_RESPONSE: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const LONG_RATE_LIMITED_RESPONSE: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 30\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const ARTISTS_RESPONSE: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: \
+                                    14\r\nConnection: close\r\n\r\n{\"artists\":[]}";
+
+    const NOT_FOUND_RESPONSE: &str = concat!(
+        "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 47\r\nConnection: close\r\n\r\n",
+        r#"{"error":{"status":404,"message":"Invalid id"}}"#
+    );
+    const NO_CONTENT_RESPONSE: &str = "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n";
+    const SERVER_ERROR_RESPONSE: &str =
+        "HTTP/1.1 502 Bad Gateway\r\nContent-Length: 8\r\nConnection: close\r\n\r\nupstream";
+
+    fn request(
+        url: String,
+    ) -> RequestBuilder<AsyncImplicitGrantUserClient, object::ArtistsResponse, (), Vec<FullArtist>> {
+        RequestBuilder::new(Method::GET, url, user_client())
+    }
+
+    #[tokio::test]
+    async fn rate_limited_request_is_retried() {
+        let (url, server) = serve_responses(vec![RATE_LIMITED_RESPONSE, ARTISTS_RESPONSE]);
+
+        let artists = request(url).send_async().await.unwrap();
+        server.join().unwrap();
+
+        assert!(artists.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rate_limit_retries_are_limited() {
+        let (url, server) = serve_responses(vec![RATE_LIMITED_RESPONSE, RATE_LIMITED_RESPONSE]);
+
+        let result = request(url).max_rate_limit_retries(1).send_async().await;
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::RateLimit(retry_after)) if retry_after.as_secs() == 0));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_retries_are_limited_by_default() {
+        // the first attempt and the three default retries
+        let (url, server) = serve_responses(vec![RATE_LIMITED_RESPONSE; 4]);
+
+        let result = request(url).send_async().await;
+        assert_eq!(server.join().unwrap().len(), 4);
+
+        assert!(matches!(result, Err(Error::RateLimit(_))));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_retries_are_limited_by_client() {
+        let (url, server) = serve_responses(vec![RATE_LIMITED_RESPONSE, RATE_LIMITED_RESPONSE]);
+        let client = user_client_from(SpotifyClientBuilder::new("client ID").max_rate_limit_retries(1));
+
+        let request: RequestBuilder<_, object::ArtistsResponse, (), Vec<FullArtist>> =
+            RequestBuilder::new(Method::GET, url, client);
+        let result = request.send_async().await;
+        assert_eq!(server.join().unwrap().len(), 2);
+
+        assert!(matches!(result, Err(Error::RateLimit(_))));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_without_reacting() {
+        let (url, server) = serve_responses(vec![RATE_LIMITED_RESPONSE]);
+
+        let result = request(url).react_to_rate_limit(false).send_async().await;
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::RateLimit(_))));
+    }
+
+    #[tokio::test]
+    async fn last_retry_after_is_saved() {
+        let (url, server) = serve_responses(vec![LONG_RATE_LIMITED_RESPONSE]);
+        let client = user_client();
+        assert_eq!(client.last_retry_after(), None);
+
+        let result =
+            RequestBuilder::<_, object::ArtistsResponse, (), Vec<FullArtist>>::new(Method::GET, url, client.clone())
+                .react_to_rate_limit(false)
+                .send_async()
+                .await;
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::RateLimit(_))));
+        assert_eq!(client.last_retry_after(), Some(Duration::from_secs(30)));
+    }
+
+    #[tokio::test]
+    async fn api_error_response() {
+        let (url, server) = serve_responses(vec![NOT_FOUND_RESPONSE]);
+
+        let result = request(url).send_async().await;
+        server.join().unwrap();
+
+        match result {
+            Err(Error::Api(ApiError {
+                status: 404, message, ..
+            })) => assert_eq!(message, "Invalid id"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn no_content_playback_state_is_none() {
+        let (url, server) = serve_responses(vec![NO_CONTENT_RESPONSE]);
+
+        let request: RequestBuilder<_, Option<PlaybackState>> = RequestBuilder::new(Method::GET, url, user_client());
+        let playback_state = request.send_async().await.unwrap();
+        server.join().unwrap();
+
+        assert!(playback_state.is_none());
+    }
+
+    #[tokio::test]
+    async fn unparseable_error_response() {
+        let (url, server) = serve_responses(vec![SERVER_ERROR_RESPONSE]);
+
+        let result = request(url).send_async().await;
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::UnhandledSpotifyResponseStatusCode(502))));
+    }
+
+    fn retrying_client() -> AsyncImplicitGrantUserClient {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(1))
+            .jitter(false);
+        user_client_from(SpotifyClientBuilder::new("client ID").retry_policy(policy))
+    }
+
+    #[tokio::test]
+    async fn server_error_is_retried_for_get() {
+        let (url, server) = serve_responses(vec![SERVER_ERROR_RESPONSE, ARTISTS_RESPONSE]);
+
+        let request: RequestBuilder<_, object::ArtistsResponse, (), Vec<FullArtist>> =
+            RequestBuilder::new(Method::GET, url, retrying_client());
+        let artists = request.send_async().await.unwrap();
+
+        assert_eq!(server.join().unwrap().len(), 2);
+        assert!(artists.is_empty());
+    }
+
+    #[tokio::test]
+    async fn server_error_retries_are_limited() {
+        let (url, server) = serve_responses(vec![SERVER_ERROR_RESPONSE; 3]);
+
+        let request: RequestBuilder<_, object::ArtistsResponse, (), Vec<FullArtist>> =
+            RequestBuilder::new(Method::GET, url, retrying_client());
+        let result = request.send_async().await;
+
+        assert_eq!(server.join().unwrap().len(), 3);
+        assert!(matches!(result, Err(Error::UnhandledSpotifyResponseStatusCode(502))));
+    }
+
+    #[tokio::test]
+    async fn server_error_is_not_retried_for_mutating_request() {
+        let (url, server) = serve_responses(vec![SERVER_ERROR_RESPONSE]);
+
+        let request: RequestBuilder<_, ()> = RequestBuilder::new(Method::PUT, url, retrying_client());
+        let result = request.send_async().await;
+
+        assert_eq!(server.join().unwrap().len(), 1);
+        assert!(matches!(result, Err(Error::UnhandledSpotifyResponseStatusCode(502))));
+    }
+
+    #[tokio::test]
+    async fn not_modified_response_uses_cached_body() {
+        let (url, server) = serve_requests(2, |request| match request.header("if-none-match") {
+            Some(_) => "HTTP/1.1 304 Not Modified\r\nETag: \"1\"\r\nConnection: close\r\n\r\n".to_owned(),
+            None => format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nETag: \"1\"\r\nConnection: \
+                 close\r\n\r\n{TRACK_JSON}",
+                TRACK_JSON.len()
+            ),
+        });
+
+        let client = user_client_from(
+            SpotifyClientBuilder::new("client ID")
+                .api_base_url(Url::parse(&url).unwrap())
+                .enable_response_cache(10),
+        );
+
+        let track_id = Id::from_bare(TRACK_ID).unwrap();
+        let first = client.track(track_id.as_borrowed()).send_async().await.unwrap();
+        let second = client.track(track_id).send_async().await.unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].header("if-none-match"), None);
+        assert_eq!(requests[1].header("if-none-match").as_deref(), Some("\"1\""));
+        assert_eq!(first.name(), second.name());
+        assert_eq!(second.id().as_str(), TRACK_ID);
+    }
+
+    #[tokio::test]
+    async fn cached_responses_are_not_shared_between_users() {
+        // both users' profiles have the same entity tag, so a shared cache would answer the second user's request with
+        // the first user's profile
+        let (url, server) = serve_requests(2, |request| match request.header("if-none-match") {
+            Some(_) => "HTTP/1.1 304 Not Modified\r\nETag: \"1\"\r\nConnection: close\r\n\r\n".to_owned(),
+            None => {
+                let id = match request.header("authorization").as_deref() {
+                    Some("Bearer first token") => "first",
+                    _ => "second",
+                };
+
+                let body = format!(
+                    r#"{{"display_name":null,"external_urls":{{}},"followers":{{"href":null,"total":0}},"id":"{id}","images":[],"type":"user"}}"#
+                );
+
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nETag: \"1\"\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            }
+        });
+
+        let spotify_client = SpotifyClientBuilder::new("client ID")
+            .api_base_url(Url::parse(&url).unwrap())
+            .enable_response_cache(10)
+            .build_async();
+
+        let first = user_client_with_token(&spotify_client, "first token")
+            .current_user_profile()
+            .send_async()
+            .await
+            .unwrap();
+        let second = user_client_with_token(&spotify_client, "second token")
+            .current_user_profile()
+            .send_async()
+            .await
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[1].header("if-none-match"), None);
+
+        match (first, second) {
+            (User::Public(first), User::Public(second)) => {
+                assert_eq!(first.id().as_str(), "first");
+                assert_eq!(second.id().as_str(), "second");
+            }
+            users => panic!("expected public users, got {users:?}"),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn request_span_fields() {
+        use std::{
+            collections::HashMap,
+            sync::{Arc, Mutex},
+        };
+
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            Event, Metadata, Subscriber,
+        };
+
+        // records the fields of every span into a single map
+        #[derive(Clone, Default)]
+        struct SpanFields(Arc<Mutex<HashMap<String, String>>>);
+
+        impl Visit for SpanFields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .insert(field.name().to_owned(), format!("{value:?}"));
+            }
+        }
+
+        impl Subscriber for SpanFields {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                span.record(&mut self.clone());
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut self.clone());
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let fields = SpanFields::default();
+        let _guard = tracing::subscriber::set_default(fields.clone());
+
+        let (url, server) = serve_responses(vec![ARTISTS_RESPONSE]);
+        request(format!("{url}artists?ids=")).send_async().await.unwrap();
+        server.join().unwrap();
+
+        let fields = fields.0.lock().unwrap();
+        assert_eq!(
+            *fields,
+            HashMap::from([
+                ("method".to_owned(), "GET".to_owned()),
+                ("path".to_owned(), "\"/artists\"".to_owned()),
+                ("status".to_owned(), "200".to_owned()),
+            ])
+        );
+    }
+}
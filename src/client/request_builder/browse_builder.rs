@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    model::{
+        album::PartialAlbum,
+        browse::{BrowsePlaylists, Categories, Category, FeaturedPlaylists, NewReleases},
+        playlist::PartialPlaylist,
+        CountryCode, Page,
+    },
+    util::timestamp,
+};
+
+const BROWSE_COUNTRY: &str = "country";
+const BROWSE_LOCALE: &str = "locale";
+const BROWSE_TIMESTAMP: &str = "timestamp";
+
+/// A base builder type for the requests that browse Spotify's catalog. Every browse request may be limited to a given
+/// country and returns a page of items.
+pub struct BaseBrowseRequestBuilder<TClient, TResponse, TReturn = TResponse>(
+    RequestBuilder<TClient, TResponse, (), TReturn>,
+);
+
+/// A type alias for a builder type for retrieving new album releases.
+pub type NewReleasesRequestBuilder<TClient> =
+    BaseBrowseRequestBuilder<TClient, NewReleases, Page<NewReleases, PartialAlbum>>;
+/// A type alias for a builder type for retrieving featured playlists.
+pub type FeaturedPlaylistsRequestBuilder<TClient> = BaseBrowseRequestBuilder<TClient, FeaturedPlaylists>;
+/// A type alias for a builder type for retrieving categories.
+pub type CategoriesRequestBuilder<TClient> = BaseBrowseRequestBuilder<TClient, Categories, Page<Categories, Category>>;
+/// A type alias for a builder type for retrieving a category's playlists.
+pub type CategoryPlaylistsRequestBuilder<TClient> =
+    BaseBrowseRequestBuilder<TClient, BrowsePlaylists, Page<BrowsePlaylists, PartialPlaylist>>;
+
+impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse, (), TReturn>
+    for BaseBrowseRequestBuilder<TClient, TResponse, TReturn>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, (), TReturn> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, (), TReturn> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TResponse, TReturn> BaseBrowseRequestBuilder<TClient, TResponse, TReturn> {
+    /// The maximum number of items to return in the page. If the limit is out of range, the request fails with an
+    /// [Error::InvalidPageLimit](crate::error::Error::InvalidPageLimit) without being sent.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.with_page_limit(limit, object::DEFAULT_PAGE_BOUNDS)
+    }
+
+    /// The index of the first item to return. By combining this with [limit](BaseBrowseRequestBuilder::limit), you may
+    /// request any page of items.
+    ///
+    /// Default: 0.
+    pub fn offset(self, offset: u32) -> Self {
+        self.with_page_offset(offset, object::DEFAULT_PAGE_BOUNDS)
+    }
+
+    /// Return only items relevant to the given country. By default, items relevant to all countries are returned.
+    pub fn country(self, country: CountryCode) -> Self {
+        self.append_query(BROWSE_COUNTRY, country.as_str())
+    }
+}
+
+impl<TClient> FeaturedPlaylistsRequestBuilder<TClient> {
+    /// The language the message and the playlists' names and descriptions should be returned in, as an
+    /// [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) language code and an
+    /// [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country code joined by an underscore, such
+    /// as `es_MX`.
+    ///
+    /// Default: American English (`en_US`).
+    pub fn locale<S>(self, locale: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.append_query(BROWSE_LOCALE, locale.into())
+    }
+
+    /// The user's local date and time as an ISO 8601 timestamp without a timezone, such as `2014-10-23T09:00:00`.
+    /// Spotify uses it to return playlists relevant to that time of day.
+    ///
+    /// Default: the current UTC time.
+    ///
+    /// If the timestamp isn't in the expected format, the request fails with an
+    /// [Error::InvalidArgument](crate::error::Error::InvalidArgument) without being sent.
+    pub fn timestamp<S>(self, timestamp: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let timestamp = timestamp.into();
+
+        // the timestamp is the user's local time so it has no timezone, but otherwise it's a regular ISO 8601 timestamp
+        if timestamp::parse(&format!("{timestamp}Z")).is_none() {
+            let message = format!("invalid timestamp: {timestamp}");
+            self.append_query(BROWSE_TIMESTAMP, timestamp)
+                .with_invalid_argument(message)
+        } else {
+            self.append_query(BROWSE_TIMESTAMP, timestamp)
+        }
+    }
+}
+
+impl<TClient> CategoriesRequestBuilder<TClient> {
+    /// The language the categories' names should be returned in, as an
+    /// [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) language code and an
+    /// [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country code joined by an underscore, such
+    /// as `es_MX`.
+    ///
+    /// Default: American English (`en_US`).
+    pub fn locale<S>(self, locale: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.append_query(BROWSE_LOCALE, locale.into())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{request_builder::AsyncRequestBuilder, test_util::user_client},
+        error::Error,
+    };
+
+    fn builder() -> FeaturedPlaylistsRequestBuilder<crate::client::implicit_grant::AsyncImplicitGrantUserClient> {
+        FeaturedPlaylistsRequestBuilder::new(Method::GET, "http://127.0.0.1:1/featured-playlists", user_client())
+    }
+
+    #[test]
+    fn featured_playlists_queries() {
+        let url = builder()
+            .country(CountryCode::FI)
+            .locale("fi_FI")
+            .timestamp("2014-10-23T09:00:00")
+            .limit(10)
+            .take_base_builder()
+            .build_url();
+
+        let query = |key: &str| url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned());
+
+        assert_eq!(query(BROWSE_COUNTRY).as_deref(), Some("FI"));
+        assert_eq!(query(BROWSE_LOCALE).as_deref(), Some("fi_FI"));
+        assert_eq!(query(BROWSE_TIMESTAMP).as_deref(), Some("2014-10-23T09:00:00"));
+        assert_eq!(query(object::PAGE_LIMIT_QUERY).as_deref(), Some("10"));
+    }
+
+    #[tokio::test]
+    async fn invalid_timestamp() {
+        let result = builder().timestamp("2014-10-23 09:00").send_async().await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+}
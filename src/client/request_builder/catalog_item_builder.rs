@@ -7,7 +7,7 @@ use crate::{
         object,
         request_builder::{BaseRequestBuilderContainer, RequestBuilder},
     },
-    model::CountryCode,
+    model::Market,
 };
 
 /// A builder type for catalog searches and item retrievals.
@@ -22,14 +22,14 @@ impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse
     where
         S: Into<Cow<'static, str>>,
     {
-        Self(RequestBuilder::new(method, base_url, client))
+        Self(RequestBuilder::new(method, base_url, client).with_default_market())
     }
 
     fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
     where
         S: Into<Cow<'static, str>>,
     {
-        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+        Self(RequestBuilder::new_with_body(method, base_url, body, client).with_default_market())
     }
 
     fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, (), TReturn> {
@@ -41,10 +41,15 @@ impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse
     }
 }
 
-impl<TReturn, C> CatalogItemRequestBuilder<TReturn, C> {
-    /// Specify a target market country for this request. Only content that is available in that market will be returned
-    /// and [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
-    pub fn market(self, market: CountryCode) -> Self {
-        self.append_query(object::MARKET_QUERY, market.to_string())
+impl<TClient, TResponse, TReturn> CatalogItemRequestBuilder<TClient, TResponse, TReturn> {
+    /// Specify a target market for this request. Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    ///
+    /// Either a [CountryCode](crate::model::CountryCode) or a [Market] may be given.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(object::MARKET_QUERY, market.into().as_str())
     }
 }
@@ -0,0 +1,516 @@
+#[cfg(any(feature = "async", feature = "sync"))]
+use std::fmt::Debug;
+use std::{borrow::Cow, marker::PhantomData};
+
+use reqwest::Method;
+#[cfg(any(feature = "async", feature = "sync"))]
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "async")]
+use crate::client::request_builder::AsyncRequestBuilder;
+#[cfg(feature = "sync")]
+use crate::client::request_builder::SyncRequestBuilder;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::{
+    client::request_builder::TryFromEmptyResponse,
+    error::{Error, Result},
+};
+use crate::{
+    client::request_builder::{
+        BaseRequestBuilder, BaseRequestBuilderContainer, CatalogItemRequestBuilder, RequestBuilder,
+    },
+    model::Market,
+    scope::Scope,
+};
+
+/// A builder type for requests on any amount of items that are split into multiple requests of a limited amount of items
+/// each. If the requests return items, their results are concatenated in the same order as the requested items.
+///
+/// With the asynchronous client, the requests are sent concurrently, up to the client's
+/// [maximum amount at a time](crate::client::SpotifyClientBuilder::max_concurrent_requests). The limit may be lowered
+/// further for a single request with [max_concurrency](Self::max_concurrency). With the synchronous client, the
+/// requests are sent one after another.
+pub struct ChunkedRequestBuilder<
+    TClient,
+    TResponse,
+    TReturn = TResponse,
+    TChunk = RequestBuilder<TClient, TResponse, (), TReturn>,
+> {
+    chunks: Vec<TChunk>,
+    max_concurrency: Option<usize>,
+    // the client holds the concurrency limit the asynchronous requests share
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    client: TClient,
+    phantom: PhantomData<(TResponse, TReturn)>,
+}
+
+/// A builder type for catalog item retrievals that are split into multiple requests of a limited amount of items each.
+/// See [ChunkedRequestBuilder].
+pub type ChunkedCatalogItemRequestBuilder<TClient, TResponse, TReturn = TResponse> =
+    ChunkedRequestBuilder<TClient, TResponse, TReturn, CatalogItemRequestBuilder<TClient, TResponse, TReturn>>;
+
+impl<TClient, TResponse, TReturn, TChunk> ChunkedRequestBuilder<TClient, TResponse, TReturn, TChunk>
+where
+    TChunk: BaseRequestBuilderContainer<TClient, TResponse, (), TReturn>,
+{
+    /// Split the given IDs into chunks of at most `chunk_size` IDs, and build a request for each chunk with the IDs in
+    /// the given query parameter.
+    pub(crate) fn new<S, I>(
+        method: Method,
+        base_url: S,
+        client: TClient,
+        ids_query: &'static str,
+        ids: I,
+        chunk_size: usize,
+    ) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        I: IntoIterator<Item = String>,
+        TClient: Clone,
+    {
+        let base_url = base_url.into();
+        let ids = ids.into_iter().collect::<Vec<_>>();
+
+        let chunks = ids
+            .chunks(chunk_size)
+            .map(|chunk| {
+                TChunk::new(method.clone(), base_url.clone(), client.clone()).append_query(ids_query, chunk.join(","))
+            })
+            .collect();
+
+        Self {
+            chunks,
+            max_concurrency: None,
+            client,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Set the maximum amount of these requests sent concurrently by the asynchronous client. This can only lower the
+    /// client's own [limit](crate::client::SpotifyClientBuilder::max_concurrent_requests), which applies by default.
+    /// Has no effect with the synchronous client.
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency.max(1));
+        self
+    }
+
+    /// Set whether to react to the rate limit in each request. See
+    /// [BaseRequestBuilder::react_to_rate_limit].
+    pub fn react_to_rate_limit(self, react_to_rate_limit: bool) -> Self {
+        self.map_chunks(|chunk| chunk.react_to_rate_limit(react_to_rate_limit))
+    }
+
+    /// Set the maximum amount of times each request is retried after being rate limited. See
+    /// [BaseRequestBuilder::max_rate_limit_retries].
+    pub fn max_rate_limit_retries(self, max_rate_limit_retries: u32) -> Self {
+        self.map_chunks(|chunk| chunk.max_rate_limit_retries(max_rate_limit_retries))
+    }
+
+    /// Set whether to automatically refresh the access token in each request. See
+    /// [BaseRequestBuilder::auto_refresh_access_token].
+    pub fn auto_refresh_access_token(self, auto_refresh_access_token: bool) -> Self {
+        self.map_chunks(|chunk| chunk.auto_refresh_access_token(auto_refresh_access_token))
+    }
+
+    pub(crate) fn with_required_scope(self, scope: Scope) -> Self {
+        self.map_chunks(|chunk| chunk.with_required_scope(scope))
+    }
+
+    /// Append the same query parameter to every chunk's request.
+    pub(crate) fn append_query(self, key: &'static str, value: &'static str) -> Self {
+        self.map_chunks(|chunk| chunk.append_query(key, value))
+    }
+
+    #[cfg(all(test, feature = "async"))]
+    pub(crate) fn take_chunks(self) -> Vec<TChunk> {
+        self.chunks
+    }
+
+    fn map_chunks<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(TChunk) -> TChunk,
+    {
+        self.chunks = self.chunks.into_iter().map(f).collect();
+        self
+    }
+}
+
+impl<TClient, TResponse, TReturn> ChunkedCatalogItemRequestBuilder<TClient, TResponse, TReturn> {
+    /// Specify a target market for this request. Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    ///
+    /// Either a [CountryCode](crate::model::CountryCode) or a [Market] may be given.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        let market = market.into();
+        self.map_chunks(|chunk| chunk.market(market))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TClient, TResponse, TItem, TChunk> ChunkedRequestBuilder<TClient, TResponse, Vec<TItem>, TChunk>
+where
+    TChunk: AsyncRequestBuilder<TClient, TResponse, (), Vec<TItem>> + Send,
+    TResponse: Debug + DeserializeOwned + TryFromEmptyResponse + Send + Sync,
+    TItem: Send + Sync,
+    Vec<TItem>: TryFrom<TResponse>,
+    TClient: crate::client::private::BuildHttpRequestAsync
+        + crate::client::private::AccessTokenExpiryAsync
+        + crate::client::private::GrantedScopes
+        + Send
+        + Sync,
+    Error: From<<Vec<TItem> as TryFrom<TResponse>>::Error>,
+{
+    /// Send the requests asynchronously and concatenate their results in order.
+    pub async fn send_async(self) -> Result<Vec<TItem>> {
+        use futures_util::{StreamExt, TryStreamExt};
+
+        let max_concurrency = self.max_concurrency.unwrap_or(self.chunks.len()).max(1);
+        let concurrent_requests = self.client.concurrent_requests();
+
+        let results = futures_util::stream::iter(self.chunks)
+            .map(|chunk| async move {
+                let _permit = concurrent_requests
+                    .acquire()
+                    .await
+                    .expect("concurrent request semaphore closed");
+
+                chunk.send_async().await
+            })
+            .buffered(max_concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(results.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TClient, TChunk> ChunkedRequestBuilder<TClient, (), (), TChunk>
+where
+    TChunk: AsyncRequestBuilder<TClient, (), (), ()> + Send,
+    TClient: crate::client::private::BuildHttpRequestAsync
+        + crate::client::private::AccessTokenExpiryAsync
+        + crate::client::private::GrantedScopes
+        + Send
+        + Sync,
+{
+    /// Send the requests asynchronously.
+    pub async fn send_async(self) -> Result<()> {
+        use futures_util::{StreamExt, TryStreamExt};
+
+        let max_concurrency = self.max_concurrency.unwrap_or(self.chunks.len()).max(1);
+        let concurrent_requests = self.client.concurrent_requests();
+
+        futures_util::stream::iter(self.chunks)
+            .map(|chunk| async move {
+                let _permit = concurrent_requests
+                    .acquire()
+                    .await
+                    .expect("concurrent request semaphore closed");
+
+                chunk.send_async().await
+            })
+            .buffered(max_concurrency)
+            .try_collect()
+            .await
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<TClient, TResponse, TItem, TChunk> ChunkedRequestBuilder<TClient, TResponse, Vec<TItem>, TChunk>
+where
+    TChunk: SyncRequestBuilder<TClient, TResponse, (), Vec<TItem>>,
+    TResponse: Debug + DeserializeOwned + TryFromEmptyResponse,
+    Vec<TItem>: TryFrom<TResponse>,
+    TClient: crate::client::private::BuildHttpRequestSync
+        + crate::client::private::AccessTokenExpirySync
+        + crate::client::private::GrantedScopes,
+    Error: From<<Vec<TItem> as TryFrom<TResponse>>::Error>,
+{
+    /// Send the requests synchronously one after another and concatenate their results in order.
+    pub fn send_sync(self) -> Result<Vec<TItem>> {
+        let mut results = Vec::new();
+
+        for chunk in self.chunks {
+            results.extend(chunk.send_sync()?);
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<TClient, TChunk> ChunkedRequestBuilder<TClient, (), (), TChunk>
+where
+    TChunk: SyncRequestBuilder<TClient, (), (), ()>,
+    TClient: crate::client::private::BuildHttpRequestSync
+        + crate::client::private::AccessTokenExpirySync
+        + crate::client::private::GrantedScopes,
+{
+    /// Send the requests synchronously one after another.
+    pub fn send_sync(self) -> Result<()> {
+        for chunk in self.chunks {
+            chunk.send_sync()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    use reqwest::Method;
+    use serde_json::json;
+
+    use super::{ChunkedCatalogItemRequestBuilder, ChunkedRequestBuilder};
+    use crate::{
+        client::{
+            object,
+            test_util::{json_response, serve_requests, user_client, user_client_from, CapturedRequest},
+            SpotifyClientBuilder, UnscopedClient,
+        },
+        model::{
+            id::{Id, IdFromBare, IdTrait},
+            track::NonLocalTrackInformation,
+        },
+    };
+
+    fn track_json(id: &str) -> serde_json::Value {
+        let artist = json!({
+            "external_urls": { "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl" },
+            "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+            "id": "6pNgnvzBa6Bthsv8SrZJYl",
+            "name": "Hatsune Miku",
+            "type": "artist",
+            "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+        });
+
+        json!({
+            "album": {
+                "album_type": "single",
+                "artists": [artist],
+                "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                "images": [],
+                "name": "World is Mine",
+                "release_date": "2008-05-31",
+                "release_date_precision": "day",
+                "total_tracks": 1,
+                "type": "album",
+                "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "artists": [artist],
+            "disc_number": 1,
+            "duration_ms": 254000,
+            "explicit": false,
+            "external_ids": {},
+            "external_urls": { "spotify": format!("https://open.spotify.com/track/{id}") },
+            "href": format!("https://api.spotify.com/v1/tracks/{id}"),
+            "id": id,
+            "is_local": false,
+            "name": "World is Mine",
+            "popularity": 60,
+            "preview_url": null,
+            "track_number": 1,
+            "type": "track",
+            "uri": format!("spotify:track:{id}")
+        })
+    }
+
+    /// Serves the given amount of track requests, responding with a track for each ID in the request's query. Returns
+    /// the server's URL.
+    fn serve_tracks(requests: usize) -> (String, thread::JoinHandle<Vec<CapturedRequest>>) {
+        serve_requests(requests, |request| {
+            let ids = request.query("ids").unwrap();
+            let tracks = ids.split(',').map(track_json).collect::<Vec<_>>();
+
+            json_response("200 OK", &json!({ "tracks": tracks }).to_string())
+        })
+    }
+
+    /// Serves the given amount of requests, each on its own thread, responding to each with the given body after a short
+    /// delay. Returns the server's URL and a handle that returns the most requests that were being served at once.
+    fn serve_concurrently(requests: usize, body: &'static str) -> (String, thread::JoinHandle<usize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let in_flight = Arc::new(AtomicUsize::new(0));
+            let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+            let handlers = (0..requests)
+                .map(|_| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let in_flight = Arc::clone(&in_flight);
+                    let max_in_flight = Arc::clone(&max_in_flight);
+
+                    thread::spawn(move || {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+                        let mut reader = BufReader::new(stream.try_clone().unwrap());
+                        let mut line = String::new();
+                        while reader.read_line(&mut line).unwrap() > 2 {
+                            line.clear();
+                        }
+
+                        thread::sleep(Duration::from_millis(50));
+
+                        // the request is done before the client gets the response and may send the next one
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        stream.write_all(json_response("200 OK", body).as_bytes()).unwrap();
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for handler in handlers {
+                handler.join().unwrap();
+            }
+
+            max_in_flight.load(Ordering::SeqCst)
+        });
+
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn chunked_tracks_are_returned_in_order() {
+        let ids = (0..120).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+        let (url, server) = serve_tracks(3);
+
+        let builder: ChunkedCatalogItemRequestBuilder<_, object::TracksResponse, Vec<_>> =
+            ChunkedCatalogItemRequestBuilder::new(Method::GET, url, user_client(), object::IDS_QUERY, ids.clone(), 50);
+
+        let tracks = builder.send_async().await.unwrap();
+        server.join().unwrap();
+
+        assert_eq!(tracks.len(), 120);
+
+        for (track, id) in tracks.iter().zip(ids) {
+            assert_eq!(track.id().as_str(), id);
+        }
+    }
+
+    #[tokio::test]
+    async fn no_ids_sends_no_requests() {
+        let builder: ChunkedCatalogItemRequestBuilder<_, object::TracksResponse, Vec<_>> =
+            ChunkedCatalogItemRequestBuilder::new(
+                Method::GET,
+                "http://127.0.0.1:1/tracks",
+                user_client(),
+                object::IDS_QUERY,
+                Vec::new(),
+                50,
+            );
+
+        assert!(builder.send_async().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn chunked_booleans_are_returned_in_order() {
+        let ids = (0..120).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+
+        // every ID divisible by three is "saved"
+        let (url, server) = serve_requests(3, |request| {
+            let saved = request
+                .query("ids")
+                .unwrap()
+                .split(',')
+                .map(|id| id.parse::<u32>().unwrap() % 3 == 0)
+                .collect::<Vec<_>>();
+
+            json_response("200 OK", &serde_json::to_string(&saved).unwrap())
+        });
+
+        let builder: ChunkedRequestBuilder<_, Vec<bool>> =
+            ChunkedRequestBuilder::new(Method::GET, url, user_client(), object::IDS_QUERY, ids, 50);
+
+        let saved = builder.send_async().await.unwrap();
+        let requests = server.join().unwrap();
+
+        let chunk_sizes = requests
+            .iter()
+            .map(|request| request.query("ids").unwrap().split(',').count())
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunk_sizes, [50, 50, 20]);
+        assert_eq!(saved, (0..120).map(|i| i % 3 == 0).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn chunked_requests_without_content() {
+        let ids = (0..60).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+        let (url, server) = serve_requests(2, |_| {
+            "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_owned()
+        });
+
+        let builder: ChunkedRequestBuilder<_, ()> =
+            ChunkedRequestBuilder::new(Method::PUT, url, user_client(), object::IDS_QUERY, ids, 50);
+
+        builder.send_async().await.unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests.iter().all(|request| request.method == "PUT"));
+        assert_eq!(requests[0].query("ids").unwrap().split(',').count(), 50);
+        assert_eq!(requests[1].query("ids").unwrap().split(',').count(), 10);
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_are_limited_by_client() {
+        let client = user_client_from(SpotifyClientBuilder::new("client ID").max_concurrent_requests(1));
+        let ids = (0..100).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+        let (url, server) = serve_concurrently(4, "[]");
+
+        let builder = |ids: &[String]| -> ChunkedRequestBuilder<_, Vec<bool>> {
+            ChunkedRequestBuilder::new(
+                Method::GET,
+                url.clone(),
+                client.clone(),
+                object::IDS_QUERY,
+                ids.to_vec(),
+                25,
+            )
+        };
+
+        // the limit is shared between requests sent at the same time
+        let (first, second) = tokio::join!(builder(&ids[..50]).send_async(), builder(&ids[50..]).send_async());
+        first.unwrap();
+        second.unwrap();
+
+        assert_eq!(server.join().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn audio_features_are_limited_by_client() {
+        let (url, server) = serve_concurrently(3, r#"{ "audio_features": [] }"#);
+        let client = user_client_from(
+            SpotifyClientBuilder::new("client ID")
+                .api_base_url(url.parse().unwrap())
+                .max_concurrent_requests(1),
+        );
+        let ids = (0..250).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+
+        client
+            .audio_features_multiple(ids.iter().map(|id| Id::from_bare(id).unwrap()))
+            .send_async()
+            .await
+            .unwrap();
+
+        assert_eq!(server.join().unwrap(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    model::{
+        artist::FullArtist,
+        id::{ArtistId, Id, IdTrait},
+        CursorPage, CursorPageResponse,
+    },
+};
+
+const FOLLOWED_ARTISTS_AFTER: &str = "after";
+
+/// A builder for retrieving the artists the user follows. New instances are returned by the
+/// [followed_artists-function](crate::client::ScopedClient::followed_artists) in
+/// [ScopedClient](crate::client::ScopedClient).
+pub struct FollowedArtistsRequestBuilder<TClient>(
+    RequestBuilder<TClient, CursorPageResponse<FullArtist>, (), CursorPage<FullArtist>>,
+);
+
+impl<TClient> BaseRequestBuilderContainer<TClient, CursorPageResponse<FullArtist>, (), CursorPage<FullArtist>>
+    for FollowedArtistsRequestBuilder<TClient>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, CursorPageResponse<FullArtist>, (), CursorPage<FullArtist>> {
+        self.0
+    }
+
+    fn get_base_builder_mut(
+        &mut self,
+    ) -> &mut RequestBuilder<TClient, CursorPageResponse<FullArtist>, (), CursorPage<FullArtist>> {
+        &mut self.0
+    }
+}
+
+impl<TClient> FollowedArtistsRequestBuilder<TClient> {
+    /// The maximum number of artists to return. If the limit is out of range, the request fails with an
+    /// [Error::InvalidPageLimit](crate::error::Error::InvalidPageLimit) without being sent.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.with_page_limit(limit, object::DEFAULT_PAGE_BOUNDS)
+    }
+
+    /// Return only the artists after the given artist. The [after-cursor](crate::model::Cursors::after) of a previous
+    /// page is the ID of its last artist, and may be used to retrieve the page after it.
+    pub fn after(self, after: Id<'_, ArtistId>) -> Self {
+        self.append_query(FOLLOWED_ARTISTS_AFTER, after.as_str().to_owned())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{private::ApiBaseUrl, request_builder::AsyncRequestBuilder, test_util::user_client},
+        error::Error,
+        model::id::IdFromBare,
+    };
+
+    fn builder() -> FollowedArtistsRequestBuilder<crate::client::implicit_grant::AsyncImplicitGrantUserClient> {
+        FollowedArtistsRequestBuilder::new(Method::GET, "http://127.0.0.1:1/following", user_client())
+    }
+
+    fn query(builder: FollowedArtistsRequestBuilder<impl ApiBaseUrl>, key: &str) -> Option<String> {
+        let url = builder.take_base_builder().build_url();
+        url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned())
+    }
+
+    #[test]
+    fn followed_artists_queries() {
+        let after = Id::<ArtistId>::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap();
+
+        assert_eq!(
+            query(builder().after(after), FOLLOWED_ARTISTS_AFTER).as_deref(),
+            Some("6pNgnvzBa6Bthsv8SrZJYl")
+        );
+        assert_eq!(
+            query(builder().limit(50), object::PAGE_LIMIT_QUERY).as_deref(),
+            Some("50")
+        );
+    }
+
+    #[tokio::test]
+    async fn limit_out_of_range() {
+        let result = builder().limit(0).send_async().await;
+        assert!(matches!(result, Err(Error::InvalidPageLimit { limit: 0, max: 50 })));
+    }
+}
@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object::{self, PageBounds},
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    model::{
+        album::{AlbumGroup, ArtistAlbums, PartialAlbum},
+        Market, Page,
+    },
+};
+
+const ARTIST_ALBUMS_INCLUDE_GROUPS: &str = "include_groups";
+
+/// A builder type for requests that return a [Page](crate::model::Page) of items.
+pub struct PagedRequestBuilder<TClient, TResponse, TReturn = TResponse> {
+    builder: RequestBuilder<TClient, TResponse, (), TReturn>,
+    bounds: PageBounds,
+}
+
+/// A type alias for a builder type for retrieving an artist's albums.
+pub type ArtistAlbumsRequestBuilder<TClient> =
+    PagedRequestBuilder<TClient, ArtistAlbums, Page<ArtistAlbums, PartialAlbum>>;
+
+impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse, (), TReturn>
+    for PagedRequestBuilder<TClient, TResponse, TReturn>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self {
+            builder: RequestBuilder::new(method, base_url, client).with_default_market(),
+            bounds: object::DEFAULT_PAGE_BOUNDS,
+        }
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self {
+            builder: RequestBuilder::new_with_body(method, base_url, body, client).with_default_market(),
+            bounds: object::DEFAULT_PAGE_BOUNDS,
+        }
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, (), TReturn> {
+        self.builder
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, (), TReturn> {
+        &mut self.builder
+    }
+}
+
+impl<TClient, TResponse, TReturn> PagedRequestBuilder<TClient, TResponse, TReturn> {
+    /// The maximum number of items to return in the page. If the limit is out of range, the request fails with an
+    /// [Error::InvalidPageLimit](crate::error::Error::InvalidPageLimit) without being sent.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        let bounds = self.bounds;
+        self.with_page_limit(limit, bounds)
+    }
+
+    /// The index of the first item to return. By combining this with [limit](PagedRequestBuilder::limit), you may
+    /// request any page of items. If the offset is out of range, the request fails with an
+    /// [Error::InvalidPageOffset](crate::error::Error::InvalidPageOffset) without being sent.
+    ///
+    /// Default: 0. Maximum: 100 000 for the user's and other users' playlists, unlimited otherwise.
+    pub fn offset(self, offset: u32) -> Self {
+        let bounds = self.bounds;
+        self.with_page_offset(offset, bounds)
+    }
+
+    /// Set the endpoint's page bounds, if they differ from the default bounds.
+    pub(crate) fn with_page_bounds(self, bounds: PageBounds) -> Self {
+        Self { bounds, ..self }
+    }
+
+    /// Specify a target market for this request. Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied. Endpoints that don't
+    /// support a market ignore it.
+    ///
+    /// Either a [CountryCode](crate::model::CountryCode) or a [Market] may be given.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(object::MARKET_QUERY, market.into().as_str())
+    }
+}
+
+impl<TClient> ArtistAlbumsRequestBuilder<TClient> {
+    /// Return only albums in the given groups. Replaces any previously given groups. By default, albums in every group
+    /// are returned. [Unknown](AlbumGroup::Unknown) groups are ignored.
+    pub fn include_groups<I>(self, groups: I) -> Self
+    where
+        I: IntoIterator<Item = AlbumGroup>,
+    {
+        let groups = groups
+            .into_iter()
+            .filter(|group| *group != AlbumGroup::Unknown)
+            .map(|group| group.as_str())
+            .collect::<Vec<_>>();
+        self.append_query(ARTIST_ALBUMS_INCLUDE_GROUPS, groups.join(","))
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{
+            implicit_grant::AsyncImplicitGrantUserClient, request_builder::AsyncRequestBuilder, test_util::user_client,
+        },
+        error::Error,
+    };
+
+    fn builder() -> ArtistAlbumsRequestBuilder<AsyncImplicitGrantUserClient> {
+        PagedRequestBuilder::new(Method::GET, "http://127.0.0.1:1/albums", user_client())
+    }
+
+    #[tokio::test]
+    async fn limit_out_of_range() {
+        let result = builder().limit(100).send_async().await;
+
+        assert!(matches!(result, Err(Error::InvalidPageLimit { limit: 100, max: 50 })));
+    }
+
+    #[tokio::test]
+    async fn offset_out_of_range() {
+        let result = builder()
+            .with_page_bounds(object::PLAYLISTS_PAGE_BOUNDS)
+            .offset(100_001)
+            .send_async()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidPageOffset {
+                offset: 100_001,
+                max: 100_000
+            })
+        ));
+    }
+
+    #[test]
+    fn offset_is_unlimited_by_default() {
+        let mut builder = builder().offset(u32::MAX).take_base_builder();
+
+        assert!(builder.check_arguments().is_ok());
+    }
+}
@@ -1,10 +1,13 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 use reqwest::Method;
 
-use crate::client::{
-    object,
-    request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    model::playback::PlaybackOffset,
 };
 
 /// A base builder type for the various player control request builders.
@@ -77,18 +80,188 @@ impl<TClient, TReturn> BasePlayerControlRequestBuilder<TClient, TReturn> {
     }
 }
 
+impl<TClient> PlayItemsRequestBuilder<TClient> {
+    /// Start playing the first item from the given position. Passing in a position that is greater than the length of
+    /// the item will cause the player to start playing the next item.
+    pub fn position(self, position: Duration) -> Self {
+        self.replace_body(|body| object::PlayItemsBody {
+            position_ms: Some(duration_to_millis(position)),
+            ..body
+        })
+    }
+}
+
 impl<TClient> PlayContextRequestBuilder<TClient> {
-    /// Start playing a certain track from the context, identified by its zero-based index in the context.
-    pub fn offset<U>(self, offset: U) -> Self
+    /// Start playing a certain item in the context, identified either by its zero-based index in the context or by its
+    /// ID. See [PlaybackOffset].
+    pub fn offset<'a, O>(self, offset: O) -> Self
     where
-        U: Into<u32>,
+        O: Into<PlaybackOffset<'a>>,
     {
+        let offset = object::PlayContextOffset::from(offset.into());
+
         self.replace_body(|body| object::PlayContextBody {
-            offset: object::PlayContextOffset {
-                position: Some(offset.into()),
-                ..body.offset
-            },
+            offset: Some(offset),
             ..body
         })
     }
+
+    /// Start playing the item from the given position. Passing in a position that is greater than the length of the
+    /// item will cause the player to start playing the next item.
+    pub fn position(self, position: Duration) -> Self {
+        self.replace_body(|body| object::PlayContextBody {
+            position_ms: Some(duration_to_millis(position)),
+            ..body
+        })
+    }
+}
+
+fn duration_to_millis(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Method;
+    use serde_json::json;
+
+    use super::{PlayContextRequestBuilder, PlayItemsRequestBuilder, PlayerControlRequestBuilder};
+    use crate::{
+        client::{
+            object,
+            request_builder::{AsyncRequestBuilder, BaseRequestBuilderContainer},
+            test_util::{serve_responses, user_client, CapturedRequest},
+        },
+        model::id::{Id, IdFromBare, TrackId},
+    };
+
+    const NO_CONTENT_RESPONSE: &str = "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n";
+
+    fn body_json(request: &CapturedRequest) -> serde_json::Value {
+        serde_json::from_str(&request.body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn play_items_on_device() {
+        let (url, server) = serve_responses(vec![NO_CONTENT_RESPONSE]);
+
+        let body = object::PlayItemsBody {
+            uris: vec![
+                "spotify:track:2takcwOaAZWiXQijPHIx7B".to_owned(),
+                "spotify:track:5XeSAezNDk9tuw3viiCbZ3".to_owned(),
+            ],
+            position_ms: None,
+        };
+
+        PlayItemsRequestBuilder::new_with_body(Method::PUT, url, body, user_client())
+            .device_id("device")
+            .position(Duration::from_millis(1500))
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].method, "PUT");
+        assert_eq!(requests[0].query(object::DEVICE_ID_QUERY).as_deref(), Some("device"));
+        assert_eq!(
+            body_json(&requests[0]),
+            json!({
+                "uris": [
+                    "spotify:track:2takcwOaAZWiXQijPHIx7B",
+                    "spotify:track:5XeSAezNDk9tuw3viiCbZ3"
+                ],
+                "position_ms": 1500
+            })
+        );
+    }
+
+    fn play_album_body() -> object::PlayContextBody {
+        object::PlayContextBody {
+            context_uri: "spotify:album:0tDsHtvN9YNuZjlqHvDY2P".to_owned(),
+            offset: None,
+            position_ms: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn play_context_with_position_offset() {
+        let (url, server) = serve_responses(vec![NO_CONTENT_RESPONSE]);
+
+        PlayContextRequestBuilder::new_with_body(Method::PUT, url, play_album_body(), user_client())
+            .offset(4u32)
+            .position(Duration::from_secs(30))
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].method, "PUT");
+        assert_eq!(requests[0].query(object::DEVICE_ID_QUERY), None);
+        assert_eq!(
+            body_json(&requests[0]),
+            json!({
+                "context_uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P",
+                "offset": { "position": 4 },
+                "position_ms": 30000
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn play_context_with_item_offset() {
+        let (url, server) = serve_responses(vec![NO_CONTENT_RESPONSE]);
+        let track = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+
+        PlayContextRequestBuilder::new_with_body(Method::PUT, url, play_album_body(), user_client())
+            .offset(track)
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+
+        assert_eq!(
+            body_json(&requests[0]),
+            json!({
+                "context_uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P",
+                "offset": { "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu" }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn play_context_without_offset() {
+        let (url, server) = serve_responses(vec![NO_CONTENT_RESPONSE]);
+
+        PlayContextRequestBuilder::new_with_body(Method::PUT, url, play_album_body(), user_client())
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+
+        assert_eq!(
+            body_json(&requests[0]),
+            json!({ "context_uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P" })
+        );
+    }
+
+    #[tokio::test]
+    async fn player_control_without_device() {
+        let (url, server) = serve_responses(vec![NO_CONTENT_RESPONSE]);
+
+        PlayerControlRequestBuilder::new(Method::POST, url, user_client())
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].query(object::DEVICE_ID_QUERY), None);
+    }
 }
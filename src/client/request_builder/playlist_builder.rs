@@ -0,0 +1,495 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+#[cfg(feature = "async")]
+use crate::client::request_builder::AsyncRequestBuilder;
+#[cfg(feature = "sync")]
+use crate::client::request_builder::SyncRequestBuilder;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::error::Result;
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilder, BaseRequestBuilderContainer, RequestBuilder},
+    },
+    error::Error,
+    model::playlist::FullPlaylist,
+    scope::Scope,
+};
+
+/// A builder for creating a new playlist. New instances are returned by the
+/// [create_playlist-function](crate::client::ScopedClient::create_playlist) in
+/// [ScopedClient](crate::client::ScopedClient).
+pub struct CreatePlaylistRequestBuilder<TClient>(RequestBuilder<TClient, FullPlaylist, object::CreatePlaylistBody>);
+
+/// A builder for changing a playlist's details. New instances are returned by the
+/// [change_playlist_details-function](crate::client::ScopedClient::change_playlist_details) in
+/// [ScopedClient](crate::client::ScopedClient).
+pub struct ChangePlaylistDetailsRequestBuilder<TClient>(RequestBuilder<TClient, (), object::ChangePlaylistDetailsBody>);
+
+/// A base builder type for the requests that modify a playlist's items. Each request returns the playlist's new
+/// snapshot ID.
+pub struct BasePlaylistItemsRequestBuilder<TClient, TBody>(
+    RequestBuilder<TClient, object::SnapshotIdResponse, TBody, String>,
+);
+
+/// A type alias for a builder type for adding items to a playlist.
+pub type AddPlaylistItemsRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::AddPlaylistItemsBody>;
+/// A type alias for a builder type for removing items from a playlist.
+pub type RemovePlaylistItemsRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::RemovePlaylistItemsBody>;
+/// A type alias for a builder type for removing items from a playlist by their positions.
+pub type RemovePlaylistItemsByPositionRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::RemovePlaylistItemsByPositionBody>;
+/// A type alias for a builder type for reordering a playlist's items.
+pub type ReorderPlaylistItemsRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::ReorderPlaylistItemsBody>;
+
+/// A builder for replacing a playlist's items with any amount of items. New instances are returned by the
+/// [replace_playlist_items-function](crate::client::ScopedClient::replace_playlist_items) in
+/// [ScopedClient](crate::client::ScopedClient).
+///
+/// A single request may contain at most 100 items, so the playlist's items are first replaced with the first 100 items,
+/// and the rest are appended to the playlist in chunks of 100 items. The requests are sent one after another, and the
+/// playlist's snapshot ID after the last request is returned.
+pub struct ReplacePlaylistItemsRequestBuilder<TClient> {
+    replace: AddPlaylistItemsRequestBuilder<TClient>,
+    appends: Vec<AddPlaylistItemsRequestBuilder<TClient>>,
+}
+
+impl<TClient> BaseRequestBuilderContainer<TClient, FullPlaylist, object::CreatePlaylistBody>
+    for CreatePlaylistRequestBuilder<TClient>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: object::CreatePlaylistBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, FullPlaylist, object::CreatePlaylistBody> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, FullPlaylist, object::CreatePlaylistBody> {
+        &mut self.0
+    }
+}
+
+impl<TClient> BaseRequestBuilderContainer<TClient, (), object::ChangePlaylistDetailsBody>
+    for ChangePlaylistDetailsRequestBuilder<TClient>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: object::ChangePlaylistDetailsBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, (), object::ChangePlaylistDetailsBody> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, (), object::ChangePlaylistDetailsBody> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TBody> BaseRequestBuilderContainer<TClient, object::SnapshotIdResponse, TBody, String>
+    for BasePlaylistItemsRequestBuilder<TClient, TBody>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: TBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, object::SnapshotIdResponse, TBody, String> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, object::SnapshotIdResponse, TBody, String> {
+        &mut self.0
+    }
+}
+
+impl<TClient> CreatePlaylistRequestBuilder<TClient> {
+    /// Whether the playlist is public. Creating a private playlist requires the
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) scope instead of
+    /// [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic).
+    ///
+    /// Default: true.
+    pub fn public(self, public: bool) -> Self {
+        let scope = if public {
+            Scope::PlaylistModifyPublic
+        } else {
+            Scope::PlaylistModifyPrivate
+        };
+
+        self.replace_body(|body| object::CreatePlaylistBody {
+            public: Some(public),
+            ..body
+        })
+        .with_required_scope(scope)
+        .check_visibility()
+    }
+
+    /// Whether other users may modify the playlist. A collaborative playlist must also be
+    /// [non-public](CreatePlaylistRequestBuilder::public), which isn't the default; if the playlist is collaborative
+    /// but not set non-public, the request fails with an [Error::InvalidArgument](crate::error::Error::InvalidArgument)
+    /// without being sent.
+    ///
+    /// Default: false.
+    pub fn collaborative(self, collaborative: bool) -> Self {
+        self.replace_body(|body| object::CreatePlaylistBody {
+            collaborative: Some(collaborative),
+            ..body
+        })
+        .check_visibility()
+    }
+
+    /// The playlist's description.
+    pub fn description<S>(self, description: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::CreatePlaylistBody {
+            description: Some(description.into()),
+            ..body
+        })
+    }
+
+    /// Fails the request before it is sent if the playlist is collaborative and public. Spotify creates a public
+    /// playlist unless told otherwise, so an unset visibility counts as public.
+    fn check_visibility(mut self) -> Self {
+        let conflict = self
+            .0
+            .body
+            .as_ref()
+            .is_some_and(|body| body.collaborative == Some(true) && body.public != Some(false));
+
+        self.0.argument_error =
+            conflict.then(|| Error::InvalidArgument("a collaborative playlist can't be public".into()));
+        self
+    }
+}
+
+impl<TClient> ChangePlaylistDetailsRequestBuilder<TClient> {
+    /// The playlist's new name.
+    pub fn name<S>(self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::ChangePlaylistDetailsBody {
+            name: Some(name.into()),
+            ..body
+        })
+    }
+
+    /// Whether the playlist is public. Making a playlist private requires the
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) scope instead of
+    /// [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic).
+    pub fn public(self, public: bool) -> Self {
+        let scope = if public {
+            Scope::PlaylistModifyPublic
+        } else {
+            Scope::PlaylistModifyPrivate
+        };
+
+        self.replace_body(|body| object::ChangePlaylistDetailsBody {
+            public: Some(public),
+            ..body
+        })
+        .with_required_scope(scope)
+        .check_visibility()
+    }
+
+    /// Whether other users may modify the playlist. A collaborative playlist can't be
+    /// [public](ChangePlaylistDetailsRequestBuilder::public); if the playlist is set both collaborative and public, the
+    /// request fails with an [Error::InvalidArgument](crate::error::Error::InvalidArgument) without being sent.
+    pub fn collaborative(self, collaborative: bool) -> Self {
+        self.replace_body(|body| object::ChangePlaylistDetailsBody {
+            collaborative: Some(collaborative),
+            ..body
+        })
+        .check_visibility()
+    }
+
+    /// The playlist's new description.
+    pub fn description<S>(self, description: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::ChangePlaylistDetailsBody {
+            description: Some(description.into()),
+            ..body
+        })
+    }
+
+    /// Fails the request before it is sent if the playlist is set both collaborative and public. The check is repeated
+    /// after each change to either setting, so a later call may resolve an earlier conflict.
+    fn check_visibility(mut self) -> Self {
+        let conflict = self
+            .0
+            .body
+            .as_ref()
+            .is_some_and(|body| body.collaborative == Some(true) && body.public == Some(true));
+
+        self.0.argument_error =
+            conflict.then(|| Error::InvalidArgument("a collaborative playlist can't be public".into()));
+        self
+    }
+}
+
+impl<TClient> AddPlaylistItemsRequestBuilder<TClient> {
+    /// The zero-based position to insert the items in. By default, the items are appended to the end of the playlist.
+    pub fn position(self, position: u32) -> Self {
+        self.replace_body(|body| object::AddPlaylistItemsBody {
+            position: Some(position),
+            ..body
+        })
+    }
+}
+
+impl<TClient> RemovePlaylistItemsRequestBuilder<TClient> {
+    /// The playlist's snapshot ID to remove the items from. The items are removed from the playlist's state at the
+    /// given snapshot, even if the playlist has been modified since.
+    pub fn snapshot_id<S>(self, snapshot_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::RemovePlaylistItemsBody {
+            snapshot_id: Some(snapshot_id.into()),
+            ..body
+        })
+    }
+}
+
+impl<TClient> ReorderPlaylistItemsRequestBuilder<TClient> {
+    /// The amount of consecutive items to move, starting from the range start.
+    ///
+    /// Default: 1.
+    pub fn range_length(self, range_length: u32) -> Self {
+        self.replace_body(|body| object::ReorderPlaylistItemsBody {
+            range_length: Some(range_length),
+            ..body
+        })
+    }
+
+    /// The playlist's snapshot ID to reorder the items in.
+    pub fn snapshot_id<S>(self, snapshot_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::ReorderPlaylistItemsBody {
+            snapshot_id: Some(snapshot_id.into()),
+            ..body
+        })
+    }
+}
+
+impl<TClient> ReplacePlaylistItemsRequestBuilder<TClient> {
+    /// Split the given item URIs into a request replacing the playlist's items with the first chunk, and requests
+    /// appending each following chunk to the playlist.
+    pub(crate) fn new<S>(base_url: S, client: TClient, uris: Vec<String>) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        TClient: Clone,
+    {
+        let base_url = base_url.into();
+        let mut chunks =
+            uris.chunks(object::MAX_PLAYLIST_ITEMS_PER_REQUEST)
+                .map(|chunk| object::AddPlaylistItemsBody {
+                    uris: chunk.to_vec(),
+                    position: None,
+                });
+
+        // replacing with no items at all clears the playlist
+        let replace_body = chunks.next().unwrap_or(object::AddPlaylistItemsBody {
+            uris: Vec::new(),
+            position: None,
+        });
+
+        Self {
+            replace: AddPlaylistItemsRequestBuilder::new_with_body(
+                Method::PUT,
+                base_url.clone(),
+                replace_body,
+                client.clone(),
+            ),
+            appends: chunks
+                .map(|body| {
+                    AddPlaylistItemsRequestBuilder::new_with_body(Method::POST, base_url.clone(), body, client.clone())
+                })
+                .collect(),
+        }
+    }
+
+    /// Set whether to react to the rate limit in each request. See
+    /// [BaseRequestBuilder::react_to_rate_limit].
+    pub fn react_to_rate_limit(self, react_to_rate_limit: bool) -> Self {
+        self.map_requests(|request| request.react_to_rate_limit(react_to_rate_limit))
+    }
+
+    /// Set the maximum amount of times each request is retried after being rate limited. See
+    /// [BaseRequestBuilder::max_rate_limit_retries].
+    pub fn max_rate_limit_retries(self, max_rate_limit_retries: u32) -> Self {
+        self.map_requests(|request| request.max_rate_limit_retries(max_rate_limit_retries))
+    }
+
+    /// Set whether to automatically refresh the access token in each request. See
+    /// [BaseRequestBuilder::auto_refresh_access_token].
+    pub fn auto_refresh_access_token(self, auto_refresh_access_token: bool) -> Self {
+        self.map_requests(|request| request.auto_refresh_access_token(auto_refresh_access_token))
+    }
+
+    pub(crate) fn with_either_required_scope(self, scope: Scope, alternative: Scope) -> Self {
+        self.map_requests(|request| request.with_either_required_scope(scope, alternative))
+    }
+
+    #[cfg(all(test, feature = "async"))]
+    pub(crate) fn take_requests(self) -> Vec<AddPlaylistItemsRequestBuilder<TClient>> {
+        std::iter::once(self.replace).chain(self.appends).collect()
+    }
+
+    fn map_requests<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(AddPlaylistItemsRequestBuilder<TClient>) -> AddPlaylistItemsRequestBuilder<TClient>,
+    {
+        Self {
+            replace: f(self.replace),
+            appends: self.appends.into_iter().map(f).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TClient> ReplacePlaylistItemsRequestBuilder<TClient>
+where
+    TClient: crate::client::private::BuildHttpRequestAsync
+        + crate::client::private::AccessTokenExpiryAsync
+        + crate::client::private::GrantedScopes
+        + Send
+        + Sync,
+{
+    /// Send the requests asynchronously one after another and return the playlist's final snapshot ID.
+    pub async fn send_async(self) -> Result<String> {
+        let mut snapshot_id = self.replace.send_async().await?;
+
+        for append in self.appends {
+            snapshot_id = append.send_async().await?;
+        }
+
+        Ok(snapshot_id)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<TClient> ReplacePlaylistItemsRequestBuilder<TClient>
+where
+    TClient: crate::client::private::BuildHttpRequestSync
+        + crate::client::private::AccessTokenExpirySync
+        + crate::client::private::GrantedScopes,
+{
+    /// Send the requests synchronously one after another and return the playlist's final snapshot ID.
+    pub fn send_sync(self) -> Result<String> {
+        let mut snapshot_id = self.replace.send_sync()?;
+
+        for append in self.appends {
+            snapshot_id = append.send_sync()?;
+        }
+
+        Ok(snapshot_id)
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client::{
+        request_builder::AsyncRequestBuilder,
+        test_util::{json_response, serve_requests, user_client},
+    };
+
+    #[tokio::test]
+    async fn playlist_items_request_returns_snapshot_id() {
+        let (url, handle) = serve_requests(1, |_| {
+            json_response("201 Created", r#"{"snapshot_id":"JbtmHBDBAYu3/bt8BOXKjzKx3i0b6LCa"}"#)
+        });
+
+        let body = object::AddPlaylistItemsBody {
+            uris: vec!["spotify:track:2pDPOMX0kWA7kcPBcDCQBu".to_owned()],
+            position: None,
+        };
+        let snapshot_id = AddPlaylistItemsRequestBuilder::new_with_body(Method::POST, url, body, user_client())
+            .position(3)
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = handle.join().unwrap();
+
+        assert_eq!(snapshot_id, "JbtmHBDBAYu3/bt8BOXKjzKx3i0b6LCa");
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+            json!({
+                "uris": ["spotify:track:2pDPOMX0kWA7kcPBcDCQBu"],
+                "position": 3
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn replace_then_append() {
+        let (url, handle) = serve_requests(3, |request| {
+            let snapshot_id = format!("snapshot-{}", request.method);
+            json_response("200 OK", &format!(r#"{{"snapshot_id":"{snapshot_id}"}}"#))
+        });
+
+        let uris: Vec<_> = (0..250).map(|i| format!("spotify:track:{i:0>22}")).collect();
+        let snapshot_id = ReplacePlaylistItemsRequestBuilder::new(url, user_client(), uris.clone())
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = handle.join().unwrap();
+        let methods: Vec<_> = requests.iter().map(|request| request.method.as_str()).collect();
+        let bodies: Vec<_> = requests
+            .iter()
+            .map(|request| serde_json::from_str::<serde_json::Value>(&request.body).unwrap())
+            .collect();
+
+        assert_eq!(snapshot_id, "snapshot-POST");
+        assert_eq!(methods, ["PUT", "POST", "POST"]);
+        assert_eq!(bodies[0], json!({ "uris": uris[..100] }));
+        assert_eq!(bodies[1], json!({ "uris": uris[100..200] }));
+        assert_eq!(bodies[2], json!({ "uris": uris[200..] }));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{borrow::Cow, time::SystemTime};
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    model::{playback::PlayHistory, CursorPage},
+};
+
+const RECENTLY_PLAYED_BEFORE: &str = "before";
+const RECENTLY_PLAYED_AFTER: &str = "after";
+
+/// A builder for retrieving the user's recently played tracks. New instances are returned by the
+/// [recently_played-function](crate::client::ScopedClient::recently_played) in
+/// [ScopedClient](crate::client::ScopedClient).
+///
+/// At most one of [before](RecentlyPlayedRequestBuilder::before) and [after](RecentlyPlayedRequestBuilder::after) may
+/// be given. If both are given, the request fails with an [Error::InvalidArgument](crate::error::Error::InvalidArgument)
+/// without being sent.
+pub struct RecentlyPlayedRequestBuilder<TClient>(RequestBuilder<TClient, CursorPage<PlayHistory>>);
+
+impl<TClient> BaseRequestBuilderContainer<TClient, CursorPage<PlayHistory>> for RecentlyPlayedRequestBuilder<TClient> {
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, CursorPage<PlayHistory>> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, CursorPage<PlayHistory>> {
+        &mut self.0
+    }
+}
+
+impl<TClient> RecentlyPlayedRequestBuilder<TClient> {
+    /// The maximum number of tracks to return. If the limit is out of range, the request fails with an
+    /// [Error::InvalidPageLimit](crate::error::Error::InvalidPageLimit) without being sent.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.with_page_limit(limit, object::DEFAULT_PAGE_BOUNDS)
+    }
+
+    /// Return only tracks played before the given time, not including it. The
+    /// [before-cursor](crate::model::Cursors::before) of a previous page may be used to retrieve the page before it.
+    pub fn before(self, before: SystemTime) -> Self {
+        self.cursor(RECENTLY_PLAYED_BEFORE, before)
+    }
+
+    /// Return only tracks played after the given time, not including it. The
+    /// [after-cursor](crate::model::Cursors::after) of a previous page may be used to retrieve the page after it.
+    pub fn after(self, after: SystemTime) -> Self {
+        self.cursor(RECENTLY_PLAYED_AFTER, after)
+    }
+
+    fn cursor(mut self, key: &'static str, time: SystemTime) -> Self {
+        let millis = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        let query_params = &self.get_base_builder_mut().query_params;
+        let other_key = if key == RECENTLY_PLAYED_BEFORE {
+            RECENTLY_PLAYED_AFTER
+        } else {
+            RECENTLY_PLAYED_BEFORE
+        };
+
+        if query_params.contains_key(other_key) {
+            self = self.with_invalid_argument("only one of before and after may be given");
+        }
+
+        self.append_query(key, millis.to_string())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+    use crate::{
+        client::{private::ApiBaseUrl, request_builder::AsyncRequestBuilder, test_util::user_client},
+        error::Error,
+    };
+
+    fn builder() -> RecentlyPlayedRequestBuilder<crate::client::implicit_grant::AsyncImplicitGrantUserClient> {
+        RecentlyPlayedRequestBuilder::new(Method::GET, "http://127.0.0.1:1/recently-played", user_client())
+    }
+
+    fn query(builder: RecentlyPlayedRequestBuilder<impl ApiBaseUrl>, key: &str) -> Option<String> {
+        let url = builder.take_base_builder().build_url();
+        url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned())
+    }
+
+    #[test]
+    fn cursor_queries() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_481_661_844_589);
+
+        assert_eq!(
+            query(builder().before(time), RECENTLY_PLAYED_BEFORE).as_deref(),
+            Some("1481661844589")
+        );
+        assert_eq!(
+            query(builder().after(time), RECENTLY_PLAYED_AFTER).as_deref(),
+            Some("1481661844589")
+        );
+        assert_eq!(
+            query(builder().limit(50), object::PAGE_LIMIT_QUERY).as_deref(),
+            Some("50")
+        );
+    }
+
+    #[tokio::test]
+    async fn before_and_after_are_mutually_exclusive() {
+        let time = SystemTime::now();
+        let result = builder().before(time).after(time).send_async().await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn limit_out_of_range() {
+        let result = builder().limit(51).send_async().await;
+        assert!(matches!(result, Err(Error::InvalidPageLimit { limit: 51, max: 50 })));
+    }
+}
@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    error::Error,
+    model::{
+        id::{ArtistId, Id, IdTrait, TrackId},
+        recommendations::{Recommendations, TunableAttribute},
+        Market,
+    },
+};
+
+const RECOMMENDATIONS_SEED_ARTISTS: &str = "seed_artists";
+const RECOMMENDATIONS_SEED_TRACKS: &str = "seed_tracks";
+const RECOMMENDATIONS_SEED_GENRES: &str = "seed_genres";
+
+const MAX_SEEDS: usize = 5;
+
+/// A builder for retrieving track recommendations. New instances are returned by the
+/// [recommendations-function](crate::client::UnscopedClient::recommendations) in
+/// [UnscopedClient](crate::client::UnscopedClient).
+///
+/// Between one and five seeds in total must be given with the [seed_artists](RecommendationsBuilder::seed_artists),
+/// [seed_tracks](RecommendationsBuilder::seed_tracks) and [seed_genres](RecommendationsBuilder::seed_genres)
+/// functions. Otherwise, the request fails with an [Error::InvalidArgument](crate::error::Error::InvalidArgument)
+/// without being sent.
+pub struct RecommendationsBuilder<TClient> {
+    builder: RequestBuilder<TClient, Recommendations>,
+    artist_seeds: usize,
+    track_seeds: usize,
+    genre_seeds: usize,
+}
+
+impl<TClient> BaseRequestBuilderContainer<TClient, Recommendations> for RecommendationsBuilder<TClient> {
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self {
+            builder: RequestBuilder::new(method, base_url, client).with_default_market(),
+            artist_seeds: 0,
+            track_seeds: 0,
+            genre_seeds: 0,
+        }
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self {
+            builder: RequestBuilder::new_with_body(method, base_url, body, client).with_default_market(),
+            artist_seeds: 0,
+            track_seeds: 0,
+            genre_seeds: 0,
+        }
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, Recommendations> {
+        let seeds = self.artist_seeds + self.track_seeds + self.genre_seeds;
+        let mut builder = self.builder;
+
+        // the seed count can only be checked once every seed has been given
+        if !(1..=MAX_SEEDS).contains(&seeds) && builder.argument_error.is_none() {
+            builder.argument_error = Some(Error::InvalidArgument(
+                format!("{seeds} seeds given, expected between 1 and {MAX_SEEDS}").into(),
+            ));
+        }
+
+        builder
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, Recommendations> {
+        &mut self.builder
+    }
+}
+
+impl<TClient> RecommendationsBuilder<TClient> {
+    /// Artists to use as seeds for the recommendations. Replaces any previously given seed artists.
+    pub fn seed_artists<'a, I>(mut self, artists: I) -> Self
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        let artists = artists.into_iter().map(|id| id.as_str().to_owned()).collect::<Vec<_>>();
+        self.artist_seeds = artists.len();
+        self.append_query(RECOMMENDATIONS_SEED_ARTISTS, artists.join(","))
+    }
+
+    /// Tracks to use as seeds for the recommendations. Replaces any previously given seed tracks.
+    pub fn seed_tracks<'a, I>(mut self, tracks: I) -> Self
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        let tracks = tracks.into_iter().map(|id| id.as_str().to_owned()).collect::<Vec<_>>();
+        self.track_seeds = tracks.len();
+        self.append_query(RECOMMENDATIONS_SEED_TRACKS, tracks.join(","))
+    }
+
+    /// Genres to use as seeds for the recommendations. Replaces any previously given seed genres. The available genres
+    /// can be retrieved with the [available_genre_seeds](crate::client::UnscopedClient::available_genre_seeds)-function.
+    pub fn seed_genres<I, S>(mut self, genres: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let genres = genres
+            .into_iter()
+            .map(|genre| genre.as_ref().to_owned())
+            .collect::<Vec<_>>();
+        self.genre_seeds = genres.len();
+        self.append_query(RECOMMENDATIONS_SEED_GENRES, genres.join(","))
+    }
+
+    /// Only recommend tracks whose given attribute is at least the given value.
+    pub fn min(self, attribute: TunableAttribute, value: f32) -> Self {
+        self.append_query(attribute.min_query(), value.to_string())
+    }
+
+    /// Only recommend tracks whose given attribute is at most the given value.
+    pub fn max(self, attribute: TunableAttribute, value: f32) -> Self {
+        self.append_query(attribute.max_query(), value.to_string())
+    }
+
+    /// Prefer tracks whose given attribute is closest to the given value.
+    pub fn target(self, attribute: TunableAttribute, value: f32) -> Self {
+        self.append_query(attribute.target_query(), value.to_string())
+    }
+
+    /// The number of tracks to recommend. If the limit is out of range, the request fails with an
+    /// [Error::InvalidPageLimit](crate::error::Error::InvalidPageLimit) without being sent.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 100.
+    pub fn limit(self, limit: u32) -> Self {
+        self.with_page_limit(limit, object::RECOMMENDATIONS_PAGE_BOUNDS)
+    }
+
+    /// Specify a country such that content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    ///
+    /// Either a [CountryCode](crate::model::CountryCode) or a [Market] may be given.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(object::MARKET_QUERY, market.into().as_str())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        client::{private::ApiBaseUrl, request_builder::AsyncRequestBuilder, test_util::user_client},
+        error::Error,
+        model::id::IdFromBare,
+    };
+
+    fn builder() -> RecommendationsBuilder<crate::client::implicit_grant::AsyncImplicitGrantUserClient> {
+        RecommendationsBuilder::new(Method::GET, "http://127.0.0.1:1/recommendations", user_client())
+    }
+
+    fn query(builder: RecommendationsBuilder<impl ApiBaseUrl>) -> HashMap<String, String> {
+        builder
+            .take_base_builder()
+            .build_url()
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn tunable_queries() {
+        let query = query(
+            builder()
+                .seed_genres(["j-pop"])
+                .min(TunableAttribute::Energy, 0.5)
+                .max(TunableAttribute::Tempo, 140.0)
+                .target(TunableAttribute::Danceability, 0.75)
+                .limit(10),
+        );
+
+        let expected = HashMap::from([
+            ("seed_genres".to_owned(), "j-pop".to_owned()),
+            ("min_energy".to_owned(), "0.5".to_owned()),
+            ("max_tempo".to_owned(), "140".to_owned()),
+            ("target_danceability".to_owned(), "0.75".to_owned()),
+            ("limit".to_owned(), "10".to_owned()),
+        ]);
+
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn seed_queries() {
+        let query = query(
+            builder()
+                .seed_artists([Id::<ArtistId>::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap()])
+                .seed_tracks([
+                    Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap(),
+                    Id::<TrackId>::from_bare("3n3Ppam7vgaVa1iaRUc9Lp").unwrap(),
+                ]),
+        );
+
+        assert_eq!(query["seed_artists"], "6pNgnvzBa6Bthsv8SrZJYl");
+        assert_eq!(query["seed_tracks"], "2pDPOMX0kWA7kcPBcDCQBu,3n3Ppam7vgaVa1iaRUc9Lp");
+    }
+
+    #[tokio::test]
+    async fn no_seeds() {
+        let result = builder().send_async().await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn too_many_seeds() {
+        let result = builder()
+            .seed_genres(["j-pop", "anime"])
+            .seed_tracks([Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap()])
+            .seed_artists([
+                Id::<ArtistId>::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap(),
+                Id::<ArtistId>::from_bare("0NIPkIjTV8mB795yEIiPYL").unwrap(),
+                Id::<ArtistId>::from_bare("1snhtMLeb2DYoMOcVbb8iB").unwrap(),
+            ])
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn seed_count_replaces_previous_seeds() {
+        let builder = builder()
+            .seed_genres(["j-pop", "anime", "rock", "metal", "pop"])
+            .seed_genres(["j-pop"])
+            .seed_artists([Id::<ArtistId>::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap()]);
+
+        assert!(builder.take_base_builder().argument_error.is_none());
+    }
+}
@@ -3,20 +3,21 @@ use std::borrow::Cow;
 use reqwest::Method;
 
 use crate::{
-    client::request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    },
     model::{
         search::{
             AlbumSearchResults, ArtistSearchResults, SearchResults, SearchResultsObject, ToTypesString,
             TrackSearchResults, DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET, DEFAULT_SEARCH_TYPES_STRING,
         },
-        CountryCode,
+        Market,
     },
 };
 
 const SEARCH_QUERY: &str = "q";
 const SEARCH_TYPE: &str = "type";
-const SEARCH_LIMIT: &str = "limit";
-const SEARCH_OFFSET: &str = "offset";
 const SEARCH_MARKET: &str = "market";
 
 impl TryFromEmptyResponse for SearchResultsObject {}
@@ -35,9 +36,10 @@ impl<TClient> BaseRequestBuilderContainer<TClient, SearchResultsObject, (), Sear
     {
         Self(
             RequestBuilder::new(method, base_url, client)
+                .with_default_market()
                 .append_query(SEARCH_TYPE, DEFAULT_SEARCH_TYPES_STRING)
-                .append_query(SEARCH_LIMIT, DEFAULT_SEARCH_LIMIT.to_string())
-                .append_query(SEARCH_OFFSET, DEFAULT_SEARCH_OFFSET.to_string()),
+                .append_query(object::PAGE_LIMIT_QUERY, DEFAULT_SEARCH_LIMIT.to_string())
+                .append_query(object::PAGE_OFFSET_QUERY, DEFAULT_SEARCH_OFFSET.to_string()),
         )
     }
 
@@ -45,7 +47,7 @@ impl<TClient> BaseRequestBuilderContainer<TClient, SearchResultsObject, (), Sear
     where
         S: Into<Cow<'static, str>>,
     {
-        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+        Self(RequestBuilder::new_with_body(method, base_url, body, client).with_default_market())
     }
 
     fn take_base_builder(self) -> RequestBuilder<TClient, SearchResultsObject, (), SearchResults> {
@@ -76,25 +78,62 @@ where
         self.append_query(SEARCH_TYPE, types.to_types_string())
     }
 
-    /// The maximum number of results to return in each item type.
+    /// The maximum number of results to return in each item type. If the limit is out of range, the request fails
+    /// with an [Error::InvalidPageLimit](crate::error::Error::InvalidPageLimit) without being sent.
     ///
-    /// Default: 20. Maximum: 50.
+    /// Default: 20. Minimum: 1. Maximum: 50.
     pub fn limit(self, limit: u32) -> Self {
-        self.append_query(SEARCH_LIMIT, limit.to_string())
+        self.with_page_limit(limit, object::SEARCH_PAGE_BOUNDS)
     }
 
     /// The index of the first result to return. By combining this with [limit](SearchBuilder::limit), you may request
     /// new pages of content.
     ///
-    /// Default: 0.
+    /// Default: 0. Maximum: 1000.
     pub fn offset(self, offset: u32) -> Self {
-        self.append_query(SEARCH_OFFSET, offset.to_string())
+        self.with_page_offset(offset, object::SEARCH_PAGE_BOUNDS)
     }
 
     /// Specify a country such that content that is available in that market will be returned. If using an
     /// user-authenticated client, the country associated with the corresponding user account will take priority over
     /// this parameter.
-    pub fn market(self, market: CountryCode) -> Self {
-        self.append_query(SEARCH_MARKET, market.to_string())
+    ///
+    /// Either a [CountryCode](crate::model::CountryCode) or a [Market] may be given.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(SEARCH_MARKET, market.into().as_str())
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{request_builder::AsyncRequestBuilder, test_util::user_client},
+        error::Error,
+    };
+
+    fn builder() -> SearchBuilder<crate::client::implicit_grant::AsyncImplicitGrantUserClient> {
+        SearchBuilder::new(Method::GET, "http://127.0.0.1:1/search", user_client()).query("miku".to_owned())
+    }
+
+    #[tokio::test]
+    async fn limit_out_of_range() {
+        let result = builder().limit(51).send_async().await;
+        assert!(matches!(result, Err(Error::InvalidPageLimit { limit: 51, max: 50 })));
+    }
+
+    #[tokio::test]
+    async fn offset_out_of_range() {
+        let result = builder().offset(1001).send_async().await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidPageOffset {
+                offset: 1001,
+                max: 1000
+            })
+        ));
     }
 }
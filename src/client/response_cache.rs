@@ -0,0 +1,112 @@
+//! An in-memory cache of API response bodies used for conditional requests.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+use reqwest::{header::HeaderValue, Url};
+
+/// A response body and the entity tag Spotify returned with it.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub etag: HeaderValue,
+    pub body: Vec<u8>,
+}
+
+/// A cache of response bodies keyed by their request URL. Once the cache is full, storing a new response evicts the
+/// oldest one.
+#[derive(Debug)]
+pub struct ResponseCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    responses: HashMap<Url, CachedResponse>,
+    // the URLs in the order their responses were stored in, oldest first
+    order: VecDeque<Url>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    /// Returns the maximum amount of responses the cache holds.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the cached response for the given URL, if any.
+    pub(crate) fn get(&self, url: &Url) -> Option<CachedResponse> {
+        self.entries
+            .lock()
+            .expect("response cache mutex poisoned")
+            .responses
+            .get(url)
+            .cloned()
+    }
+
+    /// Stores the response for the given URL, replacing any previously stored response for it.
+    pub(crate) fn insert(&self, url: Url, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().expect("response cache mutex poisoned");
+
+        if entries.responses.insert(url.clone(), response).is_some() {
+            entries.order.retain(|stored| *stored != url);
+        }
+
+        entries.order.push_back(url);
+
+        while entries.order.len() > self.capacity {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.responses.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(path: &str) -> Url {
+        Url::parse("https://api.spotify.com/v1/").unwrap().join(path).unwrap()
+    }
+
+    fn response(etag: &'static str) -> CachedResponse {
+        CachedResponse {
+            etag: HeaderValue::from_static(etag),
+            body: etag.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn oldest_response_is_evicted() {
+        let cache = ResponseCache::new(2);
+        cache.insert(url("tracks/1"), response("\"1\""));
+        cache.insert(url("tracks/2"), response("\"2\""));
+        cache.insert(url("tracks/1"), response("\"3\""));
+        cache.insert(url("tracks/4"), response("\"4\""));
+
+        assert!(cache.get(&url("tracks/2")).is_none());
+        assert_eq!(cache.get(&url("tracks/1")).unwrap().etag, "\"3\"");
+        assert_eq!(cache.get(&url("tracks/4")).unwrap().etag, "\"4\"");
+    }
+
+    #[test]
+    fn zero_capacity_stores_nothing() {
+        let cache = ResponseCache::new(0);
+        cache.insert(url("tracks/1"), response("\"1\""));
+
+        assert!(cache.get(&url("tracks/1")).is_none());
+    }
+}
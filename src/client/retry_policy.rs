@@ -0,0 +1,233 @@
+//! Contains the [RetryPolicy] for retrying requests that fail because of transient errors.
+
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{Method, StatusCode};
+
+/// A response status a [RetryPolicy] may retry requests on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryStatus {
+    /// HTTP 502 Bad Gateway.
+    BadGateway,
+    /// HTTP 503 Service Unavailable.
+    ServiceUnavailable,
+    /// HTTP 504 Gateway Timeout.
+    GatewayTimeout,
+}
+
+/// A policy for retrying requests that fail because of transient errors, such as Spotify's servers being temporarily
+/// unavailable or the connection to them failing. Failed requests are retried after an exponentially growing delay: the
+/// first retry waits the base delay, and each following retry waits twice as long as the previous one.
+///
+/// Only `GET` requests are retried after receiving one of the [retried response statuses](Self::retry_statuses), or if
+/// the connection fails in the middle of the request, since Spotify may have already acted on a request that modifies
+/// something. Every request is retried if connecting to Spotify fails, since the request never reached Spotify.
+///
+/// Rate limited responses (HTTP 429) aren't retried by the policy. Instead, they're handled by
+/// [reacting to the rate limit](crate::client::request_builder::BaseRequestBuilder::react_to_rate_limit), which waits as
+/// long as Spotify asks for.
+///
+/// The asynchronous client waits between the attempts with the runtime's timer, which requires either the
+/// `tokio_sleep` or the `async_std_sleep` feature. Without either, asynchronous requests aren't retried. The
+/// synchronous client blocks the current thread while it waits.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use ferrispot::client::{RetryPolicy, RetryStatus, SpotifyClientBuilder};
+/// let policy = RetryPolicy::default()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(200))
+///     .retry_statuses([RetryStatus::ServiceUnavailable]);
+///
+/// let builder = SpotifyClientBuilder::new("client ID").retry_policy(policy);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    jitter: bool,
+    statuses: Vec<RetryStatus>,
+}
+
+/// Why sending a request failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RetryCause {
+    /// Spotify responded with the given status.
+    Status(StatusCode),
+    /// Connecting to Spotify failed, so the request was never sent.
+    Connect,
+    /// The connection failed or timed out after the request may have been sent.
+    Interrupted,
+}
+
+impl RetryStatus {
+    fn status_code(self) -> StatusCode {
+        match self {
+            RetryStatus::BadGateway => StatusCode::BAD_GATEWAY,
+            RetryStatus::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            RetryStatus::GatewayTimeout => StatusCode::GATEWAY_TIMEOUT,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Returns a policy that makes at most 3 attempts, starting from a delay of 500 milliseconds with jitter, and
+    /// retries every [RetryStatus].
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            jitter: true,
+            statuses: vec![
+                RetryStatus::BadGateway,
+                RetryStatus::ServiceUnavailable,
+                RetryStatus::GatewayTimeout,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The maximum amount of times a request is attempted, including the first attempt. A maximum of 1 disables
+    /// retrying.
+    ///
+    /// Default: 3. Minimum: 1.
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..self
+        }
+    }
+
+    /// The delay before the first retry. Each following retry waits twice as long as the previous one.
+    ///
+    /// Default: 500 milliseconds.
+    pub fn base_delay(self, base_delay: Duration) -> Self {
+        Self { base_delay, ..self }
+    }
+
+    /// Whether to randomize each delay between half of the delay and the full delay. This spreads out the retries of
+    /// many requests that failed at the same time.
+    ///
+    /// Default: true.
+    pub fn jitter(self, jitter: bool) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// The response statuses `GET` requests are retried on.
+    ///
+    /// Default: every [RetryStatus].
+    pub fn retry_statuses<I>(self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = RetryStatus>,
+    {
+        Self {
+            statuses: statuses.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Returns whether a request with the given method should be retried after it failed for the given cause, given
+    /// how many times it has been attempted so far.
+    pub(crate) fn should_retry(&self, method: &Method, cause: RetryCause, attempts: u32) -> bool {
+        if attempts >= self.max_attempts {
+            return false;
+        }
+
+        match cause {
+            RetryCause::Connect => true,
+            RetryCause::Interrupted => *method == Method::GET,
+            RetryCause::Status(status) => {
+                *method == Method::GET && self.statuses.iter().any(|retried| retried.status_code() == status)
+            }
+        }
+    }
+
+    /// Returns how long to wait before the next attempt, given how many times the request has been attempted so far.
+    pub(crate) fn delay(&self, attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor);
+
+        if self.jitter {
+            rand::thread_rng().gen_range(delay / 2..=delay)
+        } else {
+            delay
+        }
+    }
+}
+
+impl RetryCause {
+    /// Returns the cause of the given request error, if it's one that may be retried.
+    pub(crate) fn from_error(error: &reqwest::Error) -> Option<Self> {
+        if error.is_connect() {
+            Some(RetryCause::Connect)
+        } else if error.is_timeout() || error.is_request() {
+            Some(RetryCause::Interrupted)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .max_attempts(4)
+            .base_delay(Duration::from_millis(100))
+            .jitter(false)
+    }
+
+    #[test]
+    fn backoff_schedule() {
+        let policy = policy();
+        let delays: Vec<_> = (1..=3).map(|attempts| policy.delay(attempts)).collect();
+
+        assert_eq!(delays, [100, 200, 400].map(Duration::from_millis));
+    }
+
+    #[test]
+    fn jitter_stays_within_delay() {
+        let policy = policy().jitter(true);
+
+        for attempts in 1..=3 {
+            let full = policy.clone().jitter(false).delay(attempts);
+            let delay = policy.delay(attempts);
+
+            assert!(delay >= full / 2 && delay <= full, "{delay:?} not within {full:?}");
+        }
+    }
+
+    #[test]
+    fn attempts_are_limited() {
+        let policy = policy();
+        let cause = RetryCause::Status(StatusCode::SERVICE_UNAVAILABLE);
+
+        assert!(policy.should_retry(&Method::GET, cause, 3));
+        assert!(!policy.should_retry(&Method::GET, cause, 4));
+        assert!(!policy
+            .max_attempts(1)
+            .should_retry(&Method::GET, RetryCause::Connect, 1));
+    }
+
+    #[test]
+    fn only_get_requests_are_retried_on_statuses() {
+        let policy = policy().retry_statuses([RetryStatus::BadGateway, RetryStatus::GatewayTimeout]);
+        let bad_gateway = RetryCause::Status(StatusCode::BAD_GATEWAY);
+
+        assert!(policy.should_retry(&Method::GET, bad_gateway, 1));
+        assert!(!policy.should_retry(&Method::GET, RetryCause::Status(StatusCode::SERVICE_UNAVAILABLE), 1));
+        assert!(!policy.should_retry(&Method::GET, RetryCause::Status(StatusCode::TOO_MANY_REQUESTS), 1));
+
+        for method in [Method::POST, Method::PUT, Method::DELETE] {
+            assert!(!policy.should_retry(&method, bad_gateway, 1));
+            assert!(!policy.should_retry(&method, RetryCause::Interrupted, 1));
+            assert!(policy.should_retry(&method, RetryCause::Connect, 1));
+        }
+
+        assert!(policy.should_retry(&Method::GET, RetryCause::Interrupted, 1));
+    }
+}
@@ -1,29 +1,50 @@
+use std::time::Duration;
 #[cfg(feature = "async")]
 use std::{future::Future, pin::Pin};
 
+use base64::Engine;
 use log::{error, trace, warn};
 use reqwest::{Method, StatusCode};
 
+#[cfg(feature = "async")]
+use super::request_builder::api_error_from_response_async;
+#[cfg(feature = "sync")]
+use super::request_builder::api_error_from_response_sync;
 use super::API_CURRENT_USER_PROFILE_ENDPOINT;
 use crate::{
     client::{
         object,
         request_builder::{
-            BaseRequestBuilderContainer, PlayContextRequestBuilder, PlayItemsRequestBuilder,
-            PlayerControlRequestBuilder, RequestBuilder,
+            AddPlaylistItemsRequestBuilder, BasePlaylistItemsRequestBuilder, BaseRequestBuilderContainer,
+            ChangePlaylistDetailsRequestBuilder, ChunkedRequestBuilder, CreatePlaylistRequestBuilder,
+            FollowedArtistsRequestBuilder, PagedRequestBuilder, PlayContextRequestBuilder, PlayItemsRequestBuilder,
+            PlayerControlRequestBuilder, RecentlyPlayedRequestBuilder, RemovePlaylistItemsByPositionRequestBuilder,
+            RemovePlaylistItemsRequestBuilder, ReorderPlaylistItemsRequestBuilder, ReplacePlaylistItemsRequestBuilder,
+            RequestBuilder,
         },
-        API_CURRENTLY_PLAYING_ITEM_ENDPOINT, API_PLAYBACK_STATE_ENDPOINT, API_PLAYER_DEVICES_ENDPOINT,
+        API_CURRENTLY_PLAYING_ITEM_ENDPOINT, API_CURRENT_USER_PLAYLISTS_ENDPOINT, API_FOLLOWING_CONTAINS_ENDPOINT,
+        API_FOLLOWING_ENDPOINT, API_PLAYBACK_STATE_ENDPOINT, API_PLAYER_DEVICES_ENDPOINT, API_PLAYER_ENDPOINT,
         API_PLAYER_NEXT_ENDPOINT, API_PLAYER_PAUSE_ENDPOINT, API_PLAYER_PLAY_ENDPOINT, API_PLAYER_PREVIOUS_ENDPOINT,
         API_PLAYER_QUEUE_ENDPOINT, API_PLAYER_REPEAT_ENDPOINT, API_PLAYER_SEEK_ENDPOINT, API_PLAYER_SHUFFLE_ENDPOINT,
-        API_PLAYER_VOLUME_ENDPOINT,
+        API_PLAYER_VOLUME_ENDPOINT, API_PLAYLISTS_ENDPOINT, API_RECENTLY_PLAYED_ENDPOINT,
+        API_SAVED_ALBUMS_CONTAINS_ENDPOINT, API_SAVED_ALBUMS_ENDPOINT, API_SAVED_SHOWS_CONTAINS_ENDPOINT,
+        API_SAVED_SHOWS_ENDPOINT, API_SAVED_TRACKS_CONTAINS_ENDPOINT, API_SAVED_TRACKS_ENDPOINT,
+        API_USER_PROFILE_ENDPOINT,
     },
     error::{Error, Result},
     model::{
         error::{ApiErrorMessage, ApiErrorResponse},
-        id::{IdTrait, PlayableContext, PlayableItem},
-        playback::{CurrentlyPlayingItem, Device, PlaybackState, RepeatState},
+        id::{
+            AlbumId, ArtistId, Id, IdTrait, ItemTypeId, PlayableContext, PlayableItem, PlaylistId, ShowId, TrackId,
+            UserId,
+        },
+        library::{SavedAlbum, SavedAlbums, SavedShow, SavedShows, SavedTrack, SavedTracks},
+        playback::{CurrentlyPlayingItem, Device, NowPlaying, PlaybackQueue, PlaybackState, RepeatState},
+        playlist::{PartialPlaylist, Playlists},
         user::User,
+        Page,
     },
+    scope::Scope,
 };
 
 /// All scoped Spotify endpoints. The functions in this trait require user authentication, since they're specific to a
@@ -37,18 +58,51 @@ where
     /// Get information about the user’s current playback state, including track or episode, progress, and active
     /// device.
     ///
-    /// This function returns a superset of the [currently playing item](Self::currently_playing_item).
+    /// This function returns a superset of the [currently playing item](Self::currently_playing_item). If there is no
+    /// active playback, the request returns `None`.
     ///
     /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
     fn playback_state(&self) -> RequestBuilder<Self, Option<PlaybackState>> {
         RequestBuilder::new(Method::GET, API_PLAYBACK_STATE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
     }
 
-    /// Get the item currently being played on the user's Spotify account.
+    /// Get the item currently being played on the user's Spotify account. If there is no active playback, the request
+    /// returns `None`.
     ///
     /// Required scope: [UserReadCurrentlyPlaying](crate::scope::Scope::UserReadCurrentlyPlaying).
     fn currently_playing_item(&self) -> RequestBuilder<Self, Option<CurrentlyPlayingItem>> {
         RequestBuilder::new(Method::GET, API_CURRENTLY_PLAYING_ITEM_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadCurrentlyPlaying)
+    }
+
+    /// Get a [simplified view](NowPlaying) of the track currently being played on the user's Spotify account, with its
+    /// name, artists, album, progress and duration ready to be displayed. If there is no active playback, or the
+    /// playing item isn't [public](crate::model::playback::PublicPlayingItem), the request returns `None`. Paused
+    /// playback is still returned; its [is_playing](NowPlaying::is_playing) is `false`.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    fn now_playing(&self) -> RequestBuilder<Self, object::NowPlayingResponse, (), Option<NowPlaying>> {
+        RequestBuilder::new(Method::GET, API_PLAYBACK_STATE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
+    }
+
+    /// Get the user's playback queue; the currently playing item and the items queued after it.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    fn queue(&self) -> RequestBuilder<Self, PlaybackQueue> {
+        RequestBuilder::new(Method::GET, API_PLAYER_QUEUE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
+    }
+
+    /// Get the tracks the user has recently played. The results are paged with cursors; the
+    /// [before](RecentlyPlayedRequestBuilder::before) and [after](RecentlyPlayedRequestBuilder::after) functions in
+    /// the returned builder select which page to return. Podcast episodes are not included.
+    ///
+    /// Required scope: [UserReadRecentlyPlayed](crate::scope::Scope::UserReadRecentlyPlayed).
+    fn recently_played(&self) -> RecentlyPlayedRequestBuilder<Self> {
+        RecentlyPlayedRequestBuilder::new(Method::GET, API_RECENTLY_PLAYED_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadRecentlyPlayed)
     }
 
     /// Get information about the user's available devices.
@@ -56,6 +110,37 @@ where
     /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
     fn devices(&self) -> RequestBuilder<Self, object::DevicesResponse, (), Vec<Device>> {
         RequestBuilder::new(Method::GET, API_PLAYER_DEVICES_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
+    }
+
+    /// Transfer playback to the given Spotify device in the user's account. If `play` is `true`, playback starts on the
+    /// new device. Otherwise, the current playback state is kept.
+    ///
+    /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
+    fn transfer_playback<S>(&self, device_id: S, play: bool) -> RequestBuilder<Self, (), object::TransferPlaybackBody>
+    where
+        S: Into<String>,
+    {
+        let body = object::TransferPlaybackBody {
+            device_ids: [device_id.into()],
+            play,
+        };
+
+        trace!("Transfer playback body: {:?}", body);
+        let mut builder = RequestBuilder::new_with_body(Method::PUT, API_PLAYER_ENDPOINT, body, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(Box::new(handle_player_control_response_async));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(Box::new(handle_player_control_response_sync));
+        }
+
+        builder
     }
 
     /// Start playing a collection of playable items in order; tracks or episodes.
@@ -66,6 +151,10 @@ where
     /// currently active device. In case no device is active and no device is given, the function will
     /// return an [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
+    /// If the user's explicit content filter is enabled, playing an explicit track may fail. The filter's state is
+    /// available through [User::explicit_content_filtered](crate::model::user::User::explicit_content_filtered) once
+    /// the [current user's profile](Self::current_user_profile) has been fetched.
+    ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn play_items<'a, I, P>(&'a self, items: I) -> PlayItemsRequestBuilder<Self>
     where
@@ -75,11 +164,13 @@ where
         let tracks: Vec<_> = items.into_iter().map(|id| id.into()).collect();
         let body = object::PlayItemsBody {
             uris: tracks.iter().map(|id| id.as_uri().to_string()).collect(),
+            position_ms: None,
         };
 
         trace!("Play body: {:?}", body);
         let mut builder =
-            PlayItemsRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone());
+            PlayItemsRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone())
+                .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -102,19 +193,22 @@ where
     /// currently active device. In case no device is active and no device is given, the function will return an
     /// [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
+    /// If the user's explicit content filter is enabled, playing an explicit track may fail. The filter's state is
+    /// available through [User::explicit_content_filtered](crate::model::user::User::explicit_content_filtered) once
+    /// the [current user's profile](Self::current_user_profile) has been fetched.
+    ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn play_context<'a>(&'a self, context: PlayableContext<'a>) -> PlayContextRequestBuilder<Self> {
         let body = object::PlayContextBody {
             context_uri: context.as_uri().to_string(),
-            offset: object::PlayContextOffset {
-                position: Some(0),
-                uri: None,
-            },
+            offset: None,
+            position_ms: None,
         };
 
         trace!("Play body: {:?}", body);
         let mut builder =
-            PlayContextRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone());
+            PlayContextRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone())
+                .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -139,7 +233,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn resume(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PLAY_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PLAY_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -164,7 +259,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn pause(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PAUSE_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PAUSE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -187,11 +283,19 @@ where
     /// currently active device. In case no device is active and no device is given, the function will
     /// return an [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
+    /// The [unknown](RepeatState::Unknown) repeat state can't be set; attempting to do so fails with an
+    /// [Error::InvalidArgument](crate::error::Error::InvalidArgument).
+    ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
-    fn repeat_state(&self, repeat_state: RepeatState) -> PlayerControlRequestBuilder<Self> {
+    fn set_repeat(&self, repeat_state: RepeatState) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_REPEAT_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::REPEAT_STATE_QUERY, repeat_state.as_str());
 
+        if repeat_state == RepeatState::Unknown {
+            builder = builder.with_invalid_argument("unknown repeat state can't be set");
+        }
+
         #[cfg(feature = "async")]
         {
             builder = builder.with_async_response_handler(Box::new(handle_player_control_response_async));
@@ -214,8 +318,9 @@ where
     /// return an [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
-    fn shuffle(&self, shuffle: bool) -> PlayerControlRequestBuilder<Self> {
+    fn set_shuffle(&self, shuffle: bool) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_SHUFFLE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::SHUFFLE_QUERY, if shuffle { "true" } else { "false" });
 
         #[cfg(feature = "async")]
@@ -231,7 +336,9 @@ where
         builder
     }
 
-    /// Set the volume for the current playback. `volume_percent` is an integer between 0 and 100 inclusive.
+    /// Set the volume for the current playback. `percent` is an integer between 0 and 100 inclusive. If it is greater
+    /// than 100, the request is not sent and it fails with an
+    /// [Error::InvalidArgument](crate::error::Error::InvalidArgument).
     ///
     /// A Spotify device ID in the user's account may be supplied with the [`device_id`-function in the request builder
     /// this function returns](crate::client::request_builder::BasePlayerControlRequestBuilder::device_id) such that
@@ -240,13 +347,14 @@ where
     /// return an [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
-    fn volume<U>(&self, volume_percent: U) -> PlayerControlRequestBuilder<Self>
-    where
-        U: Into<u8>,
-    {
-        let volume_percent = volume_percent.into().to_string();
+    fn set_volume(&self, percent: u8) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_VOLUME_ENDPOINT, self.clone())
-            .append_query(object::VOLUME_PERCENT_QUERY, volume_percent);
+            .with_required_scope(Scope::UserModifyPlaybackState)
+            .append_query(object::VOLUME_PERCENT_QUERY, percent.to_string());
+
+        if percent > 100 {
+            builder = builder.with_invalid_argument(format!("volume percentage {percent} is greater than 100"));
+        }
 
         #[cfg(feature = "async")]
         {
@@ -271,7 +379,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn next(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_NEXT_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_NEXT_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -296,7 +405,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn previous(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_PREVIOUS_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_PREVIOUS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -311,9 +421,9 @@ where
         builder
     }
 
-    /// Seeks to the given position in the user’s currently playing track. `position` is the position in milliseconds to
-    /// seek to. Passing in a position that is greater than the length of the track will cause the player to start
-    /// playing the next song.
+    /// Seeks to the given position in the user’s currently playing track. The position is sent to Spotify with
+    /// millisecond precision. Passing in a position that is greater than the length of the track will cause the player
+    /// to start playing the next song.
     ///
     /// A Spotify device ID in the user's account may be supplied with the [`device_id`-function in the request builder
     /// this function returns](crate::client::request_builder::BasePlayerControlRequestBuilder::device_id) such that
@@ -322,12 +432,10 @@ where
     /// return an [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
-    fn seek<U>(&self, position: U) -> PlayerControlRequestBuilder<Self>
-    where
-        U: Into<u64>,
-    {
-        let position = position.into().to_string();
+    fn seek(&self, position: Duration) -> PlayerControlRequestBuilder<Self> {
+        let position = position.as_millis().to_string();
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_SEEK_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::SEEK_POSITION_QUERY, position);
 
         #[cfg(feature = "async")]
@@ -354,6 +462,7 @@ where
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn add_to_queue<'a>(&'a self, item: PlayableItem<'a>) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_QUEUE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::QUEUE_URI_QUERY, item.as_uri().to_string());
 
         #[cfg(feature = "async")]
@@ -387,6 +496,633 @@ where
     fn current_user_profile(&self) -> RequestBuilder<Self, User> {
         RequestBuilder::new(Method::GET, API_CURRENT_USER_PROFILE_ENDPOINT, self.clone())
     }
+
+    /// Get the playlists the user owns or follows. The page's size and offset may be set with the
+    /// [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    ///
+    /// Required scope: [PlaylistReadPrivate](crate::scope::Scope::PlaylistReadPrivate).
+    fn current_user_playlists(&self) -> PagedRequestBuilder<Self, Playlists, Page<Playlists, PartialPlaylist>> {
+        PagedRequestBuilder::new(Method::GET, API_CURRENT_USER_PLAYLISTS_ENDPOINT, self.clone())
+            .with_page_bounds(object::PLAYLISTS_PAGE_BOUNDS)
+            .with_required_scope(Scope::PlaylistReadPrivate)
+    }
+
+    /// Create a new playlist for a user. The playlist is empty when created. The playlist's visibility, collaborative
+    /// status and description may be set with the functions in the returned builder.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn create_playlist<'a, S>(&'a self, user_id: Id<'a, UserId>, name: S) -> CreatePlaylistRequestBuilder<Self>
+    where
+        S: Into<String>,
+    {
+        let body = object::CreatePlaylistBody {
+            name: name.into(),
+            public: None,
+            collaborative: None,
+            description: None,
+        };
+
+        CreatePlaylistRequestBuilder::new_with_body(
+            Method::POST,
+            format!("{API_USER_PROFILE_ENDPOINT}/{}/playlists", user_id.as_str()),
+            body,
+            self.clone(),
+        )
+        .with_required_scope(Scope::PlaylistModifyPublic)
+    }
+
+    /// Change a playlist's details. Only the details set with the functions in the returned builder are changed; the
+    /// playlist's name, visibility, collaborative status and description.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn change_playlist_details<'a>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+    ) -> ChangePlaylistDetailsRequestBuilder<Self> {
+        ChangePlaylistDetailsRequestBuilder::new_with_body(
+            Method::PUT,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}", playlist_id.as_str()),
+            object::ChangePlaylistDetailsBody::default(),
+            self.clone(),
+        )
+        .with_either_required_scope(Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate)
+    }
+
+    /// Add up to 100 playable items to a playlist; tracks or episodes. The request returns the playlist's new snapshot
+    /// ID. By default, the items are appended to the end of the playlist; a position to insert them in may be set with
+    /// the [`position`](AddPlaylistItemsRequestBuilder::position) function in the returned builder.
+    ///
+    /// If more than 100 items are given, the request fails with an
+    /// [Error::InvalidArgument](crate::error::Error::InvalidArgument) without being sent.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn add_items_to_playlist<'a, I, P>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+        items: I,
+    ) -> AddPlaylistItemsRequestBuilder<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PlayableItem<'a>>,
+    {
+        let body = object::AddPlaylistItemsBody {
+            uris: playable_item_uris(items),
+            position: None,
+        };
+
+        trace!("Add playlist items body: {:?}", body);
+        let item_count = body.uris.len();
+        let builder = AddPlaylistItemsRequestBuilder::new_with_body(
+            Method::POST,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/tracks", playlist_id.as_str()),
+            body,
+            self.clone(),
+        )
+        .with_either_required_scope(Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate);
+
+        check_playlist_item_count(builder, item_count)
+    }
+
+    /// Replace all the items in a playlist with any amount of playable items; tracks or episodes. Giving no items clears
+    /// the playlist. The request returns the playlist's new snapshot ID.
+    ///
+    /// The playlist's items are first replaced with the first 100 items, and the rest are appended to the playlist 100
+    /// items at a time. See [ReplacePlaylistItemsRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn replace_playlist_items<'a, I, P>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+        items: I,
+    ) -> ReplacePlaylistItemsRequestBuilder<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PlayableItem<'a>>,
+    {
+        ReplacePlaylistItemsRequestBuilder::new(
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/tracks", playlist_id.as_str()),
+            self.clone(),
+            playable_item_uris(items),
+        )
+        .with_either_required_scope(Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate)
+    }
+
+    /// Remove up to 100 playable items from a playlist; tracks or episodes. Every occurrence of each item is removed.
+    /// The request returns the playlist's new snapshot ID.
+    ///
+    /// If more than 100 items are given, the request fails with an
+    /// [Error::InvalidArgument](crate::error::Error::InvalidArgument) without being sent.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn remove_items_from_playlist<'a, I, P>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+        items: I,
+    ) -> RemovePlaylistItemsRequestBuilder<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PlayableItem<'a>>,
+    {
+        let body = object::RemovePlaylistItemsBody {
+            tracks: playable_item_uris(items)
+                .into_iter()
+                .map(|uri| object::PlaylistItemUri { uri })
+                .collect(),
+            snapshot_id: None,
+        };
+
+        trace!("Remove playlist items body: {:?}", body);
+        let item_count = body.tracks.len();
+        let builder = RemovePlaylistItemsRequestBuilder::new_with_body(
+            Method::DELETE,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/tracks", playlist_id.as_str()),
+            body,
+            self.clone(),
+        )
+        .with_either_required_scope(Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate);
+
+        check_playlist_item_count(builder, item_count)
+    }
+
+    /// Remove up to 100 items from a playlist by their zero-based positions in the playlist. Unlike
+    /// [remove_items_from_playlist](Self::remove_items_from_playlist), only the items in the given positions are
+    /// removed, even if the same item occurs elsewhere in the playlist. The request returns the playlist's new snapshot
+    /// ID.
+    ///
+    /// The positions refer to the playlist's state at the given snapshot ID, so the playlist's current snapshot ID
+    /// must be given.
+    ///
+    /// If more than 100 positions are given, the request fails with an
+    /// [Error::InvalidArgument](crate::error::Error::InvalidArgument) without being sent.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn remove_playlist_items_by_position<'a, S>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+        positions: &[u32],
+        snapshot_id: S,
+    ) -> RemovePlaylistItemsByPositionRequestBuilder<Self>
+    where
+        S: Into<String>,
+    {
+        let body = object::RemovePlaylistItemsByPositionBody {
+            positions: positions.to_vec(),
+            snapshot_id: snapshot_id.into(),
+        };
+
+        trace!("Remove playlist items by position body: {:?}", body);
+        let item_count = body.positions.len();
+        let builder = RemovePlaylistItemsByPositionRequestBuilder::new_with_body(
+            Method::DELETE,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/tracks", playlist_id.as_str()),
+            body,
+            self.clone(),
+        )
+        .with_either_required_scope(Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate);
+
+        check_playlist_item_count(builder, item_count)
+    }
+
+    /// Move an item or a range of items in a playlist to another position. The item at the zero-based `range_start`
+    /// position is moved before the item at the `insert_before` position. The request returns the playlist's new
+    /// snapshot ID.
+    ///
+    /// The amount of consecutive items to move may be set with the
+    /// [`range_length`](ReorderPlaylistItemsRequestBuilder::range_length) function in the returned builder.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn reorder_playlist_items<'a>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+        range_start: u32,
+        insert_before: u32,
+    ) -> ReorderPlaylistItemsRequestBuilder<Self> {
+        let body = object::ReorderPlaylistItemsBody {
+            range_start,
+            insert_before,
+            range_length: None,
+            snapshot_id: None,
+        };
+
+        trace!("Reorder playlist items body: {:?}", body);
+        ReorderPlaylistItemsRequestBuilder::new_with_body(
+            Method::PUT,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/tracks", playlist_id.as_str()),
+            body,
+            self.clone(),
+        )
+        .with_either_required_scope(Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate)
+    }
+
+    /// Replace a playlist's cover image with the given JPEG image. The image is base64-encoded before it is sent.
+    ///
+    /// Spotify accepts at most 256 KB of base64-encoded image data. If the encoded image is larger than that, the
+    /// request fails with an [Error::InvalidArgument](crate::error::Error::InvalidArgument) without being sent.
+    ///
+    /// Required scope: [UgcImageUpload](crate::scope::Scope::UgcImageUpload). In addition,
+    /// [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) is required for a public playlist, or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for a private playlist.
+    fn upload_playlist_cover(&self, playlist_id: Id<'_, PlaylistId>, jpeg_bytes: &[u8]) -> RequestBuilder<Self, ()> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(jpeg_bytes);
+        let encoded_size = encoded.len();
+
+        let builder = RequestBuilder::new(
+            Method::PUT,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/images", playlist_id.as_str()),
+            self.clone(),
+        )
+        .with_raw_body("image/jpeg", encoded.into_bytes())
+        .with_required_scope(Scope::UgcImageUpload);
+
+        if encoded_size > object::MAX_PLAYLIST_COVER_IMAGE_SIZE {
+            builder.with_invalid_argument(format!(
+                "base64-encoded cover image of {encoded_size} bytes is larger than the maximum of {} bytes",
+                object::MAX_PLAYLIST_COVER_IMAGE_SIZE
+            ))
+        } else {
+            builder
+        }
+    }
+
+    /// Get the tracks saved in the user's library, most recently saved first. The page's size and offset may be set with
+    /// the [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn saved_tracks(&self) -> PagedRequestBuilder<Self, SavedTracks, Page<SavedTracks, SavedTrack>> {
+        PagedRequestBuilder::new(Method::GET, API_SAVED_TRACKS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save any amount of tracks in the user's library. The IDs are split into chunks of 50 IDs, and each chunk is saved
+    /// in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_tracks<'a, I>(&'a self, tracks: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::PUT,
+            API_SAVED_TRACKS_ENDPOINT,
+            tracks,
+            object::MAX_TRACKS_PER_REQUEST,
+            Scope::UserLibraryModify,
+        )
+    }
+
+    /// Remove any amount of tracks from the user's library. The IDs are split into chunks of 50 IDs, and each chunk is
+    /// removed in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_tracks<'a, I>(&'a self, tracks: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::DELETE,
+            API_SAVED_TRACKS_ENDPOINT,
+            tracks,
+            object::MAX_TRACKS_PER_REQUEST,
+            Scope::UserLibraryModify,
+        )
+    }
+
+    /// Check whether any amount of tracks are saved in the user's library. The returned list contains a boolean for
+    /// each ID, in the same order as the given IDs. The IDs are split into chunks of 50 IDs, and each chunk is checked
+    /// in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn are_tracks_saved<'a, I>(&'a self, tracks: I) -> ChunkedRequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::GET,
+            API_SAVED_TRACKS_CONTAINS_ENDPOINT,
+            tracks,
+            object::MAX_TRACKS_PER_REQUEST,
+            Scope::UserLibraryRead,
+        )
+    }
+
+    /// Get the albums saved in the user's library, most recently saved first. The page's size and offset may be set with
+    /// the [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn saved_albums(&self) -> PagedRequestBuilder<Self, SavedAlbums, Page<SavedAlbums, SavedAlbum>> {
+        PagedRequestBuilder::new(Method::GET, API_SAVED_ALBUMS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save any amount of albums in the user's library. The IDs are split into chunks of 20 IDs, and each chunk is saved
+    /// in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_albums<'a, I>(&'a self, albums: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::PUT,
+            API_SAVED_ALBUMS_ENDPOINT,
+            albums,
+            object::MAX_ALBUMS_PER_REQUEST,
+            Scope::UserLibraryModify,
+        )
+    }
+
+    /// Remove any amount of albums from the user's library. The IDs are split into chunks of 20 IDs, and each chunk is
+    /// removed in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_albums<'a, I>(&'a self, albums: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::DELETE,
+            API_SAVED_ALBUMS_ENDPOINT,
+            albums,
+            object::MAX_ALBUMS_PER_REQUEST,
+            Scope::UserLibraryModify,
+        )
+    }
+
+    /// Check whether any amount of albums are saved in the user's library. The returned list contains a boolean for
+    /// each ID, in the same order as the given IDs. The IDs are split into chunks of 20 IDs, and each chunk is checked
+    /// in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn are_albums_saved<'a, I>(&'a self, albums: I) -> ChunkedRequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::GET,
+            API_SAVED_ALBUMS_CONTAINS_ENDPOINT,
+            albums,
+            object::MAX_ALBUMS_PER_REQUEST,
+            Scope::UserLibraryRead,
+        )
+    }
+
+    /// Get the shows saved in the user's library, most recently saved first. The page's size and offset may be set with
+    /// the [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn saved_shows(&self) -> PagedRequestBuilder<Self, SavedShows, Page<SavedShows, SavedShow>> {
+        PagedRequestBuilder::new(Method::GET, API_SAVED_SHOWS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save any amount of shows in the user's library. The IDs are split into chunks of 50 IDs, and each chunk is saved
+    /// in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_shows<'a, I>(&'a self, shows: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::PUT,
+            API_SAVED_SHOWS_ENDPOINT,
+            shows,
+            object::MAX_SHOWS_PER_REQUEST,
+            Scope::UserLibraryModify,
+        )
+    }
+
+    /// Remove any amount of shows from the user's library. The IDs are split into chunks of 50 IDs, and each chunk is
+    /// removed in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_shows<'a, I>(&'a self, shows: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::DELETE,
+            API_SAVED_SHOWS_ENDPOINT,
+            shows,
+            object::MAX_SHOWS_PER_REQUEST,
+            Scope::UserLibraryModify,
+        )
+    }
+
+    /// Check whether any amount of shows are saved in the user's library. The returned list contains a boolean for
+    /// each ID, in the same order as the given IDs. The IDs are split into chunks of 50 IDs, and each chunk is checked
+    /// in its own request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn are_shows_saved<'a, I>(&'a self, shows: I) -> ChunkedRequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::GET,
+            API_SAVED_SHOWS_CONTAINS_ENDPOINT,
+            shows,
+            object::MAX_SHOWS_PER_REQUEST,
+            Scope::UserLibraryRead,
+        )
+    }
+
+    /// Get the artists the user follows. The page's size may be set with the
+    /// [`limit`](FollowedArtistsRequestBuilder::limit) function in the returned builder. The pages use cursors instead
+    /// of offsets; use [`after`](FollowedArtistsRequestBuilder::after) to request the page after a given artist.
+    ///
+    /// Required scope: [UserFollowRead](crate::scope::Scope::UserFollowRead).
+    fn followed_artists(&self) -> FollowedArtistsRequestBuilder<Self> {
+        FollowedArtistsRequestBuilder::new(Method::GET, API_FOLLOWING_ENDPOINT, self.clone())
+            .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_ARTIST)
+            .with_required_scope(Scope::UserFollowRead)
+    }
+
+    /// Follow any amount of artists. The IDs are split into chunks of 50 IDs, and each chunk is followed in its own
+    /// request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn follow_artists<'a, I>(&'a self, artists: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::PUT,
+            API_FOLLOWING_ENDPOINT,
+            artists,
+            object::MAX_FOLLOWS_PER_REQUEST,
+            Scope::UserFollowModify,
+        )
+        .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_ARTIST)
+    }
+
+    /// Unfollow any amount of artists. The IDs are split into chunks of 50 IDs, and each chunk is unfollowed in its own
+    /// request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn unfollow_artists<'a, I>(&'a self, artists: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::DELETE,
+            API_FOLLOWING_ENDPOINT,
+            artists,
+            object::MAX_FOLLOWS_PER_REQUEST,
+            Scope::UserFollowModify,
+        )
+        .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_ARTIST)
+    }
+
+    /// Check whether the user follows any amount of artists. The returned list contains a boolean for each ID, in the
+    /// same order as the given IDs. The IDs are split into chunks of 50 IDs, and each chunk is checked in its own
+    /// request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserFollowRead](crate::scope::Scope::UserFollowRead).
+    fn is_following_artists<'a, I>(&'a self, artists: I) -> ChunkedRequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::GET,
+            API_FOLLOWING_CONTAINS_ENDPOINT,
+            artists,
+            object::MAX_FOLLOWS_PER_REQUEST,
+            Scope::UserFollowRead,
+        )
+        .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_ARTIST)
+    }
+
+    /// Follow any amount of users. The IDs are split into chunks of 50 IDs, and each chunk is followed in its own
+    /// request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn follow_users<'a, I>(&'a self, users: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, UserId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::PUT,
+            API_FOLLOWING_ENDPOINT,
+            users,
+            object::MAX_FOLLOWS_PER_REQUEST,
+            Scope::UserFollowModify,
+        )
+        .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_USER)
+    }
+
+    /// Unfollow any amount of users. The IDs are split into chunks of 50 IDs, and each chunk is unfollowed in its own
+    /// request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn unfollow_users<'a, I>(&'a self, users: I) -> ChunkedRequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, UserId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::DELETE,
+            API_FOLLOWING_ENDPOINT,
+            users,
+            object::MAX_FOLLOWS_PER_REQUEST,
+            Scope::UserFollowModify,
+        )
+        .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_USER)
+    }
+
+    /// Check whether the user follows any amount of users. The returned list contains a boolean for each ID, in the
+    /// same order as the given IDs. The IDs are split into chunks of 50 IDs, and each chunk is checked in its own
+    /// request. See [ChunkedRequestBuilder] for how the requests are sent.
+    ///
+    /// Required scope: [UserFollowRead](crate::scope::Scope::UserFollowRead).
+    fn is_following_users<'a, I>(&'a self, users: I) -> ChunkedRequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, UserId>>,
+    {
+        chunked_ids_request(
+            self,
+            Method::GET,
+            API_FOLLOWING_CONTAINS_ENDPOINT,
+            users,
+            object::MAX_FOLLOWS_PER_REQUEST,
+            Scope::UserFollowRead,
+        )
+        .append_query(object::FOLLOW_TYPE_QUERY, object::FOLLOW_TYPE_USER)
+    }
+}
+
+/// Returns the URIs of the given playable items.
+fn playable_item_uris<'a, I, P>(items: I) -> Vec<String>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<PlayableItem<'a>>,
+{
+    items.into_iter().map(|item| item.into().as_uri().to_string()).collect()
+}
+
+/// Fails the given playlist items request before it is sent if it has more items than fit in a single request.
+fn check_playlist_item_count<C, B>(
+    builder: BasePlaylistItemsRequestBuilder<C, B>,
+    item_count: usize,
+) -> BasePlaylistItemsRequestBuilder<C, B> {
+    if item_count > object::MAX_PLAYLIST_ITEMS_PER_REQUEST {
+        builder.with_invalid_argument(format!(
+            "{item_count} items is more than the maximum of {} items per request",
+            object::MAX_PLAYLIST_ITEMS_PER_REQUEST
+        ))
+    } else {
+        builder
+    }
+}
+
+/// Builds a chunked request for an endpoint that takes a list of IDs, such as the endpoints for the user's library. The
+/// IDs are split into chunks of the given size.
+fn chunked_ids_request<'a, C, R, T, I>(
+    client: &C,
+    method: Method,
+    endpoint: &'static str,
+    ids: I,
+    chunk_size: usize,
+    scope: Scope,
+) -> ChunkedRequestBuilder<C, R>
+where
+    C: Clone,
+    T: ItemTypeId + 'static,
+    I: IntoIterator<Item = Id<'a, T>>,
+{
+    ChunkedRequestBuilder::<C, R>::new(
+        method,
+        endpoint,
+        client.clone(),
+        object::IDS_QUERY,
+        ids.into_iter().map(|id| id.as_str().to_owned()),
+        chunk_size,
+    )
+    .with_required_scope(scope)
 }
 
 #[cfg(feature = "async")]
@@ -401,7 +1137,7 @@ fn handle_player_control_response_async(
                 warn!("Got 404 Not Found to play call");
                 let error_response: ApiErrorResponse = response.json().await?;
 
-                match error_response.error.message {
+                match error_response.error.message_kind() {
                     ApiErrorMessage::NoActiveDevice | ApiErrorMessage::NotFound => {
                         warn!("Player call failed: no active device or playback failed on active device");
                         Err(Error::NoActiveDevice)
@@ -409,17 +1145,14 @@ fn handle_player_control_response_async(
 
                     other => {
                         error!("Unexpected Spotify error response to player call: {:?}", other);
-                        Err(Error::UnhandledSpotifyResponseStatusCode(404))
+                        Err(Error::Api(error_response.error))
                     }
                 }
             }
 
             other => {
                 warn!("Got unexpected response status to player call: {}", other);
-                let body = response.text().await?;
-                warn!("Response body: {body}");
-
-                Err(Error::UnhandledSpotifyResponseStatusCode(other.as_u16()))
+                Err(api_error_from_response_async(response).await)
             }
         }
     })
@@ -434,25 +1167,512 @@ fn handle_player_control_response_sync(response: reqwest::blocking::Response) ->
             warn!("Got 404 Not Found to play call");
             let error_response: ApiErrorResponse = response.json()?;
 
-            match error_response.error.message {
-                ApiErrorMessage::NoActiveDevice => {
-                    warn!("Player call failed: no active device");
+            match error_response.error.message_kind() {
+                ApiErrorMessage::NoActiveDevice | ApiErrorMessage::NotFound => {
+                    warn!("Player call failed: no active device or playback failed on active device");
                     Err(Error::NoActiveDevice)
                 }
 
                 other => {
                     error!("Unexpected Spotify error response to player call: {:?}", other);
-                    Err(Error::UnhandledSpotifyResponseStatusCode(404))
+                    Err(Error::Api(error_response.error))
                 }
             }
         }
 
         other => {
             warn!("Got unexpected response status to player call: {}", other);
-            let body = response.text()?;
-            warn!("Response body: {body}");
-
-            Err(Error::UnhandledSpotifyResponseStatusCode(other.as_u16()))
+            Err(api_error_from_response_sync(response))
         }
     }
 }
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use reqwest::{Method, Url};
+    use serde_json::json;
+
+    use super::ScopedClient;
+    use crate::{
+        client::{
+            object,
+            private::ApiBaseUrl,
+            request_builder::{AsyncRequestBuilder, BaseRequestBuilderContainer, PlayerControlRequestBuilder},
+            test_util::{json_response, serve_requests, user_client, user_client_from},
+            SpotifyClientBuilder,
+        },
+        error::Error,
+        model::{
+            id::{AlbumId, EpisodeId, Id, IdFromBare, IdTrait, PlayableItem, PlaylistId, ShowId, TrackId, UserId},
+            playback::RepeatState,
+        },
+    };
+
+    fn query<C: ApiBaseUrl>(builder: PlayerControlRequestBuilder<C>, key: &str) -> Option<String> {
+        let url = builder.take_base_builder().build_url();
+        url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned())
+    }
+
+    #[test]
+    fn volume_query() {
+        let builder = user_client().set_volume(42);
+        assert_eq!(query(builder, object::VOLUME_PERCENT_QUERY).as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn shuffle_query() {
+        assert_eq!(
+            query(user_client().set_shuffle(true), object::SHUFFLE_QUERY).as_deref(),
+            Some("true")
+        );
+        assert_eq!(
+            query(user_client().set_shuffle(false), object::SHUFFLE_QUERY).as_deref(),
+            Some("false")
+        );
+    }
+
+    #[test]
+    fn repeat_query() {
+        assert_eq!(
+            query(user_client().set_repeat(RepeatState::Track), object::REPEAT_STATE_QUERY).as_deref(),
+            Some("track")
+        );
+        assert_eq!(
+            query(user_client().set_repeat(RepeatState::Off), object::REPEAT_STATE_QUERY).as_deref(),
+            Some("off")
+        );
+    }
+
+    #[tokio::test]
+    async fn nothing_is_now_playing() {
+        let (url, server) = serve_requests(1, |_| {
+            "HTTP/1.1 204 No Content\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_owned()
+        });
+
+        let now_playing =
+            user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(Url::parse(&url).unwrap()))
+                .now_playing()
+                .send_async()
+                .await
+                .unwrap();
+        assert_eq!(now_playing, None);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].path, "/me/player");
+    }
+
+    #[tokio::test]
+    async fn unknown_repeat_state_cannot_be_set() {
+        let result = user_client().set_repeat(RepeatState::Unknown).send_async().await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn transfer_playback_body() {
+        let builder = user_client().transfer_playback("0123456789abcdef0123456789abcdef01234567", true);
+        let body = serde_json::to_value(builder.take_base_builder().body()).unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "device_ids": ["0123456789abcdef0123456789abcdef01234567"],
+                "play": true
+            })
+        );
+    }
+
+    #[test]
+    fn add_to_queue_query() {
+        let client = user_client();
+        let track = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let builder = client
+            .add_to_queue(PlayableItem::Track(track))
+            .device_id("0123456789abcdef0123456789abcdef01234567");
+
+        let base_builder = builder.take_base_builder();
+        assert_eq!(base_builder.method(), Method::POST);
+
+        let url = base_builder.build_url();
+        let query = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+
+        assert!(url.path().ends_with("/me/player/queue"));
+        assert_eq!(query[object::QUEUE_URI_QUERY], "spotify:track:2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(
+            query[object::DEVICE_ID_QUERY],
+            "0123456789abcdef0123456789abcdef01234567"
+        );
+    }
+
+    #[tokio::test]
+    async fn volume_out_of_range() {
+        // the request fails before being sent, so the dummy access token never reaches Spotify
+        let result = user_client().set_volume(101).send_async().await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn library_requests_are_chunked() {
+        let albums: Vec<_> = (0..25).map(|i| format!("{i:0>22}")).collect();
+        let chunks = user_client()
+            .are_albums_saved(albums.iter().map(|id| Id::<AlbumId>::from_bare(id).unwrap()))
+            .take_chunks();
+
+        let urls: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| chunk.take_base_builder().build_url())
+            .collect();
+        let ids: Vec<_> = urls
+            .iter()
+            .map(|url| {
+                url.query_pairs()
+                    .find(|(k, _)| k == object::IDS_QUERY)
+                    .unwrap()
+                    .1
+                    .into_owned()
+            })
+            .collect();
+
+        assert!(urls.iter().all(|url| url.path() == "/v1/me/albums/contains"));
+        assert_eq!(ids, [albums[..20].join(","), albums[20..].join(",")]);
+
+        let shows = (0..60).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+        let chunks = user_client()
+            .save_shows(shows.iter().map(|id| Id::<ShowId>::from_bare(id).unwrap()))
+            .take_chunks();
+        let sizes: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| {
+                let url = chunk.take_base_builder().build_url();
+                assert_eq!(url.path(), "/v1/me/shows");
+                url.query_pairs()
+                    .find(|(k, _)| k == object::IDS_QUERY)
+                    .unwrap()
+                    .1
+                    .split(',')
+                    .count()
+            })
+            .collect();
+
+        assert_eq!(sizes, [50, 10]);
+    }
+
+    #[test]
+    fn follow_requests() {
+        let users = ["spotify", "smedjan"].map(|id| Id::<UserId>::from_bare(id).unwrap());
+        let mut chunks = user_client().unfollow_users(users).take_chunks();
+        let builder = chunks.remove(0).take_base_builder();
+        let url = builder.build_url();
+
+        assert!(chunks.is_empty());
+        assert_eq!(builder.method(), Method::DELETE);
+        assert_eq!(url.path(), "/v1/me/following");
+        assert_eq!(
+            url.query_pairs().collect::<HashMap<_, _>>(),
+            HashMap::from([("ids".into(), "spotify,smedjan".into()), ("type".into(), "user".into())])
+        );
+
+        let url = user_client().followed_artists().take_base_builder().build_url();
+        assert_eq!(url.query(), Some("type=artist"));
+    }
+
+    fn body<C, R, B, T>(builder: impl BaseRequestBuilderContainer<C, R, B, T>) -> serde_json::Value
+    where
+        B: serde::Serialize,
+    {
+        serde_json::to_value(builder.take_base_builder().body()).unwrap()
+    }
+
+    #[test]
+    fn create_playlist_body() {
+        let user = Id::<UserId>::from_bare("smedjan").unwrap();
+        let builder = user_client()
+            .create_playlist(user.as_borrowed(), "Vocaloid")
+            .public(false)
+            .collaborative(true)
+            .description("Songs by virtual singers");
+
+        assert_eq!(
+            body(builder),
+            json!({
+                "name": "Vocaloid",
+                "public": false,
+                "collaborative": true,
+                "description": "Songs by virtual singers"
+            })
+        );
+
+        let builder = user_client().create_playlist(user, "Defaults");
+        assert_eq!(body(builder), json!({ "name": "Defaults" }));
+    }
+
+    #[test]
+    fn change_playlist_details_body() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        let builder = user_client()
+            .change_playlist_details(playlist.as_borrowed())
+            .description("Songs by virtual singers");
+        let base_builder = builder.take_base_builder();
+
+        assert_eq!(base_builder.method(), Method::PUT);
+        assert!(base_builder
+            .build_url()
+            .path()
+            .ends_with("/playlists/3cEYpjA9oz9GiPac4AsH4n"));
+        assert_eq!(
+            serde_json::to_value(base_builder.body()).unwrap(),
+            json!({ "description": "Songs by virtual singers" })
+        );
+
+        let builder = user_client()
+            .change_playlist_details(playlist)
+            .name("Vocaloid")
+            .public(false)
+            .collaborative(true);
+        assert_eq!(
+            body(builder),
+            json!({ "name": "Vocaloid", "public": false, "collaborative": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn created_collaborative_playlist_cannot_be_public() {
+        let user = Id::<UserId>::from_bare("smedjan").unwrap();
+
+        // playlists are created public by default
+        let result = user_client()
+            .create_playlist(user.as_borrowed(), "Vocaloid")
+            .collaborative(true)
+            .send_async()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let result = user_client()
+            .create_playlist(user.as_borrowed(), "Vocaloid")
+            .public(false)
+            .collaborative(true)
+            .public(true)
+            .send_async()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // a later change may resolve the conflict
+        let (url, server) = serve_requests(1, |_| {
+            let playlist = json!({
+                "collaborative": true,
+                "description": null,
+                "external_urls": {},
+                "followers": { "href": null, "total": 0 },
+                "href": "https://api.spotify.com/v1/playlists/7d2D2S200NyUE5KYs80PwO",
+                "id": "7d2D2S200NyUE5KYs80PwO",
+                "images": [],
+                "name": "Vocaloid",
+                "owner": {
+                    "external_urls": {},
+                    "href": "https://api.spotify.com/v1/users/smedjan",
+                    "id": "smedjan",
+                    "type": "user",
+                    "uri": "spotify:user:smedjan"
+                },
+                "public": false,
+                "snapshot_id": "MSw1ZmE5MDRjZmQ3NDQ5NDg2ZDE2NDQ4YTljZDEwOTkyYmYwZTU3MjRj",
+                "tracks": {
+                    "href": "https://api.spotify.com/v1/playlists/7d2D2S200NyUE5KYs80PwO/tracks",
+                    "items": [],
+                    "limit": 100,
+                    "next": null,
+                    "offset": 0,
+                    "previous": null,
+                    "total": 0
+                },
+                "type": "playlist",
+                "uri": "spotify:playlist:7d2D2S200NyUE5KYs80PwO"
+            });
+
+            json_response("201 Created", &playlist.to_string())
+        });
+
+        user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(Url::parse(&url).unwrap()))
+            .create_playlist(user, "Vocaloid")
+            .collaborative(true)
+            .public(false)
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+            json!({ "name": "Vocaloid", "public": false, "collaborative": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn collaborative_playlist_cannot_be_public() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        let result = user_client()
+            .change_playlist_details(playlist.as_borrowed())
+            .collaborative(true)
+            .public(true)
+            .send_async()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        // a later change may resolve the conflict
+        let (url, server) = serve_requests(1, |_| {
+            "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_owned()
+        });
+
+        user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(Url::parse(&url).unwrap()))
+            .change_playlist_details(playlist)
+            .public(true)
+            .collaborative(true)
+            .public(false)
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+            json!({ "public": false, "collaborative": true })
+        );
+    }
+
+    #[test]
+    fn playlist_items_bodies() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+        let track = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let episode = Id::<EpisodeId>::from_bare("512ojhOuo1ktJprKbVcKyQ").unwrap();
+
+        let builder = user_client().add_items_to_playlist(
+            playlist.as_borrowed(),
+            [PlayableItem::from(track.as_borrowed()), episode.into()],
+        );
+        assert_eq!(
+            body(builder),
+            json!({ "uris": ["spotify:track:2pDPOMX0kWA7kcPBcDCQBu", "spotify:episode:512ojhOuo1ktJprKbVcKyQ"] })
+        );
+
+        let builder = user_client()
+            .remove_items_from_playlist(playlist.as_borrowed(), [track])
+            .snapshot_id("JbtmHBDBAYu3");
+        assert_eq!(
+            body(builder),
+            json!({
+                "tracks": [{ "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu" }],
+                "snapshot_id": "JbtmHBDBAYu3"
+            })
+        );
+
+        let builder = user_client().reorder_playlist_items(playlist, 1, 5).range_length(2);
+        assert_eq!(
+            body(builder),
+            json!({ "range_start": 1, "insert_before": 5, "range_length": 2 })
+        );
+    }
+
+    #[test]
+    fn remove_playlist_items_by_position_body() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+        let builder = user_client().remove_playlist_items_by_position(playlist, &[3, 7], "JbtmHBDBAYu3");
+        let builder = builder.take_base_builder();
+
+        assert_eq!(builder.method(), Method::DELETE);
+        assert!(builder
+            .build_url()
+            .path()
+            .ends_with("/playlists/3cEYpjA9oz9GiPac4AsH4n/tracks"));
+        assert_eq!(
+            serde_json::to_value(builder.body()).unwrap(),
+            json!({ "positions": [3, 7], "snapshot_id": "JbtmHBDBAYu3" })
+        );
+    }
+
+    #[tokio::test]
+    async fn too_many_playlist_positions() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+        let positions: Vec<_> = (0..101).collect();
+
+        let result = user_client()
+            .remove_playlist_items_by_position(playlist, &positions, "JbtmHBDBAYu3")
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn replacing_with_no_items_clears_playlist() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+        let mut requests = user_client()
+            .replace_playlist_items(playlist, Vec::<PlayableItem>::new())
+            .take_requests();
+        let builder = requests.remove(0).take_base_builder();
+
+        assert!(requests.is_empty());
+        assert_eq!(builder.method(), Method::PUT);
+        assert!(builder
+            .build_url()
+            .path()
+            .ends_with("/playlists/3cEYpjA9oz9GiPac4AsH4n/tracks"));
+        assert_eq!(serde_json::to_value(builder.body()).unwrap(), json!({ "uris": [] }));
+    }
+
+    #[tokio::test]
+    async fn too_many_playlist_items() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+        let track = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+
+        let result = user_client()
+            .add_items_to_playlist(playlist, vec![track; 101])
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn playlist_cover_is_sent_base64_encoded() {
+        let (url, server) = serve_requests(1, |_| {
+            "HTTP/1.1 202 Accepted\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_owned()
+        });
+
+        let client = user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(Url::parse(&url).unwrap()));
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        client
+            .upload_playlist_cover(playlist, &[0xff, 0xd8, 0xff, 0xe0])
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].method, "PUT");
+        assert_eq!(requests[0].path, "/playlists/3cEYpjA9oz9GiPac4AsH4n/images");
+        assert_eq!(requests[0].header("content-type").as_deref(), Some("image/jpeg"));
+        assert_eq!(requests[0].body, "/9j/4A==");
+    }
+
+    #[tokio::test]
+    async fn playlist_cover_too_large() {
+        let playlist = Id::<PlaylistId>::from_bare("3cEYpjA9oz9GiPac4AsH4n").unwrap();
+
+        // 192 KiB of image data is exactly 256 KiB once encoded
+        let largest = user_client().upload_playlist_cover(playlist.as_borrowed(), &[0; 192 * 1024]);
+        assert_eq!(
+            largest.take_base_builder().raw_body().unwrap().1.len(),
+            object::MAX_PLAYLIST_COVER_IMAGE_SIZE
+        );
+
+        let result = user_client()
+            .upload_playlist_cover(playlist, &[0; 192 * 1024 + 1])
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+}
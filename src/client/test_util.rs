@@ -0,0 +1,224 @@
+//! Shared helpers for tests that send requests to a local mock server.
+
+// most of the tests that use these helpers are asynchronous
+#![cfg_attr(not(feature = "async"), allow(dead_code))]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Mutex, Once},
+    thread,
+};
+
+#[cfg(feature = "sync")]
+use super::implicit_grant::SyncImplicitGrantUserClient;
+use super::SpotifyClientBuilder;
+#[cfg(feature = "async")]
+use super::{implicit_grant::AsyncImplicitGrantUserClient, AsyncSpotifyClient};
+
+/// The ID of the track in [TRACK_JSON].
+pub(crate) const TRACK_ID: &str = "2pDPOMX0kWA7kcPBcDCQBu";
+/// A track object as returned by the track endpoint, trimmed down to the fields the model contains.
+pub(crate) const TRACK_JSON: &str = r#"{
+    "album": {
+        "album_type": "single",
+        "artists": [{ "external_urls": {}, "id": "6pNgnvzBa6Bthsv8SrZJYl", "name": "Hatsune Miku", "type": "artist" }],
+        "external_urls": {},
+        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+        "images": [],
+        "name": "World is Mine",
+        "release_date": "2008-05-31",
+        "release_date_precision": "day",
+        "total_tracks": 1,
+        "type": "album"
+    },
+    "artists": [{ "external_urls": {}, "id": "6pNgnvzBa6Bthsv8SrZJYl", "name": "Hatsune Miku", "type": "artist" }],
+    "disc_number": 1,
+    "duration_ms": 254000,
+    "explicit": false,
+    "external_ids": {},
+    "external_urls": {},
+    "id": "2pDPOMX0kWA7kcPBcDCQBu",
+    "is_local": false,
+    "name": "World is Mine",
+    "popularity": 60,
+    "preview_url": null,
+    "track_number": 1,
+    "type": "track"
+}"#;
+
+/// A request received by the mock server.
+#[derive(Debug)]
+pub(crate) struct CapturedRequest {
+    pub(crate) method: String,
+    /// The request's path, including the query.
+    pub(crate) path: String,
+    /// The request's headers, with lowercase names.
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: String,
+}
+
+impl CapturedRequest {
+    /// Returns the value of a query parameter in the request's path.
+    pub(crate) fn query(&self, key: &str) -> Option<String> {
+        reqwest::Url::parse(&format!("http://localhost{}", self.path))
+            .unwrap()
+            .query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    }
+
+    /// Returns the value of a header in the request.
+    pub(crate) fn header(&self, name: &str) -> Option<String> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    }
+}
+
+/// Serves the given amount of requests, responding to each with the raw HTTP response returned by `respond`, and then
+/// stops. Returns the server's URL and a handle that returns the received requests.
+pub(crate) fn serve_requests<F>(requests: usize, mut respond: F) -> (String, thread::JoinHandle<Vec<CapturedRequest>>)
+where
+    F: FnMut(&CapturedRequest) -> String + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let mut captured = Vec::new();
+
+        for _ in 0..requests {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut parts = request_line.split(' ');
+            let method = parts.next().unwrap().to_owned();
+            let path = parts.next().unwrap().to_owned();
+
+            let mut headers = Vec::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if let Some((name, value)) = line.split_once(':') {
+                    headers.push((name.to_ascii_lowercase(), value.trim().to_owned()));
+                }
+
+                line.clear();
+            }
+
+            let content_length = headers
+                .iter()
+                .find(|(name, _)| name == "content-length")
+                .map_or(0, |(_, value)| value.parse().unwrap());
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let request = CapturedRequest {
+                method,
+                path,
+                headers,
+                body: String::from_utf8(body).unwrap(),
+            };
+
+            stream.write_all(respond(&request).as_bytes()).unwrap();
+            captured.push(request);
+        }
+
+        captured
+    });
+
+    (url, handle)
+}
+
+/// Serves the given raw HTTP responses in order, one response per request, and then stops. Returns the server's URL.
+pub(crate) fn serve_responses(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<CapturedRequest>>) {
+    let mut responses = responses.into_iter();
+    serve_requests(responses.len(), move |_| responses.next().unwrap().to_owned())
+}
+
+/// Captures every log record into a list, formatted as `level target: message`.
+struct CapturingLogger;
+
+static CAPTURED_LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        CAPTURED_LOGS
+            .lock()
+            .unwrap()
+            .push(format!("{} {}: {}", record.level(), record.target(), record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Starts capturing every log record at every level for the rest of the test run. Since the logger is global, the
+/// captured records include the ones from tests running at the same time.
+pub(crate) fn capture_logs() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        log::set_logger(&CapturingLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+}
+
+/// Returns the log records captured since [capture_logs] was first called.
+pub(crate) fn captured_logs() -> Vec<String> {
+    CAPTURED_LOGS.lock().unwrap().clone()
+}
+
+/// Returns a raw HTTP response with a JSON body.
+pub(crate) fn json_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Returns a finalized user client with a dummy access token.
+#[cfg(feature = "async")]
+pub(crate) fn user_client() -> AsyncImplicitGrantUserClient {
+    user_client_from(SpotifyClientBuilder::new("client ID"))
+}
+
+/// Returns a finalized user client with a dummy access token, built from the given builder.
+#[cfg(feature = "async")]
+pub(crate) fn user_client_from(builder: SpotifyClientBuilder) -> AsyncImplicitGrantUserClient {
+    user_client_with_token(&builder.build_async(), "access token")
+}
+
+/// Returns a finalized user client with the given access token, built from the given client.
+#[cfg(feature = "async")]
+pub(crate) fn user_client_with_token(client: &AsyncSpotifyClient, access_token: &str) -> AsyncImplicitGrantUserClient {
+    let incomplete_client = client.implicit_grant_client("http://localhost/callback").build();
+
+    let authorize_url = reqwest::Url::parse(&incomplete_client.get_authorize_url()).unwrap();
+    let (_, state) = authorize_url.query_pairs().find(|(key, _)| key == "state").unwrap();
+    let state = state.into_owned();
+
+    incomplete_client.finalize(access_token, &state).unwrap()
+}
+
+/// Returns a finalized synchronous user client with a dummy access token, built from the given builder.
+#[cfg(feature = "sync")]
+pub(crate) fn sync_user_client_from(builder: SpotifyClientBuilder) -> SyncImplicitGrantUserClient {
+    let incomplete_client = builder
+        .build_sync()
+        .implicit_grant_client("http://localhost/callback")
+        .build();
+
+    let authorize_url = reqwest::Url::parse(&incomplete_client.get_authorize_url()).unwrap();
+    let (_, state) = authorize_url.query_pairs().find(|(key, _)| key == "state").unwrap();
+    let state = state.into_owned();
+
+    incomplete_client.finalize("access token", &state).unwrap()
+}
@@ -3,20 +3,39 @@ use reqwest::{Method, StatusCode};
 
 use super::{request_builder::RequestBuilder, API_USER_PROFILE_ENDPOINT};
 #[cfg(feature = "async")]
-use crate::client::request_builder::AsyncResponseHandler;
+use crate::client::request_builder::{api_error_from_response_async, AsyncResponseHandler};
 #[cfg(feature = "sync")]
-use crate::client::request_builder::SyncResponseHandler;
+use crate::client::request_builder::{api_error_from_response_sync, SyncResponseHandler};
 use crate::{
     client::{
         object,
-        request_builder::{BaseRequestBuilderContainer, CatalogItemRequestBuilder, SearchBuilder},
-        API_SEARCH_ENDPOINT, API_TRACKS_ENDPOINT,
+        request_builder::{
+            ArtistAlbumsRequestBuilder, BaseRequestBuilderContainer, CatalogItemRequestBuilder,
+            CategoriesRequestBuilder, CategoryPlaylistsRequestBuilder, ChunkedCatalogItemRequestBuilder,
+            ChunkedRequestBuilder, FeaturedPlaylistsRequestBuilder, NewReleasesRequestBuilder, PagedRequestBuilder,
+            RecommendationsBuilder, SearchBuilder,
+        },
+        API_ALBUMS_ENDPOINT, API_ARTISTS_ENDPOINT, API_AUDIOBOOKS_ENDPOINT, API_AUDIO_ANALYSIS_ENDPOINT,
+        API_AUDIO_FEATURES_ENDPOINT, API_CATEGORIES_ENDPOINT, API_CHAPTERS_ENDPOINT, API_EPISODES_ENDPOINT,
+        API_FEATURED_PLAYLISTS_ENDPOINT, API_GENRE_SEEDS_ENDPOINT, API_MARKETS_ENDPOINT, API_NEW_RELEASES_ENDPOINT,
+        API_PLAYLISTS_ENDPOINT, API_RECOMMENDATIONS_ENDPOINT, API_SEARCH_ENDPOINT, API_SHOWS_ENDPOINT,
+        API_TRACKS_ENDPOINT,
     },
     error::Error,
     model::{
-        id::{Id, IdTrait, TrackId, UserId},
-        track::FullTrack,
+        album::{AlbumTracks, FullAlbum},
+        artist::FullArtist,
+        audio_analysis::AudioAnalysis,
+        audio_features::AudioFeatures,
+        audiobook::FullAudiobook,
+        chapter::FullChapter,
+        episode::{FullEpisode, PartialEpisode},
+        id::{AlbumId, ArtistId, AudiobookId, ChapterId, EpisodeId, Id, IdTrait, PlaylistId, ShowId, TrackId, UserId},
+        playlist::{FullPlaylist, PartialPlaylist, PlaylistItemWrapper, PlaylistItems, Playlists},
+        show::{FullShow, PartialShow, ShowEpisodes},
+        track::{FullTrack, PartialTrack},
         user::PublicUser,
+        Market, Page,
     },
 };
 
@@ -51,6 +70,32 @@ where
         builder
     }
 
+    /// Get the untouched JSON object Spotify returns for a single track identified by its unique Spotify ID. This is
+    /// useful for debugging, or for accessing fields the [FullTrack] model doesn't contain. Otherwise the request
+    /// behaves the same as [track](Self::track).
+    ///
+    /// This function is only available with the `raw` feature.
+    #[cfg(feature = "raw")]
+    fn track_raw<'a>(&'a self, track: Id<'a, TrackId>) -> CatalogItemRequestBuilder<Self, serde_json::Value> {
+        let mut builder = CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_TRACKS_ENDPOINT, track.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(track_response_handler_async_fn(track.as_owned()));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(track_response_handler_sync_fn(track.as_owned()));
+        }
+
+        builder
+    }
+
     /// Get Spotify catalog information for multiple tracks based on their Spotify IDs.
     ///
     /// Up to 50 IDs may be given. In case some IDs cannot be found, they will be omitted from the result.
@@ -63,7 +108,7 @@ where
         I: IntoIterator<Item = Id<'a, TrackId>>,
     {
         CatalogItemRequestBuilder::new(Method::GET, API_TRACKS_ENDPOINT, self.clone()).append_query(
-            object::TRACKS_IDS_QUERY,
+            object::IDS_QUERY,
             tracks
                 .into_iter()
                 .map(|id| id.as_str().to_owned())
@@ -72,6 +117,448 @@ where
         )
     }
 
+    /// Get Spotify catalog information for any amount of tracks based on their Spotify IDs.
+    ///
+    /// The IDs are split into chunks of 50 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found, they will be omitted from the result. See
+    /// [ChunkedCatalogItemRequestBuilder] for how the requests are sent.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](ChunkedCatalogItemRequestBuilder::market). Only content that is available in that market will be
+    /// returned and [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    fn tracks_chunked<'a, I>(
+        &'a self,
+        tracks: I,
+    ) -> ChunkedCatalogItemRequestBuilder<Self, object::TracksResponse, Vec<FullTrack>>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        ChunkedCatalogItemRequestBuilder::new(
+            Method::GET,
+            API_TRACKS_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            tracks.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_TRACKS_PER_REQUEST,
+        )
+    }
+
+    /// Get audio feature information for a single track identified by its unique Spotify ID.
+    fn audio_features<'a>(&'a self, track: Id<'a, TrackId>) -> RequestBuilder<Self, AudioFeatures> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_AUDIO_FEATURES_ENDPOINT, track.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(track_response_handler_async_fn(track.as_owned()));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(track_response_handler_sync_fn(track.as_owned()));
+        }
+
+        builder
+    }
+
+    /// Get audio feature information for any amount of tracks based on their Spotify IDs.
+    ///
+    /// The IDs are split into chunks of 100 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found, their corresponding elements in the list are
+    /// `None`. See [ChunkedRequestBuilder] for how the requests are sent.
+    fn audio_features_multiple<'a, I>(
+        &'a self,
+        tracks: I,
+    ) -> ChunkedRequestBuilder<Self, object::AudioFeaturesResponse, Vec<Option<AudioFeatures>>>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        ChunkedRequestBuilder::new(
+            Method::GET,
+            API_AUDIO_FEATURES_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            tracks.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_AUDIO_FEATURES_PER_REQUEST,
+        )
+    }
+
+    /// Get a low-level audio analysis for a track identified by its unique Spotify ID. The analysis describes the
+    /// track's structure and musical content, including rhythm, pitch and timbre.
+    ///
+    /// The analysis can be very large for long tracks.
+    fn audio_analysis<'a>(&'a self, track: Id<'a, TrackId>) -> RequestBuilder<Self, AudioAnalysis> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_AUDIO_ANALYSIS_ENDPOINT, track.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(track_response_handler_async_fn(track.as_owned()));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(track_response_handler_sync_fn(track.as_owned()));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for any amount of albums based on their Spotify IDs.
+    ///
+    /// The IDs are split into chunks of 20 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found, their corresponding elements in the list are
+    /// `None`. See [ChunkedCatalogItemRequestBuilder] for how the requests are sent.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](ChunkedCatalogItemRequestBuilder::market). Only content that is available in that market will be
+    /// returned and [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    fn albums<'a, I>(
+        &'a self,
+        albums: I,
+    ) -> ChunkedCatalogItemRequestBuilder<Self, object::AlbumsResponse, Vec<Option<FullAlbum>>>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        ChunkedCatalogItemRequestBuilder::new(
+            Method::GET,
+            API_ALBUMS_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            albums.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_ALBUMS_PER_REQUEST,
+        )
+    }
+
+    /// Get the tracks of an album. The page's size and offset may be set with the
+    /// [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder, which allows paging through albums with more tracks than a [full album](crate::model::album::FullAlbum)
+    /// contains.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](PagedRequestBuilder::market). Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    fn album_tracks<'a>(
+        &'a self,
+        album: Id<'a, AlbumId>,
+    ) -> PagedRequestBuilder<Self, AlbumTracks, Page<AlbumTracks, PartialTrack>> {
+        PagedRequestBuilder::new(
+            Method::GET,
+            format!("{API_ALBUMS_ENDPOINT}/{}/tracks", album.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get Spotify catalog information for any amount of artists based on their Spotify IDs.
+    ///
+    /// The IDs are split into chunks of 50 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found, they will be omitted from the result. See
+    /// [ChunkedRequestBuilder] for how the requests are sent.
+    fn artists<'a, I>(&'a self, artists: I) -> ChunkedRequestBuilder<Self, object::ArtistsResponse, Vec<FullArtist>>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        ChunkedRequestBuilder::new(
+            Method::GET,
+            API_ARTISTS_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            artists.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_ARTISTS_PER_REQUEST,
+        )
+    }
+
+    /// Get an artist's albums. The albums may be limited to certain [groups](crate::model::album::AlbumGroup) with the
+    /// [`include_groups`](ArtistAlbumsRequestBuilder::include_groups)-function in the returned builder, and each album
+    /// has its group set. The page's size and offset may be set with the [`limit`](PagedRequestBuilder::limit) and
+    /// [`offset`](PagedRequestBuilder::offset) functions.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](PagedRequestBuilder::market). Only content that is available in that market will be returned.
+    fn artist_albums<'a>(&'a self, artist: Id<'a, ArtistId>) -> ArtistAlbumsRequestBuilder<Self> {
+        ArtistAlbumsRequestBuilder::new(
+            Method::GET,
+            format!("{API_ARTISTS_ENDPOINT}/{}/albums", artist.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get Spotify catalog information about artists similar to a given artist. Similarity is based on analysis of the
+    /// Spotify community's listening history.
+    ///
+    /// Up to 20 artists are returned. An artist may not have any related artists, in which case an empty list is
+    /// returned.
+    fn related_artists<'a>(
+        &'a self,
+        artist: Id<'a, ArtistId>,
+    ) -> RequestBuilder<Self, object::ArtistsResponse, (), Vec<FullArtist>> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}/related-artists", API_ARTISTS_ENDPOINT, artist.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(artist_response_handler_async_fn(artist.as_owned()));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(artist_response_handler_sync_fn(artist.as_owned()));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information about an artist's top tracks in a given market.
+    ///
+    /// Unlike most other catalog endpoints, the market is required. Either a [CountryCode](crate::model::CountryCode) or
+    /// a [Market] may be given. Only content that is available in that
+    /// market will be returned and [track relinking](crate::model::track#track-equality-and-track-relinking) may be
+    /// applied.
+    fn artist_top_tracks<'a, M>(
+        &'a self,
+        artist: Id<'a, ArtistId>,
+        market: M,
+    ) -> RequestBuilder<Self, object::TracksResponse, (), Vec<FullTrack>>
+    where
+        M: Into<Market>,
+    {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}/top-tracks", API_ARTISTS_ENDPOINT, artist.as_str()),
+            self.clone(),
+        )
+        .append_query(object::MARKET_QUERY, market.into().as_str());
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(artist_response_handler_async_fn(artist.as_owned()));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(artist_response_handler_sync_fn(artist.as_owned()));
+        }
+
+        builder
+    }
+
+    /// Get track recommendations based on seed artists, tracks and genres.
+    ///
+    /// The seeds are given with the functions in the [RecommendationsBuilder] this function returns. Between one and
+    /// five seeds in total must be given. The recommendations may additionally be tuned with
+    /// [TunableAttributes](crate::model::recommendations::TunableAttribute).
+    fn recommendations(&self) -> RecommendationsBuilder<Self> {
+        RecommendationsBuilder::new(Method::GET, API_RECOMMENDATIONS_ENDPOINT, self.clone())
+    }
+
+    /// Get the list of genres that may be used as seeds for [recommendations](UnscopedClient::recommendations).
+    fn available_genre_seeds(&self) -> RequestBuilder<Self, object::GenresResponse, (), Vec<String>> {
+        RequestBuilder::new(Method::GET, API_GENRE_SEEDS_ENDPOINT, self.clone())
+    }
+
+    /// Get the list of markets where Spotify is available.
+    ///
+    /// If Spotify returns a market code the library doesn't recognise, the response body fails to deserialize and an
+    /// error is returned.
+    fn available_markets(&self) -> RequestBuilder<Self, object::MarketsResponse, (), Vec<Market>> {
+        RequestBuilder::new(Method::GET, API_MARKETS_ENDPOINT, self.clone())
+    }
+
+    /// Verify that Spotify accepts the client's access token by sending a lightweight request. This is useful for
+    /// failing fast before a long batch of requests. The response body is ignored.
+    ///
+    /// If the access token has expired, it is refreshed like for any other request, if possible. If Spotify rejects
+    /// the access token, [Error::Unauthorized] is returned.
+    fn verify_token(&self) -> RequestBuilder<Self, object::IgnoredResponse, (), ()> {
+        RequestBuilder::new(Method::GET, API_MARKETS_ENDPOINT, self.clone())
+    }
+
+    /// Get Spotify catalog information for a single show identified by its unique Spotify ID, including the first page of
+    /// its episodes.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn show<'a>(&'a self, show: Id<'a, ShowId>) -> CatalogItemRequestBuilder<Self, FullShow> {
+        CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{API_SHOWS_ENDPOINT}/{}", show.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get the episodes of a show. The page's size and offset may be set with the
+    /// [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    fn show_episodes<'a>(
+        &'a self,
+        show: Id<'a, ShowId>,
+    ) -> PagedRequestBuilder<Self, ShowEpisodes, Page<ShowEpisodes, PartialEpisode>> {
+        PagedRequestBuilder::new(
+            Method::GET,
+            format!("{API_SHOWS_ENDPOINT}/{}/episodes", show.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get Spotify catalog information for any amount of shows based on their Spotify IDs. The shows don't include
+    /// their episodes.
+    ///
+    /// The IDs are split into chunks of 50 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found, their corresponding elements in the list are
+    /// `None`. See [ChunkedCatalogItemRequestBuilder] for how the requests are sent.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](ChunkedCatalogItemRequestBuilder::market). Only content that is available in that market will be
+    /// returned.
+    fn shows<'a, I>(
+        &'a self,
+        shows: I,
+    ) -> ChunkedCatalogItemRequestBuilder<Self, object::ShowsResponse, Vec<Option<PartialShow>>>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        ChunkedCatalogItemRequestBuilder::new(
+            Method::GET,
+            API_SHOWS_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            shows.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_SHOWS_PER_REQUEST,
+        )
+    }
+
+    /// Get Spotify catalog information for a single episode identified by its unique Spotify ID.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn episode<'a>(&'a self, episode: Id<'a, EpisodeId>) -> CatalogItemRequestBuilder<Self, FullEpisode> {
+        CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{API_EPISODES_ENDPOINT}/{}", episode.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get Spotify catalog information for any amount of episodes based on their Spotify IDs.
+    ///
+    /// The IDs are split into chunks of 50 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found, their corresponding elements in the list are
+    /// `None`. See [ChunkedCatalogItemRequestBuilder] for how the requests are sent.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](ChunkedCatalogItemRequestBuilder::market). Only content that is available in that market will be
+    /// returned.
+    fn episodes<'a, I>(
+        &'a self,
+        episodes: I,
+    ) -> ChunkedCatalogItemRequestBuilder<Self, object::EpisodesResponse, Vec<Option<FullEpisode>>>
+    where
+        I: IntoIterator<Item = Id<'a, EpisodeId>>,
+    {
+        ChunkedCatalogItemRequestBuilder::new(
+            Method::GET,
+            API_EPISODES_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            episodes.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_EPISODES_PER_REQUEST,
+        )
+    }
+
+    /// Get Spotify catalog information for a single audiobook identified by its unique Spotify ID, including the first
+    /// page of its chapters.
+    ///
+    /// Audiobooks are available only in certain markets. An optional market country may be specified with the
+    /// [`market`-function in the request builder this function returns](CatalogItemRequestBuilder::market). If the
+    /// audiobook isn't available in the market, Spotify responds as if it didn't exist.
+    fn audiobook<'a>(&'a self, audiobook: Id<'a, AudiobookId>) -> CatalogItemRequestBuilder<Self, FullAudiobook> {
+        CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{API_AUDIOBOOKS_ENDPOINT}/{}", audiobook.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get Spotify catalog information for any amount of audiobooks based on their Spotify IDs.
+    ///
+    /// The IDs are split into chunks of 50 IDs, and each chunk is retrieved in its own request. The returned list is in
+    /// the same order as the given IDs. In case some IDs cannot be found or the audiobooks aren't available in the
+    /// market, their corresponding elements in the list are `None`. See [ChunkedCatalogItemRequestBuilder] for how the
+    /// requests are sent.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](ChunkedCatalogItemRequestBuilder::market).
+    fn audiobooks<'a, I>(
+        &'a self,
+        audiobooks: I,
+    ) -> ChunkedCatalogItemRequestBuilder<Self, object::AudiobooksResponse, Vec<Option<FullAudiobook>>>
+    where
+        I: IntoIterator<Item = Id<'a, AudiobookId>>,
+    {
+        ChunkedCatalogItemRequestBuilder::new(
+            Method::GET,
+            API_AUDIOBOOKS_ENDPOINT,
+            self.clone(),
+            object::IDS_QUERY,
+            audiobooks.into_iter().map(|id| id.as_str().to_owned()),
+            object::MAX_AUDIOBOOKS_PER_REQUEST,
+        )
+    }
+
+    /// Get Spotify catalog information for a single audiobook chapter identified by its unique Spotify ID.
+    ///
+    /// Like audiobooks, chapters are available only in certain markets. An optional market country may be specified
+    /// with the [`market`-function in the request builder this function returns](CatalogItemRequestBuilder::market).
+    fn chapter<'a>(&'a self, chapter: Id<'a, ChapterId>) -> CatalogItemRequestBuilder<Self, FullChapter> {
+        CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{API_CHAPTERS_ENDPOINT}/{}", chapter.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get a list of new album releases featured in Spotify. The page's size and offset, and the country the releases
+    /// are relevant to, may be set with the functions in the [NewReleasesRequestBuilder] this function returns.
+    fn new_releases(&self) -> NewReleasesRequestBuilder<Self> {
+        NewReleasesRequestBuilder::new(Method::GET, API_NEW_RELEASES_ENDPOINT, self.clone())
+    }
+
+    /// Get a list of playlists featured by Spotify, along with a message describing them. The page's size and offset,
+    /// the country and the user's locale and local time may be set with the functions in the
+    /// [FeaturedPlaylistsRequestBuilder] this function returns.
+    fn featured_playlists(&self) -> FeaturedPlaylistsRequestBuilder<Self> {
+        FeaturedPlaylistsRequestBuilder::new(Method::GET, API_FEATURED_PLAYLISTS_ENDPOINT, self.clone())
+    }
+
+    /// Get a list of categories used to tag items in Spotify. The page's size and offset, the country and the locale the
+    /// categories' names are returned in may be set with the functions in the [CategoriesRequestBuilder] this function
+    /// returns.
+    fn categories(&self) -> CategoriesRequestBuilder<Self> {
+        CategoriesRequestBuilder::new(Method::GET, API_CATEGORIES_ENDPOINT, self.clone())
+    }
+
+    /// Get a list of playlists tagged with a category. The page's size and offset, and the country the playlists are
+    /// relevant to, may be set with the functions in the [CategoryPlaylistsRequestBuilder] this function returns.
+    fn category_playlists<S>(&self, category_id: S) -> CategoryPlaylistsRequestBuilder<Self>
+    where
+        S: AsRef<str>,
+    {
+        CategoryPlaylistsRequestBuilder::new(
+            Method::GET,
+            format!("{API_CATEGORIES_ENDPOINT}/{}/playlists", category_id.as_ref()),
+            self.clone(),
+        )
+    }
+
     /// Get Spotify catalog information about albums, artists, playlists, tracks, shows or episodes that match a keyword
     /// string.
     ///
@@ -92,6 +579,48 @@ where
             self.clone(),
         )
     }
+
+    /// Get the public playlists a user owns or follows. The page's size and offset may be set with the
+    /// [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    fn user_playlists<'a>(
+        &'a self,
+        user_id: Id<'a, UserId>,
+    ) -> PagedRequestBuilder<Self, Playlists, Page<Playlists, PartialPlaylist>> {
+        PagedRequestBuilder::new(
+            Method::GET,
+            format!("{API_USER_PROFILE_ENDPOINT}/{}/playlists", user_id.as_str()),
+            self.clone(),
+        )
+        .with_page_bounds(object::PLAYLISTS_PAGE_BOUNDS)
+    }
+
+    /// Get a playlist owned by a Spotify user, including the first page of its items.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    fn playlist<'a>(&'a self, playlist_id: Id<'a, PlaylistId>) -> CatalogItemRequestBuilder<Self, FullPlaylist> {
+        CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}", playlist_id.as_str()),
+            self.clone(),
+        )
+    }
+
+    /// Get the items in a playlist. The page's size and offset may be set with the
+    /// [`limit`](PagedRequestBuilder::limit) and [`offset`](PagedRequestBuilder::offset) functions in the returned
+    /// builder.
+    fn playlist_items<'a>(
+        &'a self,
+        playlist_id: Id<'a, PlaylistId>,
+    ) -> PagedRequestBuilder<Self, PlaylistItems, Page<PlaylistItems, PlaylistItemWrapper>> {
+        PagedRequestBuilder::new(
+            Method::GET,
+            format!("{API_PLAYLISTS_ENDPOINT}/{}/tracks", playlist_id.as_str()),
+            self.clone(),
+        )
+    }
 }
 
 #[cfg(feature = "async")]
@@ -106,7 +635,7 @@ fn track_response_handler_async_fn(track_id: Id<'static, TrackId>) -> AsyncRespo
                     Err(Error::NonexistentTrack(track_id))
                 }
 
-                other => Err(Error::UnhandledSpotifyResponseStatusCode(other.as_u16())),
+                _ => Err(api_error_from_response_async(response).await),
             }
         })
     })
@@ -122,6 +651,704 @@ fn track_response_handler_sync_fn(track_id: Id<'static, TrackId>) -> SyncRespons
             Err(Error::NonexistentTrack(track_id))
         }
 
-        other => Err(Error::UnhandledSpotifyResponseStatusCode(other.as_u16())),
+        _ => Err(api_error_from_response_sync(response)),
     })
 }
+
+#[cfg(feature = "async")]
+fn artist_response_handler_async_fn(artist_id: Id<'static, ArtistId>) -> AsyncResponseHandler {
+    Box::new(move |response| {
+        Box::pin(async move {
+            match response.status() {
+                StatusCode::OK => Ok(response),
+
+                StatusCode::NOT_FOUND => {
+                    warn!("Got 404 Not Found to artist call");
+                    Err(Error::NonexistentArtist(artist_id))
+                }
+
+                _ => Err(api_error_from_response_async(response).await),
+            }
+        })
+    })
+}
+
+#[cfg(feature = "sync")]
+fn artist_response_handler_sync_fn(artist_id: Id<'static, ArtistId>) -> SyncResponseHandler {
+    Box::new(move |response| match response.status() {
+        StatusCode::OK => Ok(response),
+
+        StatusCode::NOT_FOUND => {
+            warn!("Got 404 Not Found to artist call");
+            Err(Error::NonexistentArtist(artist_id))
+        }
+
+        _ => Err(api_error_from_response_sync(response)),
+    })
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod tests {
+    use super::*;
+    use crate::client::{
+        implicit_grant::SyncImplicitGrantUserClient,
+        request_builder::SyncRequestBuilder,
+        test_util::{json_response, serve_requests, sync_user_client_from, TRACK_ID, TRACK_JSON},
+        SpotifyClientBuilder,
+    };
+    use crate::model::{
+        album::{AlbumGroup, NonLocalAlbumInformation},
+        artist::NonLocalArtistInformation,
+        audiobook::CommonAudiobookInformation,
+        episode::CommonEpisodeInformation,
+        id::IdFromBare,
+        show::CommonShowInformation,
+        track::{CommonTrackInformation, NonLocalTrackInformation},
+        CountryCode,
+    };
+
+    // a track from the album tracks-endpoint, which doesn't include the track's album or popularity
+    const PARTIAL_TRACK_JSON: &str = r#"{
+        "artists": [{
+            "external_urls": { "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl" },
+            "id": "6pNgnvzBa6Bthsv8SrZJYl",
+            "name": "Hatsune Miku",
+            "type": "artist"
+        }],
+        "disc_number": 1,
+        "duration_ms": 254000,
+        "explicit": false,
+        "external_urls": { "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu" },
+        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+        "is_local": false,
+        "name": "World is Mine",
+        "preview_url": null,
+        "track_number": 1,
+        "type": "track"
+    }"#;
+
+    fn mock_client(url: &str) -> SyncImplicitGrantUserClient {
+        sync_user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(url.parse().unwrap()))
+    }
+
+    #[cfg(feature = "raw")]
+    #[test]
+    fn track_raw_sync() {
+        let (url, server) = serve_requests(1, |_| json_response("200 OK", TRACK_JSON));
+
+        let track = mock_client(&url)
+            .track_raw(Id::from_bare(TRACK_ID).unwrap())
+            .market(CountryCode::JP)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].path.starts_with(&format!("/tracks/{TRACK_ID}")));
+        assert_eq!(requests[0].query("market").as_deref(), Some("JP"));
+        assert_eq!(track, serde_json::from_str::<serde_json::Value>(TRACK_JSON).unwrap());
+    }
+
+    #[test]
+    fn track_sync() {
+        let (url, server) = serve_requests(1, |_| json_response("200 OK", TRACK_JSON));
+
+        let track = mock_client(&url)
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .market(Market::FromToken)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].method, "GET");
+        assert!(requests[0].path.starts_with(&format!("/tracks/{TRACK_ID}")));
+        assert_eq!(requests[0].query("market").as_deref(), Some("from_token"));
+        assert_eq!(track.id().as_str(), TRACK_ID);
+        assert_eq!(track.name(), "World is Mine");
+    }
+
+    #[test]
+    fn default_market_sync() {
+        let (url, server) = serve_requests(2, |_| json_response("200 OK", TRACK_JSON));
+        let client = sync_user_client_from(
+            SpotifyClientBuilder::new("client ID")
+                .api_base_url(url.parse().unwrap())
+                .default_market(CountryCode::JP),
+        );
+
+        client.track(Id::from_bare(TRACK_ID).unwrap()).send_sync().unwrap();
+        client
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .market(Market::FromToken)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].query("market").as_deref(), Some("JP"));
+        assert_eq!(requests[1].query("market").as_deref(), Some("from_token"));
+    }
+
+    #[test]
+    fn verify_token_sync() {
+        let (url, server) = serve_requests(1, |_| json_response("200 OK", r#"{ "markets": ["FI", "QQ"] }"#));
+
+        sync_user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(url.parse().unwrap()))
+            .verify_token()
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/markets");
+    }
+
+    #[test]
+    fn verify_token_unauthorized_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "401 Unauthorized",
+                r#"{ "error": { "status": 401, "message": "Invalid access token" } }"#,
+            )
+        });
+
+        let result = sync_user_client_from(SpotifyClientBuilder::new("client ID").api_base_url(url.parse().unwrap()))
+            .verify_token()
+            .send_sync();
+        server.join().unwrap();
+
+        assert!(
+            matches!(&result, Err(Error::Unauthorized(message)) if message == "Invalid access token"),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn user_agent_sync() {
+        let (url, server) = serve_requests(1, |_| json_response("200 OK", TRACK_JSON));
+
+        sync_user_client_from(
+            SpotifyClientBuilder::new("client ID")
+                .api_base_url(url.parse().unwrap())
+                .user_agent("my-app/1.0"),
+        )
+        .track(Id::from_bare(TRACK_ID).unwrap())
+        .send_sync()
+        .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests[0].header("user-agent").as_deref(), Some("my-app/1.0"));
+    }
+
+    #[test]
+    fn album_tracks_second_page_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "200 OK",
+                &format!(
+                    r#"{{
+                        "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=1&limit=1",
+                        "items": [{}],
+                        "limit": 1,
+                        "next": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=2&limit=1",
+                        "offset": 1,
+                        "previous": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=0&limit=1",
+                        "total": 3
+                    }}"#,
+                    PARTIAL_TRACK_JSON
+                ),
+            )
+        });
+
+        let page = mock_client(&url)
+            .album_tracks(Id::from_bare("0tDsHtvN9YNuZjlqHvDY2P").unwrap())
+            .limit(1)
+            .offset(1)
+            .market(CountryCode::FI)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].path.starts_with("/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks"));
+        assert_eq!(requests[0].query("limit").as_deref(), Some("1"));
+        assert_eq!(requests[0].query("offset").as_deref(), Some("1"));
+        assert_eq!(requests[0].query("market").as_deref(), Some("FI"));
+
+        assert_eq!(page.total(), 3);
+        assert_eq!(page.limit(), 1);
+        assert_eq!(page.offset(), 1);
+        assert_eq!(page.next_offset(), Some(2));
+        assert_eq!(page.prev_offset(), Some(0));
+
+        let tracks = page.take_items();
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].id().as_str(), TRACK_ID);
+        assert_eq!(tracks[0].name(), "World is Mine");
+    }
+
+    #[test]
+    fn artists_are_chunked_in_order_sync() {
+        let ids = (0..60).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+        let (url, server) = serve_requests(2, |request| {
+            let artists = request
+                .query("ids")
+                .unwrap()
+                .split(',')
+                .map(|id| {
+                    format!(
+                        r#"{{
+                            "external_urls": {{}},
+                            "followers": {{ "total": 0 }},
+                            "genres": [],
+                            "id": "{id}",
+                            "images": [],
+                            "name": "Artist {id}",
+                            "popularity": 0,
+                            "type": "artist"
+                        }}"#
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            json_response("200 OK", &format!(r#"{{ "artists": [{}] }}"#, artists.join(",")))
+        });
+
+        let artists = mock_client(&url)
+            .artists(ids.iter().map(|id| Id::from_bare(id).unwrap()))
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests.iter().all(|request| request.path.starts_with("/artists")));
+        assert_eq!(requests[0].query("ids").unwrap().split(',').count(), 50);
+        assert_eq!(requests[1].query("ids").unwrap().split(',').count(), 10);
+        assert_eq!(
+            artists
+                .iter()
+                .map(|artist| artist.id().as_str().to_owned())
+                .collect::<Vec<_>>(),
+            ids
+        );
+    }
+
+    #[test]
+    fn audio_features_are_chunked_in_order_sync() {
+        let ids = (0..150).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+
+        // the track with ID 120 has no audio features
+        let (url, server) = serve_requests(2, |request| {
+            let features = request
+                .query("ids")
+                .unwrap()
+                .split(',')
+                .map(|id| {
+                    if id == format!("{:0>22}", 120) {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::json!({
+                            "acousticness": 0.00242,
+                            "analysis_url": format!("https://api.spotify.com/v1/audio-analysis/{id}"),
+                            "danceability": 0.585,
+                            "duration_ms": 237040,
+                            "energy": 0.842,
+                            "id": id,
+                            "instrumentalness": 0.00686,
+                            "key": 9,
+                            "liveness": 0.0866,
+                            "loudness": -5.883,
+                            "mode": 0,
+                            "speechiness": 0.0556,
+                            "tempo": 118.211,
+                            "time_signature": 4,
+                            "track_href": format!("https://api.spotify.com/v1/tracks/{id}"),
+                            "type": "audio_features",
+                            "uri": format!("spotify:track:{id}"),
+                            "valence": 0.428
+                        })
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            json_response("200 OK", &serde_json::json!({ "audio_features": features }).to_string())
+        });
+
+        let features = mock_client(&url)
+            .audio_features_multiple(ids.iter().map(|id| Id::from_bare(id).unwrap()))
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests
+            .iter()
+            .all(|request| request.path.starts_with("/audio-features")));
+        assert_eq!(requests[0].query("ids").unwrap().split(',').count(), 100);
+        assert_eq!(requests[1].query("ids").unwrap().split(',').count(), 50);
+
+        let expected = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (i != 120).then(|| id.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            features
+                .iter()
+                .map(|features| features.as_ref().map(|features| features.id.as_str().to_owned()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn albums_are_chunked_in_order_sync() {
+        let ids = (0..25).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+
+        // the album with ID 21 doesn't exist
+        let (url, server) = serve_requests(2, |request| {
+            let albums = request
+                .query("ids")
+                .unwrap()
+                .split(',')
+                .map(|id| {
+                    if id.parse::<u32>().unwrap() == 21 {
+                        return serde_json::Value::Null;
+                    }
+
+                    serde_json::json!({
+                        "album_type": "album",
+                        "artists": [],
+                        "copyrights": [],
+                        "external_ids": {},
+                        "external_urls": {},
+                        "genres": [],
+                        "id": id,
+                        "images": [],
+                        "label": "Label",
+                        "name": format!("Album {id}"),
+                        "popularity": 0,
+                        "release_date": "2008",
+                        "release_date_precision": "year",
+                        "total_tracks": 0,
+                        "tracks": {
+                            "href": "",
+                            "items": [],
+                            "limit": 50,
+                            "next": null,
+                            "offset": 0,
+                            "previous": null,
+                            "total": 0
+                        },
+                        "type": "album"
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json_response("200 OK", &serde_json::json!({ "albums": albums }).to_string())
+        });
+
+        let albums = mock_client(&url)
+            .albums(ids.iter().map(|id| Id::from_bare(id).unwrap()))
+            .market(CountryCode::FI)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests.iter().all(|request| request.path.starts_with("/albums")));
+        assert!(requests
+            .iter()
+            .all(|request| request.query("market").as_deref() == Some("FI")));
+        assert_eq!(requests[0].query("ids").unwrap().split(',').count(), 20);
+        assert_eq!(requests[1].query("ids").unwrap().split(',').count(), 5);
+
+        assert_eq!(albums.len(), 25);
+        assert!(albums[21].is_none());
+
+        let expected = ids
+            .iter()
+            .map(|id| (id.parse::<u32>().unwrap() != 21).then(|| id.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            albums
+                .iter()
+                .map(|album| album.as_ref().map(|album| album.id().as_str().to_owned()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn artist_albums_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "200 OK",
+                &serde_json::json!({
+                    "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl/albums?offset=10&limit=1",
+                    "items": [{
+                        "album_group": "appears_on",
+                        "album_type": "compilation",
+                        "artists": [],
+                        "external_urls": {},
+                        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                        "images": [],
+                        "name": "Supercell",
+                        "release_date": "2009-03-04",
+                        "release_date_precision": "day",
+                        "total_tracks": 1,
+                        "type": "album"
+                    }],
+                    "limit": 1,
+                    "next": null,
+                    "offset": 10,
+                    "previous": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl/albums?offset=9&limit=1",
+                    "total": 11
+                })
+                .to_string(),
+            )
+        });
+
+        let page = mock_client(&url)
+            .artist_albums(Id::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap())
+            .include_groups([AlbumGroup::Album, AlbumGroup::Single, AlbumGroup::AppearsOn])
+            .limit(1)
+            .offset(10)
+            .market(CountryCode::JP)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].path.starts_with("/artists/6pNgnvzBa6Bthsv8SrZJYl/albums"));
+        assert_eq!(
+            requests[0].query("include_groups").as_deref(),
+            Some("album,single,appears_on")
+        );
+        assert_eq!(requests[0].query("limit").as_deref(), Some("1"));
+        assert_eq!(requests[0].query("offset").as_deref(), Some("10"));
+        assert_eq!(requests[0].query("market").as_deref(), Some("JP"));
+
+        assert_eq!(page.total(), 11);
+        assert_eq!(page.offset(), 10);
+        assert_eq!(page.next_offset(), None);
+        assert_eq!(page.prev_offset(), Some(9));
+
+        let albums = page.take_items();
+        assert_eq!(albums[0].id().as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+        assert_eq!(albums[0].album_group(), Some(AlbumGroup::AppearsOn));
+    }
+
+    fn show_json(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "copyrights": [],
+            "description": "Candid conversations about the podcasting industry.",
+            "explicit": false,
+            "external_urls": {},
+            "id": id,
+            "images": [],
+            "is_externally_hosted": false,
+            "languages": ["en"],
+            "media_type": "audio",
+            "name": "Spotify Sessions",
+            "publisher": "Spotify",
+            "total_episodes": 10,
+            "type": "show"
+        })
+    }
+
+    #[test]
+    fn episodes_are_chunked_in_order_sync() {
+        let ids = (0..60).map(|i| format!("{i:0>22}")).collect::<Vec<_>>();
+
+        // the episode with ID 50 isn't available, which makes it the first item of the second chunk
+        let (url, server) = serve_requests(2, |request| {
+            let episodes = request
+                .query("ids")
+                .unwrap()
+                .split(',')
+                .map(|id| {
+                    if id.parse::<u32>().unwrap() == 50 {
+                        return serde_json::Value::Null;
+                    }
+
+                    serde_json::json!({
+                        "audio_preview_url": null,
+                        "description": "",
+                        "duration_ms": 1686230,
+                        "explicit": false,
+                        "external_urls": {},
+                        "id": id,
+                        "images": [],
+                        "is_externally_hosted": false,
+                        "name": "Starting Your Own Podcast",
+                        "release_date": "1981-12-15",
+                        "release_date_precision": "day",
+                        "show": show_json("38bS44xjbVVZ3No3ByF1dJ"),
+                        "type": "episode"
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json_response("200 OK", &serde_json::json!({ "episodes": episodes }).to_string())
+        });
+
+        let episodes = mock_client(&url)
+            .episodes(ids.iter().map(|id| Id::from_bare(id).unwrap()))
+            .market(CountryCode::FI)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests.iter().all(|request| request.path.starts_with("/episodes")));
+        assert!(requests
+            .iter()
+            .all(|request| request.query("market").as_deref() == Some("FI")));
+        assert_eq!(requests[0].query("ids").unwrap().split(',').count(), 50);
+        assert_eq!(requests[1].query("ids").unwrap().split(',').count(), 10);
+
+        let expected = ids
+            .iter()
+            .map(|id| (id.parse::<u32>().unwrap() != 50).then(|| id.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            episodes
+                .iter()
+                .map(|episode| episode.as_ref().map(|episode| episode.id().as_str().to_owned()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn shows_keep_null_entries_in_place_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "200 OK",
+                &serde_json::json!({
+                    "shows": [show_json("38bS44xjbVVZ3No3ByF1dJ"), null, show_json("5CfCWKI5pZ28U0uOzXkDHe")]
+                })
+                .to_string(),
+            )
+        });
+
+        let shows = mock_client(&url)
+            .shows([
+                Id::from_bare("38bS44xjbVVZ3No3ByF1dJ").unwrap(),
+                Id::from_bare("0000000000000000000000").unwrap(),
+                Id::from_bare("5CfCWKI5pZ28U0uOzXkDHe").unwrap(),
+            ])
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].path.starts_with("/shows"));
+        assert_eq!(
+            requests[0].query("ids").as_deref(),
+            Some("38bS44xjbVVZ3No3ByF1dJ,0000000000000000000000,5CfCWKI5pZ28U0uOzXkDHe")
+        );
+        assert_eq!(
+            shows
+                .iter()
+                .map(|show| show.as_ref().map(|show| show.id().as_str().to_owned()))
+                .collect::<Vec<_>>(),
+            [
+                Some("38bS44xjbVVZ3No3ByF1dJ".to_owned()),
+                None,
+                Some("5CfCWKI5pZ28U0uOzXkDHe".to_owned())
+            ]
+        );
+    }
+
+    fn audiobook_json(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "authors": [{ "name": "Jane Austen" }],
+            "available_markets": ["GB", "US"],
+            "chapters": {
+                "href": "",
+                "items": [],
+                "limit": 50,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 0
+            },
+            "copyrights": [],
+            "description": "A classic novel of manners.",
+            "edition": null,
+            "explicit": false,
+            "external_urls": {},
+            "id": id,
+            "images": [],
+            "languages": ["en"],
+            "media_type": "audio",
+            "name": "Pride and Prejudice",
+            "narrators": [],
+            "publisher": "Audible Studios",
+            "total_chapters": 0,
+            "type": "audiobook"
+        })
+    }
+
+    #[test]
+    fn audiobook_in_market_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response("200 OK", &audiobook_json("7iHfbu1YPACw6oZPAFJtqe").to_string())
+        });
+
+        let audiobook = mock_client(&url)
+            .audiobook(Id::from_bare("7iHfbu1YPACw6oZPAFJtqe").unwrap())
+            .market(CountryCode::GB)
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].path.starts_with("/audiobooks/7iHfbu1YPACw6oZPAFJtqe"));
+        assert_eq!(requests[0].query("market").as_deref(), Some("GB"));
+        assert_eq!(audiobook.id().as_str(), "7iHfbu1YPACw6oZPAFJtqe");
+        assert_eq!(audiobook.edition(), None);
+    }
+
+    #[test]
+    fn audiobooks_keep_null_entries_in_place_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response(
+                "200 OK",
+                &serde_json::json!({
+                    "audiobooks": [audiobook_json("7iHfbu1YPACw6oZPAFJtqe"), null, audiobook_json("1HGw3J3NxZO1TP1BTtVhpZ")]
+                })
+                .to_string(),
+            )
+        });
+
+        let audiobooks = mock_client(&url)
+            .audiobooks([
+                Id::from_bare("7iHfbu1YPACw6oZPAFJtqe").unwrap(),
+                Id::from_bare("0000000000000000000000").unwrap(),
+                Id::from_bare("1HGw3J3NxZO1TP1BTtVhpZ").unwrap(),
+            ])
+            .send_sync()
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].path.starts_with("/audiobooks"));
+        assert_eq!(
+            audiobooks
+                .iter()
+                .map(|audiobook| audiobook.as_ref().map(|audiobook| audiobook.id().as_str().to_owned()))
+                .collect::<Vec<_>>(),
+            [
+                Some("7iHfbu1YPACw6oZPAFJtqe".to_owned()),
+                None,
+                Some("1HGw3J3NxZO1TP1BTtVhpZ".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn nonexistent_track_sync() {
+        let (url, server) = serve_requests(1, |_| {
+            json_response("404 Not Found", r#"{"error":{"status":404,"message":"Invalid id"}}"#)
+        });
+
+        let result = mock_client(&url).track(Id::from_bare(TRACK_ID).unwrap()).send_sync();
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::NonexistentTrack(id)) if id.as_str() == TRACK_ID));
+    }
+}
@@ -2,15 +2,18 @@
 
 use std::borrow::Cow;
 #[cfg(any(feature = "async", feature = "sync"))]
-use std::convert::Infallible;
+use std::{convert::Infallible, time::Duration};
 
 use thiserror::Error;
 
-use crate::model::ItemType;
+use crate::model::{artist::ArtistKind, ItemType};
 #[cfg(any(feature = "async", feature = "sync"))]
-use crate::model::{
-    error::AuthenticationErrorKind,
-    id::{AlbumId, ArtistId, EpisodeId, Id, PlaylistId, ShowId, TrackId},
+use crate::{
+    model::{
+        error::{ApiError, AuthError},
+        id::{AlbumId, ArtistId, EpisodeId, Id, PlaylistId, ShowId, TrackId},
+    },
+    scope::Scope,
 };
 
 /// The result type the library returns in the public-facing interface.
@@ -40,6 +43,11 @@ pub enum Error {
     #[error("The access token expired")]
     AccessTokenExpired,
 
+    /// Spotify rejected the access token for a reason other than it expiring, such as it being malformed or revoked.
+    /// The error message from Spotify is included. The user should be reauthorized.
+    #[error("The access token is invalid: {0}")]
+    Unauthorized(String),
+
     /// The refresh token is invalid; it cannot be used to retrieve an access token. This is likely due to the user
     /// removing the application's access to their account. The error message from Spotify is included. The user should
     /// be reauthorized.
@@ -51,12 +59,17 @@ pub enum Error {
     InvalidClient(String),
 
     /// Request rate limit was hit. The required wait time is included.
-    #[error("Request rate limit hit; retry after {0} seconds")]
-    RateLimit(u64),
+    ///
+    /// This error is returned only if reacting to rate limits is disabled, the maximum amount of rate limit retries has
+    /// been reached, or no sleep utility feature is enabled for the asynchronous client.
+    #[error("Request rate limit hit; retry after {} seconds", .0.as_secs())]
+    RateLimit(Duration),
 
-    /// The required scope for the endpoint being called has not been granted by the user.
-    #[error("The required scope for the endpoint has not been granted by the user")]
-    MissingScope,
+    /// The required scope for the endpoint being called has not been granted by the user. Contains the missing scope.
+    ///
+    /// If the client knows which scopes the user has granted, this error is returned before sending the request.
+    #[error("The required scope for the endpoint has not been granted by the user: {0}")]
+    MissingScope(Scope),
 
     /// The endpoint is forbidden and its possible error message body couldn't be mapped to a more specific error.
     ///
@@ -119,13 +132,52 @@ pub enum Error {
     InvalidRateLimitResponse,
 
     /// Spotify returned an authentication error we did not expect.
-    #[error("Unhandled authentication error: {0:?}: {1}")]
-    UnhandledAuthenticationError(AuthenticationErrorKind, String),
+    #[error("Unhandled authentication error: {0}")]
+    Auth(AuthError),
 
-    /// Spotify returned an response status code we did not expect.
+    /// Spotify returned an API error response we did not expect. The error's status code and message are included.
+    ///
+    /// ```no_run
+    /// # use ferrispot::error::Error;
+    /// # use ferrispot::model::error::ApiError;
+    /// # fn foo(err: Error) {
+    /// if let Error::Api(ApiError { status: 404, .. }) = err {
+    ///     println!("not found");
+    /// }
+    /// # }
+    /// ```
+    #[error("Unhandled Spotify API error: {0}")]
+    Api(ApiError),
+
+    /// Spotify returned an response status code we did not expect, and the response body was not an API error.
     #[error("Unhandled Spotify API response status code {0}")]
     UnhandledSpotifyResponseStatusCode(u16),
 
+    /// An argument given to an endpoint function is invalid, such as a volume percentage greater than 100. The request
+    /// is not sent. Contains a description of the invalid argument.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(Cow<'static, str>),
+
+    /// The page limit given to a paginated endpoint function is zero or greater than the endpoint's maximum. The
+    /// request is not sent.
+    #[error("Invalid page limit {limit}: the limit must be between 1 and {max}")]
+    InvalidPageLimit {
+        /// The given limit.
+        limit: u32,
+        /// The largest limit the endpoint accepts.
+        max: u32,
+    },
+
+    /// The page offset given to a paginated endpoint function is greater than the endpoint's maximum. The request is
+    /// not sent.
+    #[error("Invalid page offset {offset}: the offset must be at most {max}")]
+    InvalidPageOffset {
+        /// The given offset.
+        offset: u32,
+        /// The largest offset the endpoint accepts.
+        max: u32,
+    },
+
     /// Spotify returned an unexpected empty response (HTTP 204 No Content)
     #[error("Spotify returned an unexpected empty response (HTTP 204 No Content)")]
     EmptyResponse,
@@ -145,6 +197,12 @@ pub enum Error {
     /// request or receiving and decoding a response.
     #[error(transparent)]
     HttpError(#[from] reqwest::Error),
+
+    /// Deserializing a response body failed. Response bodies are usually decoded by reqwest, which returns an
+    /// [HttpError](Error::HttpError) instead, but the bodies of
+    /// [cached responses](crate::client::SpotifyClientBuilder::enable_response_cache) are decoded separately.
+    #[error(transparent)]
+    InvalidResponseBody(#[from] serde_json::Error),
 }
 
 /// Error type for parsing a Spotify [ID](crate::model::id::Id).
@@ -170,6 +228,22 @@ pub enum IdError {
     MalformedString(String),
 }
 
+/// Error type for parsing a [CountryCode](crate::model::CountryCode) or a [Market](crate::model::Market) from a
+/// string. Contains the invalid input.
+#[derive(Debug, Error)]
+#[error("Invalid country code: {0}")]
+pub struct CountryCodeError(pub(crate) String);
+
+/// Error type for parsing a [Scope](crate::scope::Scope) from a string. Contains the invalid input.
+#[derive(Debug, Error)]
+#[error("Invalid scope: {0}")]
+pub struct ScopeError(pub(crate) String);
+
+/// Error type for parsing a [Timestamp](crate::model::Timestamp) from a string. Contains the invalid input.
+#[derive(Debug, Error)]
+#[error("Invalid timestamp: {0}")]
+pub struct TimestampError(pub(crate) String);
+
 /// Error when converting serialized objects into model objects fails.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -182,6 +256,39 @@ impl std::fmt::Display for ConversionError {
     }
 }
 
+/// Error when converting an artist into a certain kind of artist fails, because the artist is of another kind that
+/// lacks the information the target kind requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("object conversion failed: attempt to convert {found} artist into {expected} artist")]
+pub struct ArtistConversionError {
+    pub(crate) expected: ArtistKind,
+    pub(crate) found: ArtistKind,
+}
+
+impl ArtistConversionError {
+    /// The kind of artist the conversion was into.
+    pub fn expected(&self) -> ArtistKind {
+        self.expected
+    }
+
+    /// The kind of artist that was encountered instead.
+    pub fn found(&self) -> ArtistKind {
+        self.found
+    }
+}
+
+impl From<ArtistConversionError> for ConversionError {
+    fn from(error: ArtistConversionError) -> Self {
+        ConversionError(
+            format!(
+                "attempt to convert {} artist into {} artist",
+                error.found, error.expected
+            )
+            .into(),
+        )
+    }
+}
+
 #[cfg(any(feature = "async", feature = "sync"))]
 impl From<Infallible> for Error {
     fn from(_: Infallible) -> Self {
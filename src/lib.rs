@@ -32,6 +32,18 @@
 //!   - In case neither are enabled, the library will return a [rate limit error](crate::error::Error::RateLimit) when
 //!     it occurs.
 //!   - These features are meaningless unless the `async` feature is also enabled.
+//! - `tracing`: wrap each HTTP request in a [`tracing`](https://docs.rs/tracing) span that records the request's
+//!   method, endpoint path and response status.
+//! - `chrono`: convert [timestamps](crate::model::Timestamp) into and from
+//!   [`chrono`'s `DateTime<Utc>`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html).
+//! - `time`: convert [timestamps](crate::model::Timestamp) into and from
+//!   [`time`'s `OffsetDateTime`](https://docs.rs/time/latest/time/struct.OffsetDateTime.html).
+//!   - The features are mutually exclusive and enabling both fails to compile. Timestamps return the enabled
+//!     feature's type from [datetime](crate::model::Timestamp::datetime). Without either, it returns the raw ISO 8601
+//!     timestamp string, and timestamps still convert into and from [SystemTime](std::time::SystemTime).
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+compile_error!("the `chrono` and `time` features are mutually exclusive; enable at most one of them");
 
 #[cfg(any(feature = "async", feature = "sync"))]
 pub mod client;
@@ -56,7 +68,7 @@ pub mod prelude {
     #[cfg(feature = "async")]
     pub use crate::client::{request_builder::AsyncRequestBuilder, AccessTokenRefreshAsync};
     #[cfg(any(feature = "async", feature = "sync"))]
-    pub use crate::client::{request_builder::BaseRequestBuilder, ScopedClient, UnscopedClient};
+    pub use crate::client::{request_builder::BaseRequestBuilder, RateLimitInformation, ScopedClient, UnscopedClient};
     #[cfg(feature = "sync")]
     pub use crate::client::{request_builder::SyncRequestBuilder, AccessTokenRefreshSync};
     pub use crate::{
@@ -67,6 +79,7 @@ pub mod prelude {
             search::ToTypesString,
             track::{CommonTrackInformation, FullTrackInformation, NonLocalTrackInformation, RelinkedTrackEquality},
             user::{CommonUserInformation, CurrentUserInformation, PrivateUserInformation},
+            ImageExt,
         },
         scope::ToScopesString,
     };
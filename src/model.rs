@@ -2,50 +2,99 @@
 //!
 //! Types here are *not* 1:1 representations of what the Spotify API returns, since such types are tedious to work with
 //! in a type-safe manner. Refer to the type documentation on how they map to the Spotify API objects.
+//!
+//! Every model may be deserialized from the JSON Spotify returns with any `serde` data format. The models own their
+//! data, so deserializing one never borrows from the input. JSON that is already in memory as bytes, such as a cached
+//! response, may be deserialized straight from the bytes with `serde_json::from_slice`, or with
+//! [Artist::from_slice](artist::Artist::from_slice) for artists.
+//!
+//! Likewise, every model may be serialized, such as for caching responses or forwarding them elsewhere. The serialized
+//! JSON uses Spotify's field names and may be deserialized into the model again. Optional fields Spotify leaves out,
+//! such as a track's `restrictions`, are included with their default values.
 
 pub mod album;
 pub mod artist;
+pub mod audio_analysis;
+pub mod audio_features;
+pub mod audiobook;
+pub mod browse;
+pub mod chapter;
+pub mod episode;
 pub mod error;
 pub mod id;
+pub mod library;
 pub mod playback;
+pub mod playlist;
+pub mod recommendations;
 pub mod search;
+pub mod show;
 pub mod track;
 pub mod user;
 
 mod country_code;
+mod image;
+mod market;
 pub(crate) mod object_type;
 mod page;
+mod release_date;
+mod timestamp;
 
 use std::{fmt, str::FromStr};
 
 pub use country_code::CountryCode;
-pub use page::Page;
+pub use image::{Image, ImageDimensions, ImageExt};
+pub use market::Market;
+#[cfg(any(feature = "async", feature = "sync"))]
+pub(crate) use page::CursorPageResponse;
+pub use page::{CursorPage, Cursors, Page};
+pub use release_date::ReleaseDate;
 use serde::{Deserialize, Serialize};
+pub use timestamp::{DateTime, Timestamp};
 
 use crate::error::IdError;
 
-// TODO: maybe make the fields private and expose them through functions
-/// Contains an URL to an image and its dimensions, if specified.
+/// Information about an user's, an artist's or a playlist's followers.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Image {
-    pub url: String,
-    #[serde(flatten)]
-    pub dimensions: Option<ImageDimensions>,
+pub struct Followers {
+    // the API documents this field but says it's always null. it's kept in case Spotify starts returning it
+    #[serde(default)]
+    href: Option<String>,
+    total: u32,
 }
 
-/// An image's dimensions.
-#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ImageDimensions {
-    pub width: u32,
-    pub height: u32,
+impl Followers {
+    /// The total amount of followers.
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// A link to the endpoint returning the full details of the followers. Spotify currently always returns `None`.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
 }
 
-/// A content restriction.
+/// A content restriction. Spotify returns restrictions only when the content is restricted, in which case the
+/// content's `is_playable` field is usually `false` as well.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Restrictions {
-    // TODO: this can be "market", "product", "explicit" or something else in the future. make it an enum
     /// Reason for the content restriction.
-    pub reason: Option<String>,
+    pub reason: Option<RestrictionReason>,
+}
+
+/// The reason content is restricted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RestrictionReason {
+    /// The content is not available in the given market.
+    Market,
+    /// The content is not available for the user's subscription level.
+    Product,
+    /// The content is explicit and the user's account is set to not play explicit content.
+    Explicit,
+    /// Spotify returned a reason this library doesn't know of.
+    #[serde(other)]
+    Unknown,
 }
 
 /// A date's precision.
@@ -55,12 +104,16 @@ pub enum DatePrecision {
     Year,
     Month,
     Day,
+    /// A precision this library doesn't know of. A date with an unknown precision is only as precise as its string.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Known external URLs for an object.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExternalUrls {
     /// The Spotify URL for the object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spotify: Option<String>,
 }
 
@@ -75,20 +128,28 @@ pub struct ExternalIds {
     pub upc: Option<String>,
 }
 
-// TODO: is this even used anywhere?
-/// A copyright.
+/// A copyright statement of an album or a show.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Copyright {
+    /// The copyright text, such as `2009 Sony Music Records`.
     pub text: String,
+    /// Whether the copyright is for the work itself or for its sound recording.
+    #[serde(rename = "type")]
     pub copyright_type: CopyrightType,
 }
 
 /// The type of a copyright.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CopyrightType {
+    /// The copyright of the work itself, marked with ©.
+    #[serde(rename = "C")]
+    Copyright,
+    /// The copyright of the sound recording (the phonogram) of the work, marked with ℗.
     #[serde(rename = "P")]
     Performance,
-    C, // TODO: what the shit is this supposed to be? i can't find anything about it in the spotify docs
+    /// A copyright type this library doesn't know of.
+    #[serde(other)]
+    Unknown,
 }
 
 /// The type of an item in the Spotify catalog.
@@ -102,6 +163,8 @@ pub enum ItemType {
     Track,
     Show,
     Episode,
+    Audiobook,
+    Chapter,
     Collection,
     User,
 }
@@ -117,6 +180,8 @@ impl fmt::Display for ItemType {
             ItemType::Track => write!(f, "track"),
             ItemType::Show => write!(f, "show"),
             ItemType::Episode => write!(f, "episode"),
+            ItemType::Audiobook => write!(f, "audiobook"),
+            ItemType::Chapter => write!(f, "chapter"),
             ItemType::Collection => write!(f, "collection"),
             ItemType::User => write!(f, "user"),
         }
@@ -134,6 +199,8 @@ impl FromStr for ItemType {
             "track" => Ok(Self::Track),
             "show" => Ok(Self::Show),
             "episode" => Ok(Self::Episode),
+            "audiobook" => Ok(Self::Audiobook),
+            "chapter" => Ok(Self::Chapter),
             "collection" => Ok(Self::Collection),
             "user" => Ok(Self::User),
 
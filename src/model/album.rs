@@ -4,8 +4,9 @@
 //!
 //! - [FullAlbum]: may contain all possible information about an album. Generally retrieved from the album- and
 //!   albums-endpoints (TODO: make links once implemented)
-//! - [PartialAlbum]: contains most information about an album. Generally retrieved as part of a response to, for
-//!   example, an artist listing (TODO: make a link to the artist endpoint once it exists).
+//! - [PartialAlbum]: contains most information about an album. Spotify calls these simplified album objects. Generally
+//!   retrieved as part of a response to, for example, an
+//!   [artist's album listing](crate::client::UnscopedClient::artist_albums) or a track.
 //! - [LocalAlbum]: contains only the basic information about an album. Only retrieved through a playlist that contains
 //!   local tracks.
 //!
@@ -26,11 +27,11 @@ mod private {
     use serde::{Deserialize, Serialize};
 
     use crate::model::{
-        album::{AlbumTracks, AlbumType},
+        album::{AlbumGroup, AlbumTracks, AlbumType},
         artist::PartialArtist,
         id::{AlbumId, Id},
         object_type::{object_type_serialize, TypeAlbum},
-        Copyright, CountryCode, DatePrecision, ExternalIds, ExternalUrls, Image, Restrictions,
+        Copyright, CountryCode, ExternalIds, ExternalUrls, Image, ReleaseDate, Restrictions,
     };
 
     pub(super) trait CommonFields {
@@ -88,15 +89,16 @@ mod private {
         pub(crate) label: String,
         pub(crate) popularity: u32,
         pub(crate) tracks: AlbumTracks,
-        // TODO: the artist album thing with the album group field
     }
 
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub(crate) struct NonLocalAlbumFields {
         pub(crate) album_type: AlbumType,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) album_group: Option<AlbumGroup>,
         pub(crate) id: Id<'static, AlbumId>,
-        pub(crate) release_date: String, // TODO: proper date type pls
-        pub(crate) release_date_precision: DatePrecision,
+        #[serde(flatten)]
+        pub(crate) release_date: ReleaseDate,
     }
 }
 
@@ -106,12 +108,12 @@ use serde::{Deserialize, Serialize, Serializer};
 
 pub(crate) use self::private::{AlbumObject, CommonAlbumFields, FullAlbumFields, NonLocalAlbumFields};
 use super::{
-    artist::PartialArtist,
+    artist::{CommonArtistInformation, PartialArtist},
     country_code::CountryCode,
     id::{AlbumId, Id, IdTrait},
     page::{Page, PageInformation, PageObject},
     track::{PartialTrack, TrackObject},
-    Copyright, DatePrecision, ExternalIds, ExternalUrls, Image, Restrictions,
+    Copyright, DatePrecision, ExternalIds, ExternalUrls, Image, ReleaseDate, Restrictions,
 };
 use crate::error::ConversionError;
 
@@ -121,11 +123,17 @@ pub trait CommonAlbumInformation: crate::private::Sealed {
     fn name(&self) -> &str;
     /// The artists of the album.
     fn artists(&self) -> &[PartialArtist];
+    /// The names of the album's artists.
+    fn artist_names(&self) -> Vec<&str>;
+    /// The names of the album's artists joined with the given separator, such as `Hatsune Miku, ryo` with `", "`. An
+    /// empty string if the album has no artists.
+    fn artist_names_joined(&self, separator: &str) -> String;
     /// The images for the album.
     fn images(&self) -> &[Image];
     /// The external URLs for the album.
     fn external_urls(&self) -> &ExternalUrls;
-    /// The countries the album is available in.
+    /// The countries the album is available in. Empty if a market was given in the request, since Spotify only returns
+    /// the album if it's available in the market.
     fn available_markets(&self) -> &HashSet<CountryCode>;
     /// The restrictions on the album.
     fn restrictions(&self) -> &Restrictions;
@@ -133,8 +141,6 @@ pub trait CommonAlbumInformation: crate::private::Sealed {
 
 /// Functions for retrieving information only in full albums.
 pub trait FullAlbumInformation: crate::private::Sealed {
-    // TODO: the artist album thing with the album group field
-
     /// The tracks in the album.
     fn tracks(&self) -> Page<AlbumTracks, PartialTrack>;
     /// The album's copyrights.
@@ -153,10 +159,13 @@ pub trait FullAlbumInformation: crate::private::Sealed {
 pub trait NonLocalAlbumInformation: crate::private::Sealed {
     /// The album's type.
     fn album_type(&self) -> AlbumType;
+    /// How the album relates to an artist. Only available in albums retrieved as part of an artist's albums, otherwise
+    /// `None`.
+    fn album_group(&self) -> Option<AlbumGroup>;
     /// The album's Spotify ID.
     fn id(&self) -> Id<'_, AlbumId>;
     /// The album's release date.
-    fn release_date(&self) -> &str;
+    fn release_date(&self) -> ReleaseDate;
     /// The album's release date's precision.
     fn release_date_precision(&self) -> DatePrecision;
 }
@@ -173,6 +182,14 @@ where
         &self.common_fields().artists
     }
 
+    fn artist_names(&self) -> Vec<&str> {
+        self.artists().iter().map(|artist| artist.name()).collect()
+    }
+
+    fn artist_names_joined(&self, separator: &str) -> String {
+        self.artist_names().join(separator)
+    }
+
     fn images(&self) -> &[Image] {
         &self.common_fields().images
     }
@@ -230,16 +247,20 @@ where
         self.non_local_fields().album_type
     }
 
+    fn album_group(&self) -> Option<AlbumGroup> {
+        self.non_local_fields().album_group
+    }
+
     fn id(&self) -> Id<'_, AlbumId> {
         self.non_local_fields().id.as_borrowed()
     }
 
-    fn release_date(&self) -> &str {
-        &self.non_local_fields().release_date
+    fn release_date(&self) -> ReleaseDate {
+        self.non_local_fields().release_date
     }
 
     fn release_date_precision(&self) -> DatePrecision {
-        self.non_local_fields().release_date_precision
+        self.non_local_fields().release_date.precision()
     }
 }
 
@@ -266,8 +287,9 @@ struct AlbumObjectRef<'a> {
 
 /// A page of tracks in an album.
 ///
-/// This object is retrieved only through the [tracks](FullAlbumInformation::tracks)-function. You won't be interacting
-/// objects of this type directly.
+/// This object is retrieved only through the [tracks](FullAlbumInformation::tracks)- and
+/// [album_tracks](crate::client::UnscopedClient::album_tracks)-functions. You won't be interacting objects of this type
+/// directly.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[doc(hidden)]
 pub struct AlbumTracks {
@@ -275,6 +297,17 @@ pub struct AlbumTracks {
     page: PageObject<TrackObject>,
 }
 
+/// A page of an artist's albums.
+///
+/// This object is retrieved only through the [artist_albums](crate::client::UnscopedClient::artist_albums)-function.
+/// You won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct ArtistAlbums {
+    #[serde(flatten)]
+    page: PageObject<AlbumObject>,
+}
+
 /// A full album. Contains [full information](self::FullAlbumInformation), in addition to all
 /// [common](self::CommonAlbumInformation) and [non-local](self::NonLocalAlbumInformation) information about an album.
 #[derive(Debug, Clone, Eq, Deserialize)]
@@ -287,7 +320,8 @@ pub struct FullAlbum {
 }
 
 /// A partial album. Contains all [common](self::CommonAlbumInformation) and [non-local](self::NonLocalAlbumInformation)
-/// information about an album.
+/// information about an album. Spotify's simplified album objects, such as the albums in an artist's album listing,
+/// deserialize into this type.
 #[derive(Debug, Clone, Eq, Deserialize)]
 #[serde(try_from = "AlbumObject")]
 pub struct PartialAlbum {
@@ -312,6 +346,40 @@ pub enum AlbumType {
     Single,
     #[serde(alias = "COMPILATION")]
     Compilation,
+    /// An album type this library doesn't know of.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How an album relates to an artist in the artist's albums. Unlike the album's [type](AlbumType), tells apart the
+/// artist's own albums from albums the artist only appears on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlbumGroup {
+    /// An album by the artist.
+    Album,
+    /// A single by the artist.
+    Single,
+    /// A compilation by the artist.
+    Compilation,
+    /// An album by other artists the artist appears on.
+    AppearsOn,
+    /// An album group this library doesn't know of.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AlbumGroup {
+    /// Returns the album group as the string Spotify uses for it, such as `appears_on`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlbumGroup::Album => "album",
+            AlbumGroup::Single => "single",
+            AlbumGroup::Compilation => "compilation",
+            AlbumGroup::AppearsOn => "appears_on",
+            AlbumGroup::Unknown => "unknown",
+        }
+    }
 }
 
 impl PartialEq for FullAlbum {
@@ -537,6 +605,7 @@ impl crate::private::Sealed for FullAlbum {}
 impl crate::private::Sealed for PartialAlbum {}
 impl crate::private::Sealed for LocalAlbum {}
 impl crate::private::Sealed for AlbumTracks {}
+impl crate::private::Sealed for ArtistAlbums {}
 
 impl private::CommonFields for FullAlbum {
     fn common_fields(&self) -> &CommonAlbumFields {
@@ -574,6 +643,15 @@ impl private::FullFields for FullAlbum {
     }
 }
 
+impl From<AlbumTracks> for Page<AlbumTracks, PartialTrack> {
+    fn from(page: AlbumTracks) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl PageInformation<PartialTrack> for AlbumTracks {
     type Items = Vec<PartialTrack>;
 
@@ -588,6 +666,71 @@ impl PageInformation<PartialTrack> for AlbumTracks {
     fn next(self) -> Option<String> {
         <PageObject<TrackObject> as PageInformation<PartialTrack>>::next(self.page)
     }
+
+    fn total(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::has_previous(&self.page)
+    }
+}
+
+impl From<ArtistAlbums> for Page<ArtistAlbums, PartialAlbum> {
+    fn from(page: ArtistAlbums) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl PageInformation<PartialAlbum> for ArtistAlbums {
+    type Items = Vec<PartialAlbum>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::has_previous(&self.page)
+    }
 }
 
 impl Serialize for Album {
@@ -647,3 +790,364 @@ impl Serialize for LocalAlbum {
 
 // TODO: unit tests for all the various functions here. deserializing, serializing, equality between tracks, conversion
 // between tracks
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::model::{
+        artist::CommonArtistInformation,
+        track::{CommonTrackInformation, NonLocalTrackInformation},
+        CopyrightType,
+    };
+
+    fn artists_json() -> Value {
+        json!([
+            {
+                "external_urls": {
+                    "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                },
+                "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                "name": "Hatsune Miku",
+                "type": "artist",
+                "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+            }
+        ])
+    }
+
+    fn partial_album_json() -> Value {
+        json!({
+            "album_type": "album",
+            "artists": artists_json(),
+            "available_markets": ["FI", "JP"],
+            "external_urls": {
+                "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                {
+                    "height": 640,
+                    "url": "https://i.scdn.co/image/ab67616d0000b273a7e6a1d2d0c5e04a3fb81b1c",
+                    "width": 640
+                }
+            ],
+            "name": "Supercell",
+            "release_date": "2009-03-04",
+            "release_date_precision": "day",
+            "total_tracks": 1,
+            "type": "album",
+            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+        })
+    }
+
+    fn full_album_json() -> Value {
+        let mut value = partial_album_json();
+        let obj = value.as_object_mut().unwrap();
+
+        obj.insert(
+            "copyrights".to_owned(),
+            json!([{ "text": "2009 Sony Music Records", "type": "P" }]),
+        );
+        obj.insert("external_ids".to_owned(), json!({ "upc": "4547366046553" }));
+        obj.insert("genres".to_owned(), json!([]));
+        obj.insert("label".to_owned(), json!("Sony Music Records"));
+        obj.insert("popularity".to_owned(), json!(42));
+        obj.insert(
+            "tracks".to_owned(),
+            json!({
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=0&limit=50",
+                "items": [
+                    {
+                        "artists": artists_json(),
+                        "available_markets": ["FI", "JP"],
+                        "disc_number": 1,
+                        "duration_ms": 234000,
+                        "explicit": false,
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/track/3mXLyNsVeLelMakgpGUp1f"
+                        },
+                        "href": "https://api.spotify.com/v1/tracks/3mXLyNsVeLelMakgpGUp1f",
+                        "id": "3mXLyNsVeLelMakgpGUp1f",
+                        "is_local": false,
+                        "name": "Melt",
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track",
+                        "uri": "spotify:track:3mXLyNsVeLelMakgpGUp1f"
+                    }
+                ],
+                "limit": 50,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 1
+            }),
+        );
+
+        value
+    }
+
+    // local albums appear only in playlists with local tracks, and have most of their fields nulled out
+    fn local_album_json() -> Value {
+        json!({
+            "album_type": null,
+            "artists": [],
+            "available_markets": [],
+            "external_urls": {},
+            "href": null,
+            "id": null,
+            "images": [],
+            "name": "Some Local Album",
+            "release_date": null,
+            "release_date_precision": null,
+            "type": "album",
+            "uri": null
+        })
+    }
+
+    fn album_from_json(value: Value) -> Album {
+        let obj: AlbumObject = serde_json::from_value(value).unwrap();
+        Album::try_from(obj).unwrap()
+    }
+
+    // ===============
+    // deserialization
+    // ===============
+
+    #[test]
+    fn deserialize_full_album() {
+        let album: FullAlbum = serde_json::from_value(full_album_json()).unwrap();
+
+        assert_eq!(album.name(), "Supercell");
+        assert_eq!(album.id().as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+        assert_eq!(album.album_type(), AlbumType::Album);
+        assert_eq!(album.release_date().to_string(), "2009-03-04");
+        assert_eq!(album.release_date_precision(), DatePrecision::Day);
+        assert_eq!(album.images().len(), 1);
+        assert_eq!(album.label(), "Sony Music Records");
+        assert_eq!(album.popularity(), 42);
+        assert_eq!(album.copyrights().len(), 1);
+        assert!(album.available_markets().contains(&CountryCode::FI));
+
+        let tracks = album.tracks();
+        assert_eq!(tracks.items().len(), 1);
+        assert_eq!(tracks.items()[0].name(), "Melt");
+    }
+
+    #[test]
+    fn album_embedded_tracks_are_partial() {
+        let album: FullAlbum = serde_json::from_value(full_album_json()).unwrap();
+        let tracks = album.tracks().take_items();
+        let track = &tracks[0];
+
+        assert_eq!(track.id().as_str(), "3mXLyNsVeLelMakgpGUp1f");
+        assert_eq!(track.track_number(), 1);
+        assert_eq!(track.disc_number(), 1);
+        assert_eq!(track.artist_names(), ["Hatsune Miku"]);
+    }
+
+    #[test]
+    fn artist_albums_embedded_albums_are_partial() {
+        let mut album = partial_album_json();
+        album["album_group"] = json!("single");
+
+        let page: ArtistAlbums = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl/albums?offset=0&limit=20",
+            "items": [album],
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        }))
+        .unwrap();
+        let albums = Page::from(page).take_items();
+
+        assert_eq!(albums[0].name(), "Supercell");
+        assert_eq!(albums[0].album_group(), Some(AlbumGroup::Single));
+        assert_eq!(albums[0].release_date().to_string(), "2009-03-04");
+    }
+
+    #[test]
+    fn album_types() {
+        let album_type = |album_type: &str| -> AlbumType { serde_json::from_value(json!(album_type)).unwrap() };
+
+        assert_eq!(album_type("album"), AlbumType::Album);
+        assert_eq!(album_type("single"), AlbumType::Single);
+        assert_eq!(album_type("compilation"), AlbumType::Compilation);
+        assert_eq!(album_type("COMPILATION"), AlbumType::Compilation);
+    }
+
+    #[test]
+    fn album_groups() {
+        let album_group = |album_group: &str| -> AlbumGroup { serde_json::from_value(json!(album_group)).unwrap() };
+
+        assert_eq!(album_group("album"), AlbumGroup::Album);
+        assert_eq!(album_group("single"), AlbumGroup::Single);
+        assert_eq!(album_group("compilation"), AlbumGroup::Compilation);
+        assert_eq!(album_group("appears_on"), AlbumGroup::AppearsOn);
+    }
+
+    // albums retrieved as part of an artist's albums have an album group
+    #[test]
+    fn partial_album_with_album_group() {
+        let mut value = partial_album_json();
+        value["album_group"] = json!("appears_on");
+
+        let album: PartialAlbum = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(album.album_type(), AlbumType::Album);
+        assert_eq!(album.album_group(), Some(AlbumGroup::AppearsOn));
+        assert_eq!(serde_json::to_value(&album).unwrap()["album_group"], "appears_on");
+    }
+
+    #[test]
+    fn album_without_album_group() {
+        let album: PartialAlbum = serde_json::from_value(partial_album_json()).unwrap();
+
+        assert_eq!(album.album_group(), None);
+        assert!(serde_json::to_value(&album).unwrap().get("album_group").is_none());
+    }
+
+    #[test]
+    fn full_album_copyrights() {
+        let mut value = full_album_json();
+        value["copyrights"] = json!([
+            { "text": "2009 Sony Music Records", "type": "C" },
+            { "text": "2009 Sony Music Records", "type": "P" }
+        ]);
+
+        let album: FullAlbum = serde_json::from_value(value).unwrap();
+
+        assert_eq!(
+            album.copyrights(),
+            [
+                Copyright {
+                    text: "2009 Sony Music Records".to_owned(),
+                    copyright_type: CopyrightType::Copyright
+                },
+                Copyright {
+                    text: "2009 Sony Music Records".to_owned(),
+                    copyright_type: CopyrightType::Performance
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn full_album_external_ids() {
+        let album: FullAlbum = serde_json::from_value(full_album_json()).unwrap();
+
+        assert_eq!(
+            album.external_ids(),
+            &ExternalIds {
+                upc: Some("4547366046553".to_owned()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_partial_album() {
+        let album: PartialAlbum = serde_json::from_value(partial_album_json()).unwrap();
+
+        assert_eq!(album.name(), "Supercell");
+        assert_eq!(album.id().as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+        assert_eq!(album.artists()[0].name(), "Hatsune Miku");
+    }
+
+    #[test]
+    fn deserialize_local_album() {
+        let album: LocalAlbum = serde_json::from_value(local_album_json()).unwrap();
+
+        assert_eq!(album.name(), "Some Local Album");
+        assert!(album.artists().is_empty());
+    }
+
+    #[test]
+    fn album_without_available_markets() {
+        let mut value = partial_album_json();
+        value.as_object_mut().unwrap().remove("available_markets");
+        let album = PartialAlbum::try_from(album_from_json(value)).unwrap();
+
+        assert!(album.available_markets().is_empty());
+    }
+
+    #[test]
+    fn album_artist_names_joined() {
+        let album = PartialAlbum::try_from(album_from_json(partial_album_json())).unwrap();
+        assert_eq!(album.artist_names_joined(" & "), "Hatsune Miku");
+
+        let mut value = partial_album_json();
+        let mut second = value["artists"][0].clone();
+        second["name"] = json!("ryo");
+        value["artists"].as_array_mut().unwrap().push(second);
+        let album = PartialAlbum::try_from(album_from_json(value)).unwrap();
+
+        assert_eq!(album.artist_names(), ["Hatsune Miku", "ryo"]);
+        assert_eq!(album.artist_names_joined(" & "), "Hatsune Miku & ryo");
+    }
+
+    #[test]
+    fn album_object_converts_into_matching_variant() {
+        assert!(matches!(album_from_json(full_album_json()), Album::Full(_)));
+        assert!(matches!(album_from_json(partial_album_json()), Album::Partial(_)));
+        assert!(matches!(album_from_json(local_album_json()), Album::Local(_)));
+    }
+
+    // ==========
+    // conversion
+    // ==========
+
+    #[test]
+    fn full_album_try_from_partial_album_fails() {
+        assert!(FullAlbum::try_from(album_from_json(partial_album_json())).is_err());
+    }
+
+    #[test]
+    fn full_album_try_from_local_album_fails() {
+        assert!(FullAlbum::try_from(album_from_json(local_album_json())).is_err());
+    }
+
+    #[test]
+    fn partial_album_try_from_local_album_fails() {
+        assert!(PartialAlbum::try_from(album_from_json(local_album_json())).is_err());
+    }
+
+    #[test]
+    fn partial_album_try_from_full_album_succeeds() {
+        let partial = PartialAlbum::try_from(album_from_json(full_album_json())).unwrap();
+        let full: FullAlbum = serde_json::from_value(full_album_json()).unwrap();
+
+        assert_eq!(partial, full);
+    }
+
+    #[test]
+    fn local_album_from_any_album_succeeds() {
+        let local = LocalAlbum::from(album_from_json(full_album_json()));
+        assert_eq!(local.name(), "Supercell");
+    }
+
+    #[test]
+    fn unknown_album_type_and_group() {
+        assert_eq!(
+            serde_json::from_value::<AlbumType>(json!("ep")).unwrap(),
+            AlbumType::Unknown
+        );
+        assert_eq!(
+            serde_json::from_value::<AlbumGroup>(json!("ep")).unwrap(),
+            AlbumGroup::Unknown
+        );
+    }
+
+    #[test]
+    fn unknown_copyright_type() {
+        assert_eq!(
+            serde_json::from_value::<CopyrightType>(json!("R")).unwrap(),
+            CopyrightType::Unknown
+        );
+    }
+}
@@ -19,6 +19,10 @@
 //!
 //! Two artists are considered equal when their Spotify IDs are the same. However, since [LocalArtist] doesn't have a
 //! Spotify ID, it resorts to comparing all available fields.
+//!
+//! Artists are hashed consistently with their equality, so they may be used in a `HashSet` or as `HashMap` keys. The
+//! [Artist] enum is only equal to another artist of the same kind; to compare any kinds of artists, use
+//! [Artist::id_eq].
 
 mod private {
     use serde::{Deserialize, Serialize};
@@ -26,7 +30,7 @@ mod private {
     use crate::model::{
         id::{ArtistId, Id},
         object_type::{object_type_serialize, TypeArtist},
-        ExternalUrls, Image,
+        ExternalUrls, Followers, Image,
     };
 
     pub(super) trait CommonFields {
@@ -69,7 +73,8 @@ mod private {
 
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub(crate) struct FullArtistFields {
-        // followers: Followers,
+        #[serde(default)]
+        pub(crate) followers: Followers,
         pub(crate) genres: Vec<String>,
         pub(crate) images: Vec<Image>,
         pub(crate) popularity: u32,
@@ -81,14 +86,19 @@ mod private {
     }
 }
 
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
 use serde::{Deserialize, Serialize, Serializer};
 
 pub(crate) use self::private::{ArtistObject, CommonArtistFields, FullArtistFields, NonLocalArtistFields};
 use super::{
     id::{ArtistId, Id, IdTrait},
-    ExternalUrls, Image,
+    ExternalUrls, Followers, Image,
 };
-use crate::error::ConversionError;
+use crate::error::{ArtistConversionError, ConversionError};
 
 /// Functions for retrieving information that is common to every artist type.
 pub trait CommonArtistInformation: crate::private::Sealed {
@@ -100,6 +110,8 @@ pub trait CommonArtistInformation: crate::private::Sealed {
 
 /// Functions for retrieving information only in full artists.
 pub trait FullArtistInformation: crate::private::Sealed {
+    /// Information about the artist's followers.
+    fn followers(&self) -> &Followers;
     /// Genres the artist is associated with.
     fn genres(&self) -> &[String];
     /// Images for the artist.
@@ -131,6 +143,10 @@ impl<T> FullArtistInformation for T
 where
     T: private::FullFields + crate::private::Sealed,
 {
+    fn followers(&self) -> &Followers {
+        &self.full_fields().followers
+    }
+
     fn genres(&self) -> &[String] {
         &self.full_fields().genres
     }
@@ -155,6 +171,7 @@ where
 
 /// An enum that encompasses all artist types.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "ArtistObject")]
 pub enum Artist {
     Full(Box<FullArtist>),
     Partial(Box<PartialArtist>),
@@ -202,6 +219,100 @@ pub struct LocalArtist {
     common: CommonArtistFields,
 }
 
+/// The kind of an [Artist]; full, partial or local.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArtistKind {
+    Full,
+    Partial,
+    Local,
+}
+
+impl fmt::Display for ArtistKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArtistKind::Full => write!(f, "full"),
+            ArtistKind::Partial => write!(f, "partial"),
+            ArtistKind::Local => write!(f, "local"),
+        }
+    }
+}
+
+impl Artist {
+    /// Deserializes an artist from JSON bytes, such as a cached response, with `serde_json::from_slice`. The bytes are
+    /// deserialized as they are, without first copying them into a string.
+    #[cfg(any(feature = "async", feature = "sync"))]
+    pub fn from_slice(json: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(json)
+    }
+
+    /// Returns whether this artist is the same artist as the other one, regardless of which kind of artist either one
+    /// is. Non-local artists are compared by their Spotify IDs. Local artists don't have Spotify IDs, so they are
+    /// compared by their names instead, and are never the same artist as a non-local artist.
+    pub fn id_eq(&self, other: &Artist) -> bool {
+        match (self.non_local_id(), other.non_local_id()) {
+            (Some(id), Some(other_id)) => id == other_id,
+            (None, None) => self.name() == other.name(),
+            _ => false,
+        }
+    }
+
+    /// Returns which kind of artist this artist is.
+    pub fn kind(&self) -> ArtistKind {
+        match self {
+            Artist::Full(_) => ArtistKind::Full,
+            Artist::Partial(_) => ArtistKind::Partial,
+            Artist::Local(_) => ArtistKind::Local,
+        }
+    }
+
+    /// Returns whether this artist is a full artist.
+    pub fn is_full(&self) -> bool {
+        matches!(self, Artist::Full(_))
+    }
+
+    /// Returns whether this artist is a partial artist.
+    pub fn is_partial(&self) -> bool {
+        matches!(self, Artist::Partial(_))
+    }
+
+    /// Returns whether this artist is a local artist.
+    pub fn is_local(&self) -> bool {
+        matches!(self, Artist::Local(_))
+    }
+
+    /// Returns a reference to the full artist, if this artist is one.
+    pub fn as_full(&self) -> Option<&FullArtist> {
+        match self {
+            Artist::Full(full) => Some(full),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the partial artist, if this artist is one.
+    pub fn as_partial(&self) -> Option<&PartialArtist> {
+        match self {
+            Artist::Partial(partial) => Some(partial),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the local artist, if this artist is one.
+    pub fn as_local(&self) -> Option<&LocalArtist> {
+        match self {
+            Artist::Local(local) => Some(local),
+            _ => None,
+        }
+    }
+
+    fn non_local_id(&self) -> Option<Id<'_, ArtistId>> {
+        match self {
+            Artist::Full(full) => Some(full.id()),
+            Artist::Partial(partial) => Some(partial.id()),
+            Artist::Local(_) => None,
+        }
+    }
+}
+
 impl PartialEq for FullArtist {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
@@ -250,6 +361,35 @@ impl PartialEq<PartialArtist> for LocalArtist {
     }
 }
 
+// hashing has to agree with equality, so non-local artists are hashed by their ID and local artists by their name
+impl Hash for Artist {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Artist::Full(full) => full.hash(state),
+            Artist::Partial(partial) => partial.hash(state),
+            Artist::Local(local) => local.hash(state),
+        }
+    }
+}
+
+impl Hash for FullArtist {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().as_str().hash(state);
+    }
+}
+
+impl Hash for PartialArtist {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().as_str().hash(state);
+    }
+}
+
+impl Hash for LocalArtist {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
 impl TryFrom<ArtistObject> for Artist {
     type Error = ConversionError;
 
@@ -298,25 +438,22 @@ impl From<LocalArtist> for Artist {
 }
 
 impl TryFrom<Artist> for FullArtist {
-    type Error = ConversionError;
+    type Error = ArtistConversionError;
 
     fn try_from(artist: Artist) -> Result<Self, Self::Error> {
         match artist {
             Artist::Full(full) => Ok(*full),
 
-            Artist::Partial(_) => Err(ConversionError(
-                "attempt to convert partial artist into full artist".into(),
-            )),
-
-            Artist::Local(_) => Err(ConversionError(
-                "attempt to convert local artist into full artist".into(),
-            )),
+            other => Err(ArtistConversionError {
+                expected: ArtistKind::Full,
+                found: other.kind(),
+            }),
         }
     }
 }
 
 impl TryFrom<ArtistObject> for FullArtist {
-    type Error = ConversionError;
+    type Error = ArtistConversionError;
 
     fn try_from(obj: ArtistObject) -> Result<Self, Self::Error> {
         match (obj.non_local, obj.full) {
@@ -326,53 +463,68 @@ impl TryFrom<ArtistObject> for FullArtist {
                 full,
             }),
 
-            (non_local, full) => Err(ConversionError(
-                format!(
-                    "attempt to convert non-full artist object into full artist (non-local artist fields is \
-                     {non_local:?}, full artist fields is {full:?})"
-                )
-                .into(),
-            )),
+            (Some(_), None) => Err(ArtistConversionError {
+                expected: ArtistKind::Full,
+                found: ArtistKind::Partial,
+            }),
+
+            // without the non-local fields, the artist has no ID, no matter which other fields it has
+            (None, _) => Err(ArtistConversionError {
+                expected: ArtistKind::Full,
+                found: ArtistKind::Local,
+            }),
         }
     }
 }
 
 impl TryFrom<Artist> for PartialArtist {
-    type Error = ConversionError;
+    type Error = ArtistConversionError;
 
     fn try_from(artist: Artist) -> Result<Self, Self::Error> {
         match artist {
-            Artist::Full(full) => Ok(PartialArtist {
-                common: full.common,
-                non_local: full.non_local,
-            }),
+            Artist::Full(full) => Ok((*full).into()),
 
             Artist::Partial(partial) => Ok(*partial),
 
-            Artist::Local(_) => Err(ConversionError(
-                "attempt to convert local artist into partial artist".into(),
-            )),
+            Artist::Local(_) => Err(ArtistConversionError {
+                expected: ArtistKind::Partial,
+                found: ArtistKind::Local,
+            }),
+        }
+    }
+}
+
+impl TryFrom<&Artist> for PartialArtist {
+    type Error = ArtistConversionError;
+
+    fn try_from(artist: &Artist) -> Result<Self, Self::Error> {
+        match artist {
+            Artist::Full(full) => Ok(full.as_ref().into()),
+
+            Artist::Partial(partial) => Ok(partial.as_ref().clone()),
+
+            Artist::Local(_) => Err(ArtistConversionError {
+                expected: ArtistKind::Partial,
+                found: ArtistKind::Local,
+            }),
         }
     }
 }
 
 impl TryFrom<ArtistObject> for PartialArtist {
-    type Error = ConversionError;
+    type Error = ArtistConversionError;
 
     fn try_from(obj: ArtistObject) -> Result<Self, Self::Error> {
-        if let Some(non_local) = obj.non_local {
-            Ok(PartialArtist {
+        match obj.non_local {
+            Some(non_local) => Ok(PartialArtist {
                 common: obj.common,
                 non_local,
-            })
-        } else {
-            Err(ConversionError(
-                format!(
-                    "attempt to convert local artist object into partial artist (non-local artist fields is {:?})",
-                    obj.non_local
-                )
-                .into(),
-            ))
+            }),
+
+            None => Err(ArtistConversionError {
+                expected: ArtistKind::Partial,
+                found: ArtistKind::Local,
+            }),
         }
     }
 }
@@ -380,13 +532,69 @@ impl TryFrom<ArtistObject> for PartialArtist {
 impl From<Artist> for LocalArtist {
     fn from(artist: Artist) -> Self {
         match artist {
-            Artist::Full(full) => LocalArtist { common: full.common },
-            Artist::Partial(partial) => LocalArtist { common: partial.common },
+            Artist::Full(full) => (*full).into(),
+            Artist::Partial(partial) => (*partial).into(),
             Artist::Local(local) => *local,
         }
     }
 }
 
+impl From<&Artist> for LocalArtist {
+    fn from(artist: &Artist) -> Self {
+        match artist {
+            Artist::Full(full) => full.as_ref().into(),
+            Artist::Partial(partial) => partial.as_ref().into(),
+            Artist::Local(local) => local.as_ref().clone(),
+        }
+    }
+}
+
+impl From<FullArtist> for PartialArtist {
+    fn from(full: FullArtist) -> Self {
+        PartialArtist {
+            common: full.common,
+            non_local: full.non_local,
+        }
+    }
+}
+
+impl From<FullArtist> for LocalArtist {
+    fn from(full: FullArtist) -> Self {
+        LocalArtist { common: full.common }
+    }
+}
+
+impl From<PartialArtist> for LocalArtist {
+    fn from(partial: PartialArtist) -> Self {
+        LocalArtist { common: partial.common }
+    }
+}
+
+impl From<&FullArtist> for PartialArtist {
+    fn from(full: &FullArtist) -> Self {
+        PartialArtist {
+            common: full.common.clone(),
+            non_local: full.non_local.clone(),
+        }
+    }
+}
+
+impl From<&FullArtist> for LocalArtist {
+    fn from(full: &FullArtist) -> Self {
+        LocalArtist {
+            common: full.common.clone(),
+        }
+    }
+}
+
+impl From<&PartialArtist> for LocalArtist {
+    fn from(partial: &PartialArtist) -> Self {
+        LocalArtist {
+            common: partial.common.clone(),
+        }
+    }
+}
+
 impl From<ArtistObject> for LocalArtist {
     fn from(obj: ArtistObject) -> Self {
         LocalArtist { common: obj.common }
@@ -423,10 +631,58 @@ impl From<LocalArtist> for ArtistObject {
     }
 }
 
+impl crate::private::Sealed for Artist {}
 impl crate::private::Sealed for FullArtist {}
 impl crate::private::Sealed for PartialArtist {}
 impl crate::private::Sealed for LocalArtist {}
 
+impl fmt::Display for Artist {
+    /// Formats the artist's name. For a full artist, the alternate form (`{:#}`) also includes its primary genre. See
+    /// the [Display implementation of FullArtist](FullArtist#impl-Display-for-FullArtist).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Artist::Full(full) => full.fmt(f),
+            Artist::Partial(partial) => partial.fmt(f),
+            Artist::Local(local) => local.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for FullArtist {
+    /// Formats the artist's name. The alternate form (`{:#}`) also includes the artist's primary genre in parentheses,
+    /// such as `Hatsune Miku (j-pop)`, if the artist has any genres.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.genres().first() {
+            Some(genre) if f.alternate() => write!(f, "{} ({genre})", self.name()),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+impl fmt::Display for PartialArtist {
+    /// Formats the artist's name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for LocalArtist {
+    /// Formats the artist's name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl private::CommonFields for Artist {
+    fn common_fields(&self) -> &CommonArtistFields {
+        match self {
+            Artist::Full(full) => &full.common,
+            Artist::Partial(partial) => &partial.common,
+            Artist::Local(local) => &local.common,
+        }
+    }
+}
+
 impl private::CommonFields for FullArtist {
     fn common_fields(&self) -> &CommonArtistFields {
         &self.common
@@ -520,3 +776,399 @@ impl Serialize for LocalArtist {
 
 // TODO: unit tests for all the various functions here. deserializing, serializing, equality between tracks, conversion
 // between tracks
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    // =============
+    // serialization
+    // =============
+
+    // captured from the artist-endpoint. the href and uri fields aren't part of the model so they're left out of the
+    // round-trip comparison
+    fn full_artist_json() -> Value {
+        json!({
+            "external_urls": {
+                "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+            },
+            "followers": {
+                "href": null,
+                "total": 1224745
+            },
+            "genres": ["j-pop", "vocaloid"],
+            "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+            "id": "6pNgnvzBa6Bthsv8SrZJYl",
+            "images": [
+                {
+                    "height": 640,
+                    "url": "https://i.scdn.co/image/ab6761610000e5eb0a0e9dc7c4b4fb0f06a9f4b5",
+                    "width": 640
+                },
+                {
+                    "height": 160,
+                    "url": "https://i.scdn.co/image/ab6761610000f1780a0e9dc7c4b4fb0f06a9f4b5",
+                    "width": 160
+                }
+            ],
+            "name": "Hatsune Miku",
+            "popularity": 66,
+            "type": "artist",
+            "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+        })
+    }
+
+    fn without_unmodeled_fields(mut value: Value) -> Value {
+        let obj = value.as_object_mut().unwrap();
+        obj.remove("href");
+        obj.remove("uri");
+        value
+    }
+
+    fn partial_artist_json() -> Value {
+        json!({
+            "external_urls": {
+                "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+            },
+            "id": "6pNgnvzBa6Bthsv8SrZJYl",
+            "name": "Hatsune Miku",
+            "type": "artist",
+        })
+    }
+
+    fn local_artist_json() -> Value {
+        json!({
+            "external_urls": {},
+            "name": "Hatsune Miku",
+            "type": "artist",
+        })
+    }
+
+    #[cfg(any(feature = "async", feature = "sync"))]
+    #[test]
+    fn from_slice_matches_from_str() {
+        let json = serde_json::to_vec(&full_artist_json()).unwrap();
+        let from_slice = Artist::from_slice(&json).unwrap();
+        let from_str: Artist = serde_json::from_str(std::str::from_utf8(&json).unwrap()).unwrap();
+
+        assert_eq!(from_slice, from_str);
+        assert!(matches!(from_slice, Artist::Full(_)));
+    }
+
+    #[test]
+    fn full_artist_serialize_round_trip() {
+        let original = full_artist_json();
+        let artist: FullArtist = serde_json::from_value(original.clone()).unwrap();
+        let serialized = serde_json::to_value(&artist).unwrap();
+
+        assert_eq!(serialized, without_unmodeled_fields(original));
+    }
+
+    #[test]
+    fn partial_artist_serialize_round_trip() {
+        let original = partial_artist_json();
+        let artist: PartialArtist = serde_json::from_value(original.clone()).unwrap();
+        let serialized = serde_json::to_value(&artist).unwrap();
+
+        assert_eq!(serialized, original);
+    }
+
+    #[test]
+    fn local_artist_serialize_round_trip() {
+        let original = local_artist_json();
+        let artist: LocalArtist = serde_json::from_value(original.clone()).unwrap();
+        let serialized = serde_json::to_value(&artist).unwrap();
+
+        assert_eq!(serialized, original);
+    }
+
+    #[test]
+    fn full_artist_followers() {
+        let artist: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+        assert_eq!(artist.followers().total(), 1224745);
+        assert_eq!(artist.followers().href(), None);
+    }
+
+    #[test]
+    fn followers_href() {
+        let mut value = full_artist_json();
+        value["followers"]["href"] = json!("https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl/followers");
+
+        let artist: FullArtist = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            artist.followers().href(),
+            Some("https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl/followers")
+        );
+    }
+
+    #[test]
+    fn full_artist_without_followers_defaults_to_zero() {
+        let mut value = full_artist_json();
+        value.as_object_mut().unwrap().remove("followers");
+
+        let artist: FullArtist = serde_json::from_value(value).unwrap();
+        assert_eq!(artist.followers().total(), 0);
+    }
+
+    #[test]
+    fn artist_enum_serializes_as_inner_artist() {
+        let artist: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+        let serialized = serde_json::to_value(Artist::from(artist.clone())).unwrap();
+
+        assert_eq!(serialized, serde_json::to_value(&artist).unwrap());
+    }
+
+    // ==========
+    // conversion
+    // ==========
+
+    fn artist_from_json(value: Value) -> Artist {
+        let obj: ArtistObject = serde_json::from_value(value).unwrap();
+        Artist::try_from(obj).unwrap()
+    }
+
+    #[test]
+    fn artist_object_converts_into_matching_variant() {
+        assert!(matches!(artist_from_json(full_artist_json()), Artist::Full(_)));
+        assert!(matches!(artist_from_json(partial_artist_json()), Artist::Partial(_)));
+        assert!(matches!(artist_from_json(local_artist_json()), Artist::Local(_)));
+    }
+
+    #[test]
+    fn full_artist_try_from_partial_artist_fails() {
+        let err = FullArtist::try_from(artist_from_json(partial_artist_json())).unwrap_err();
+        assert_eq!(err.expected(), ArtistKind::Full);
+        assert_eq!(err.found(), ArtistKind::Partial);
+    }
+
+    #[test]
+    fn full_artist_try_from_local_artist_fails() {
+        let err = FullArtist::try_from(artist_from_json(local_artist_json())).unwrap_err();
+        assert_eq!(err.expected(), ArtistKind::Full);
+        assert_eq!(err.found(), ArtistKind::Local);
+    }
+
+    #[test]
+    fn partial_artist_try_from_local_artist_fails() {
+        let err = PartialArtist::try_from(artist_from_json(local_artist_json())).unwrap_err();
+        assert_eq!(err.expected(), ArtistKind::Partial);
+        assert_eq!(err.found(), ArtistKind::Local);
+    }
+
+    #[test]
+    fn partial_artist_try_from_full_artist_succeeds() {
+        let partial = PartialArtist::try_from(artist_from_json(full_artist_json())).unwrap();
+        assert_eq!(partial.id().as_str(), "6pNgnvzBa6Bthsv8SrZJYl");
+    }
+
+    #[test]
+    fn local_artist_from_any_artist_succeeds() {
+        let local = LocalArtist::from(artist_from_json(full_artist_json()));
+        assert_eq!(local.name(), "Hatsune Miku");
+    }
+
+    #[test]
+    fn partial_artist_from_full_artist() {
+        let full: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+        let partial = PartialArtist::from(full.clone());
+
+        assert_eq!(partial.id(), full.id());
+        assert_eq!(partial.name(), full.name());
+        assert_eq!(partial.external_urls(), full.external_urls());
+        assert_eq!(serde_json::to_value(partial).unwrap(), partial_artist_json());
+    }
+
+    #[test]
+    fn local_artist_from_full_and_partial_artists() {
+        let full: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+        let partial: PartialArtist = serde_json::from_value(partial_artist_json()).unwrap();
+
+        let from_full = LocalArtist::from(full.clone());
+        let from_partial = LocalArtist::from(partial);
+
+        assert_eq!(from_full.name(), full.name());
+        assert_eq!(from_full.external_urls(), full.external_urls());
+        assert_eq!(from_full, from_partial);
+    }
+
+    #[test]
+    fn borrowed_conversions_equal_owned_conversions() {
+        let full: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+        let partial: PartialArtist = serde_json::from_value(partial_artist_json()).unwrap();
+
+        // equality only compares IDs, so compare the serialized artists to compare every field
+        assert_eq!(
+            serde_json::to_value(PartialArtist::from(&full)).unwrap(),
+            serde_json::to_value(PartialArtist::from(full.clone())).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(LocalArtist::from(&full)).unwrap(),
+            serde_json::to_value(LocalArtist::from(full.clone())).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(LocalArtist::from(&partial)).unwrap(),
+            serde_json::to_value(LocalArtist::from(partial.clone())).unwrap()
+        );
+
+        for value in [full_artist_json(), partial_artist_json(), local_artist_json()] {
+            let artist = artist_from_json(value);
+
+            assert_eq!(
+                serde_json::to_value(LocalArtist::from(&artist)).unwrap(),
+                serde_json::to_value(LocalArtist::from(artist.clone())).unwrap()
+            );
+            assert_eq!(
+                PartialArtist::try_from(&artist).map(|partial| serde_json::to_value(partial).unwrap()),
+                PartialArtist::try_from(artist).map(|partial| serde_json::to_value(partial).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn full_artist_try_from_partial_artist_object_fails() {
+        let obj: ArtistObject = serde_json::from_value(partial_artist_json()).unwrap();
+        let err = FullArtist::try_from(obj).unwrap_err();
+        assert_eq!(err.expected(), ArtistKind::Full);
+        assert_eq!(err.found(), ArtistKind::Partial);
+
+        let obj: ArtistObject = serde_json::from_value(local_artist_json()).unwrap();
+        assert_eq!(FullArtist::try_from(obj).unwrap_err().found(), ArtistKind::Local);
+    }
+
+    #[test]
+    fn partial_artist_try_from_local_artist_object_fails() {
+        let obj: ArtistObject = serde_json::from_value(local_artist_json()).unwrap();
+        let err = PartialArtist::try_from(obj).unwrap_err();
+        assert_eq!(err.expected(), ArtistKind::Partial);
+        assert_eq!(err.found(), ArtistKind::Local);
+    }
+
+    #[test]
+    fn artist_kind_predicates_and_accessors() {
+        let full: Artist = serde_json::from_value(full_artist_json()).unwrap();
+        let partial: Artist = serde_json::from_value(partial_artist_json()).unwrap();
+        let local: Artist = serde_json::from_value(local_artist_json()).unwrap();
+
+        assert_eq!(
+            [full.kind(), partial.kind(), local.kind()],
+            [ArtistKind::Full, ArtistKind::Partial, ArtistKind::Local]
+        );
+        assert!(full.is_full() && !full.is_partial() && !full.is_local());
+        assert!(!partial.is_full() && partial.is_partial() && !partial.is_local());
+        assert!(!local.is_full() && !local.is_partial() && local.is_local());
+
+        assert_eq!(full.as_full().unwrap().popularity(), 66);
+        assert!(full.as_partial().is_none());
+        assert!(full.as_local().is_none());
+
+        assert_eq!(partial.as_partial().unwrap().id().as_str(), "6pNgnvzBa6Bthsv8SrZJYl");
+        assert!(partial.as_full().is_none());
+        assert!(partial.as_local().is_none());
+
+        assert_eq!(local.as_local().unwrap().name(), "Hatsune Miku");
+        assert!(local.as_full().is_none());
+        assert!(local.as_partial().is_none());
+    }
+
+    // full artist fields without an ID can't be any kind of artist, and must fail to deserialize instead of panicking
+    #[test]
+    fn full_artist_fields_without_id_fail_to_deserialize() {
+        let mut value = full_artist_json();
+        value.as_object_mut().unwrap().remove("id");
+
+        let obj: ArtistObject = serde_json::from_value(value.clone()).unwrap();
+        let err = Artist::try_from(obj).unwrap_err();
+        assert!(err.0.contains("impossible case"), "{err}");
+
+        let err = serde_json::from_value::<Artist>(value).unwrap_err();
+        assert!(err.to_string().contains("impossible case"), "{err}");
+    }
+
+    // =======
+    // display
+    // =======
+
+    #[test]
+    fn full_artist_display() {
+        let artist: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+
+        assert_eq!(artist.to_string(), "Hatsune Miku");
+        assert_eq!(format!("{artist:#}"), "Hatsune Miku (j-pop)");
+    }
+
+    #[test]
+    fn full_artist_without_genres_display() {
+        let mut value = full_artist_json();
+        value["genres"] = json!([]);
+
+        let artist: FullArtist = serde_json::from_value(value).unwrap();
+        assert_eq!(format!("{artist:#}"), "Hatsune Miku");
+    }
+
+    #[test]
+    fn partial_and_local_artist_display() {
+        let partial: PartialArtist = serde_json::from_value(partial_artist_json()).unwrap();
+        let local: LocalArtist = serde_json::from_value(local_artist_json()).unwrap();
+
+        assert_eq!(partial.to_string(), "Hatsune Miku");
+        assert_eq!(format!("{local:#}"), "Hatsune Miku");
+    }
+
+    #[test]
+    fn artist_enum_displays_as_inner_artist() {
+        assert_eq!(
+            format!("{:#}", artist_from_json(full_artist_json())),
+            "Hatsune Miku (j-pop)"
+        );
+        assert_eq!(artist_from_json(partial_artist_json()).to_string(), "Hatsune Miku");
+        assert_eq!(artist_from_json(local_artist_json()).to_string(), "Hatsune Miku");
+    }
+
+    // ========
+    // equality
+    // ========
+
+    #[test]
+    fn full_artists_with_same_id_are_equal() {
+        let artist: FullArtist = serde_json::from_value(full_artist_json()).unwrap();
+
+        let mut more_popular = full_artist_json();
+        more_popular["popularity"] = json!(90);
+        let more_popular: FullArtist = serde_json::from_value(more_popular).unwrap();
+
+        assert_eq!(artist, more_popular);
+        assert!(Artist::from(artist.clone()).id_eq(&Artist::from(more_popular.clone())));
+        assert_eq!(HashSet::from([artist, more_popular]).len(), 1);
+    }
+
+    #[test]
+    fn full_and_partial_artist_with_same_id_are_id_equal() {
+        let full = artist_from_json(full_artist_json());
+        let partial = artist_from_json(partial_artist_json());
+
+        assert_ne!(full, partial);
+        assert!(full.id_eq(&partial));
+        assert_eq!(HashSet::from([full, partial]).len(), 2);
+    }
+
+    #[test]
+    fn local_artists_are_id_equal_by_name() {
+        let local = artist_from_json(local_artist_json());
+
+        let mut other_urls = local_artist_json();
+        other_urls["external_urls"] = json!({ "spotify": "https://open.spotify.com" });
+        let other_urls = artist_from_json(other_urls);
+
+        let mut renamed = local_artist_json();
+        renamed["name"] = json!("Kagamine Rin");
+        let renamed = artist_from_json(renamed);
+
+        assert!(local.id_eq(&other_urls));
+        assert!(!local.id_eq(&renamed));
+        assert!(!local.id_eq(&artist_from_json(partial_artist_json())));
+    }
+}
@@ -0,0 +1,293 @@
+//! Contains the [AudioAnalysis] object that describes a track's low-level audio analysis, and its related objects.
+//!
+//! All times in the analysis are in seconds, and all confidence values are floating point numbers from 0.0 to 1.0.
+
+use serde::{Deserialize, Serialize};
+
+use super::audio_features::Mode;
+
+/// Low-level audio analysis for a single track, describing the track's structure and musical content, including rhythm,
+/// pitch and timbre.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioAnalysis {
+    /// Summary of the whole track.
+    pub track: AnalysisTrack,
+    /// The bars in the track. A bar (or measure) is a segment of time defined as a given number of beats.
+    pub bars: Vec<TimeInterval>,
+    /// The beats in the track. A beat is the basic time unit of a piece of music.
+    pub beats: Vec<TimeInterval>,
+    /// The sections in the track. Sections are defined by large variations in rhythm or timbre, e.g. chorus, verse,
+    /// bridge or guitar solo.
+    pub sections: Vec<Section>,
+    /// The segments in the track. Each segment contains a roughly consistent sound throughout its duration.
+    pub segments: Vec<Segment>,
+    /// The tatums in the track. A tatum represents the lowest regular pulse train that a listener intuitively infers
+    /// from the timing of perceived musical events.
+    pub tatums: Vec<TimeInterval>,
+}
+
+/// Summary of a track's audio analysis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisTrack {
+    /// The exact number of audio samples analyzed from the track.
+    pub num_samples: u64,
+    /// The length of the track.
+    pub duration: f32,
+    /// The time at which the track's fade-in period ends.
+    pub end_of_fade_in: f32,
+    /// The time at which the track's fade-out period starts.
+    pub start_of_fade_out: f32,
+    /// The overall loudness of the track in decibels, typically between -60 and 0.
+    pub loudness: f32,
+    /// The overall estimated tempo of the track in beats per minute.
+    pub tempo: f32,
+    /// The confidence of the tempo.
+    pub tempo_confidence: f32,
+    /// An estimated time signature, i.e. how many beats are in each bar. Ranges from 3 to 7.
+    pub time_signature: u8,
+    /// The confidence of the time signature.
+    pub time_signature_confidence: f32,
+    /// The key the track is in, using standard [pitch class notation](https://en.wikipedia.org/wiki/Pitch_class). If
+    /// no key was detected, the value is -1.
+    pub key: i8,
+    /// The confidence of the key.
+    pub key_confidence: f32,
+    /// The modality of the track, if detected.
+    #[serde(with = "optional_mode")]
+    pub mode: Option<Mode>,
+    /// The confidence of the modality.
+    pub mode_confidence: f32,
+}
+
+/// A generic time interval; a bar, a beat or a tatum.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeInterval {
+    /// The starting point of the interval.
+    pub start: f32,
+    /// The duration of the interval.
+    pub duration: f32,
+    /// The confidence of the interval.
+    pub confidence: f32,
+}
+
+/// A section in a track.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Section {
+    /// The starting point of the section.
+    pub start: f32,
+    /// The duration of the section.
+    pub duration: f32,
+    /// The confidence of the section's boundaries.
+    pub confidence: f32,
+    /// The overall loudness of the section in decibels.
+    pub loudness: f32,
+    /// The overall estimated tempo of the section in beats per minute.
+    pub tempo: f32,
+    /// The confidence of the tempo.
+    pub tempo_confidence: f32,
+    /// The estimated key of the section. If no key was detected, the value is -1.
+    pub key: i8,
+    /// The confidence of the key.
+    pub key_confidence: f32,
+    /// The modality of the section, if detected.
+    #[serde(with = "optional_mode")]
+    pub mode: Option<Mode>,
+    /// The confidence of the modality.
+    pub mode_confidence: f32,
+    /// An estimated time signature of the section.
+    pub time_signature: u8,
+    /// The confidence of the time signature.
+    pub time_signature_confidence: f32,
+}
+
+/// A segment in a track.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+    /// The starting point of the segment.
+    pub start: f32,
+    /// The duration of the segment.
+    pub duration: f32,
+    /// The confidence of the segmentation.
+    pub confidence: f32,
+    /// The onset loudness of the segment in decibels.
+    pub loudness_start: f32,
+    /// The peak loudness of the segment in decibels.
+    pub loudness_max: f32,
+    /// The segment-relative offset of the peak loudness.
+    pub loudness_max_time: f32,
+    /// The offset loudness of the segment in decibels. Only included in the last segment of the track.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loudness_end: Option<f32>,
+    /// The relative dominance of each of the 12 pitch classes in the segment's chroma, from C to B. Each value is
+    /// between 0.0 and 1.0.
+    pub pitches: Vec<f32>,
+    /// The segment's timbre as 12 unbounded values roughly centered around 0.
+    pub timbre: Vec<f32>,
+}
+
+/// Spotify represents an undetected modality as -1.
+mod optional_mode {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::model::audio_features::Mode;
+
+    pub(super) fn serialize<S>(mode: &Option<Mode>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        mode.map_or(-1, |mode| u8::from(mode).into()).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Mode>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mode = i8::deserialize(deserializer)?;
+
+        if mode < 0 {
+            Ok(None)
+        } else {
+            Ok(Some(Mode::from(mode as u8)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn audio_analysis_json() -> serde_json::Value {
+        json!({
+            "meta": {
+                "analyzer_version": "4.0.0",
+                "platform": "Linux",
+                "detailed_status": "OK",
+                "status_code": 0,
+                "timestamp": 1495193577,
+                "analysis_time": 6.93906,
+                "input_process": "libvorbisfile L+R 44100->22050"
+            },
+            "track": {
+                "num_samples": 4585515,
+                "duration": 207.95985,
+                "sample_md5": "",
+                "offset_seconds": 0,
+                "window_seconds": 0,
+                "analysis_sample_rate": 22050,
+                "analysis_channels": 1,
+                "end_of_fade_in": 0,
+                "start_of_fade_out": 201.13705,
+                "loudness": -5.883,
+                "tempo": 118.211,
+                "tempo_confidence": 0.73,
+                "time_signature": 4,
+                "time_signature_confidence": 0.994,
+                "key": 9,
+                "key_confidence": 0.408,
+                "mode": 0,
+                "mode_confidence": 0.485,
+                "codestring": "",
+                "code_version": 3.15,
+                "echoprintstring": "",
+                "echoprint_version": 4.15,
+                "synchstring": "",
+                "synch_version": 1,
+                "rhythmstring": "",
+                "rhythm_version": 1
+            },
+            "bars": [
+                { "start": 0.49567, "duration": 2.18749, "confidence": 0.925 }
+            ],
+            "beats": [
+                { "start": 0.49567, "duration": 0.52929, "confidence": 0.872 },
+                { "start": 1.02496, "duration": 0.52929, "confidence": 0.766 }
+            ],
+            "sections": [
+                {
+                    "start": 0,
+                    "duration": 6.97092,
+                    "confidence": 1,
+                    "loudness": -14.938,
+                    "tempo": 113.178,
+                    "tempo_confidence": 0.647,
+                    "key": 9,
+                    "key_confidence": 0.297,
+                    "mode": -1,
+                    "mode_confidence": 0.471,
+                    "time_signature": 4,
+                    "time_signature_confidence": 1
+                }
+            ],
+            "segments": [
+                {
+                    "start": 0.70154,
+                    "duration": 0.19891,
+                    "confidence": 0.435,
+                    "loudness_start": -23.053,
+                    "loudness_max_time": 0.07305,
+                    "loudness_max": -14.25,
+                    "loudness_end": 0,
+                    "pitches": [0.212, 0.141, 0.294, 0.224, 0.266, 0.206, 0.15, 0.204, 0.272, 1, 0.369, 0.278],
+                    "timbre": [42.115, 64.373, -0.233, -14.141, 1.137, 0.276, 9.374, -12.315, 1.962, -5.765, -8.93, 3.114]
+                },
+                {
+                    "start": 0.90045,
+                    "duration": 0.2122,
+                    "confidence": 0.925,
+                    "loudness_start": -17.454,
+                    "loudness_max_time": 0.04544,
+                    "loudness_max": -8.316,
+                    "pitches": [0.135, 0.125, 0.229, 0.153, 0.257, 1, 0.262, 0.115, 0.13, 0.161, 0.238, 0.174],
+                    "timbre": [48.547, 43.645, 15.061, 10.283, 16.084, -35.617, 8.102, 1.862, -0.458, -7.462, 12.052, 6.291]
+                }
+            ],
+            "tatums": [
+                { "start": 0.49567, "duration": 0.26465, "confidence": 0.677 }
+            ]
+        })
+    }
+
+    #[test]
+    fn audio_analysis() {
+        let analysis: AudioAnalysis = serde_json::from_value(audio_analysis_json()).unwrap();
+
+        assert_eq!(analysis.track.num_samples, 4585515);
+        assert_eq!(analysis.track.mode, Some(Mode::Minor));
+        assert_eq!(analysis.track.time_signature, 4);
+
+        assert_eq!(analysis.bars.len(), 1);
+        assert_eq!(analysis.beats.len(), 2);
+        assert_eq!(analysis.tatums.len(), 1);
+        assert_eq!(
+            analysis.beats[1],
+            TimeInterval {
+                start: 1.02496,
+                duration: 0.52929,
+                confidence: 0.766
+            }
+        );
+
+        assert_eq!(analysis.sections.len(), 1);
+        assert_eq!(analysis.sections[0].mode, None);
+
+        assert_eq!(analysis.segments.len(), 2);
+        assert_eq!(analysis.segments[0].loudness_end, Some(0.0));
+        assert_eq!(analysis.segments[1].loudness_end, None);
+
+        for segment in &analysis.segments {
+            assert_eq!(segment.pitches.len(), 12);
+            assert_eq!(segment.timbre.len(), 12);
+        }
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let analysis: AudioAnalysis = serde_json::from_value(audio_analysis_json()).unwrap();
+        let serialized = serde_json::to_value(&analysis).unwrap();
+
+        assert_eq!(serialized["sections"][0]["mode"], -1);
+        assert_eq!(serde_json::from_value::<AudioAnalysis>(serialized).unwrap(), analysis);
+    }
+}
@@ -0,0 +1,140 @@
+//! Contains the [AudioFeatures] object that describes a track's audio features, and its related [Mode] enum.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::id::{Id, TrackId};
+use crate::util::duration_millis;
+
+/// Audio feature information for a single track.
+///
+/// The various confidence measures and perceptual measures are floating point numbers from 0.0 to 1.0.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioFeatures {
+    /// The Spotify ID of the track.
+    pub id: Id<'static, TrackId>,
+    /// A confidence measure of whether the track is acoustic.
+    pub acousticness: f32,
+    /// How suitable the track is for dancing based on a combination of musical elements including tempo, rhythm
+    /// stability, beat strength, and overall regularity.
+    pub danceability: f32,
+    /// The duration of the track.
+    #[serde(rename = "duration_ms", with = "duration_millis")]
+    pub duration: Duration,
+    /// A perceptual measure of intensity and activity.
+    pub energy: f32,
+    /// Predicts whether the track contains no vocals.
+    pub instrumentalness: f32,
+    /// The key the track is in, using standard [pitch class notation](https://en.wikipedia.org/wiki/Pitch_class).
+    /// E.g. 0 = C, 1 = C♯/D♭, 2 = D, and so on. If no key was detected, the value is -1.
+    pub key: i8,
+    /// Detects the presence of an audience in the recording.
+    pub liveness: f32,
+    /// The overall loudness of the track in decibels, typically between -60 and 0.
+    pub loudness: f32,
+    /// The modality of the track.
+    pub mode: Mode,
+    /// Detects the presence of spoken words in the track.
+    pub speechiness: f32,
+    /// The overall estimated tempo of the track in beats per minute.
+    pub tempo: f32,
+    /// An estimated time signature, i.e. how many beats are in each bar. Ranges from 3 to 7.
+    pub time_signature: u8,
+    /// The musical positiveness conveyed by the track.
+    pub valence: f32,
+}
+
+/// The modality of a track; the type of scale from which its melodic content is derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum Mode {
+    /// Minor modality.
+    Minor,
+    /// Major modality.
+    Major,
+    /// A modality this library doesn't know of, with the value Spotify returned for it.
+    Unknown(u8),
+}
+
+impl From<u8> for Mode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Minor,
+            1 => Self::Major,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Minor => 0,
+            Mode::Major => 1,
+            Mode::Unknown(value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::id::IdTrait;
+
+    fn audio_features_json(id: &str) -> serde_json::Value {
+        json!({
+            "acousticness": 0.00242,
+            "analysis_url": format!("https://api.spotify.com/v1/audio-analysis/{id}"),
+            "danceability": 0.585,
+            "duration_ms": 237040,
+            "energy": 0.842,
+            "id": id,
+            "instrumentalness": 0.00686,
+            "key": 9,
+            "liveness": 0.0866,
+            "loudness": -5.883,
+            "mode": 0,
+            "speechiness": 0.0556,
+            "tempo": 118.211,
+            "time_signature": 4,
+            "track_href": format!("https://api.spotify.com/v1/tracks/{id}"),
+            "type": "audio_features",
+            "uri": format!("spotify:track:{id}"),
+            "valence": 0.428
+        })
+    }
+
+    #[test]
+    fn audio_features() {
+        let features: AudioFeatures = serde_json::from_value(audio_features_json("2takcwOaAZWiXQijPHIx7B")).unwrap();
+
+        assert_eq!(features.id.as_str(), "2takcwOaAZWiXQijPHIx7B");
+        assert_eq!(features.duration, Duration::from_millis(237040));
+        assert_eq!(features.key, 9);
+        assert_eq!(features.mode, Mode::Minor);
+        assert_eq!(features.time_signature, 4);
+        assert_eq!(features.tempo, 118.211);
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let features: AudioFeatures = serde_json::from_value(audio_features_json("2takcwOaAZWiXQijPHIx7B")).unwrap();
+        let serialized = serde_json::to_value(&features).unwrap();
+
+        assert_eq!(serialized["mode"], 0);
+        assert_eq!(serde_json::from_value::<AudioFeatures>(serialized).unwrap(), features);
+    }
+
+    #[test]
+    fn unknown_mode() {
+        let mut features = audio_features_json("2takcwOaAZWiXQijPHIx7B");
+        features["mode"] = json!(2);
+
+        let features: AudioFeatures = serde_json::from_value(features).unwrap();
+        assert_eq!(features.mode, Mode::Unknown(2));
+        assert_eq!(serde_json::to_value(&features).unwrap()["mode"], 2);
+    }
+}
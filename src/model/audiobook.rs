@@ -0,0 +1,403 @@
+//! Everything related to audiobooks.
+//!
+//! Contains the two different kinds of audiobooks; [FullAudiobook] and [PartialAudiobook].
+//!
+//! - [FullAudiobook]: contains all information about an audiobook, including the first page of its chapters. Retrieved
+//!   from the [audiobook](crate::client::UnscopedClient::audiobook)- and
+//!   [audiobooks](crate::client::UnscopedClient::audiobooks)-functions.
+//! - [PartialAudiobook]: contains most information about an audiobook, but not its chapters. Retrieved as part of a
+//!   [full chapter](crate::model::chapter::FullChapter).
+//!
+//! Audiobooks are available only in certain markets. Requesting an audiobook that isn't available in the requested
+//! market, or in the user's market when the request is made with a user authenticated client, fails as if the audiobook
+//! didn't exist.
+//!
+//! # Audiobook equality
+//!
+//! Two audiobooks are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use std::collections::HashSet;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::model::{
+        audiobook::Person,
+        id::{AudiobookId, Id},
+        object_type::{object_type_serialize, TypeAudiobook},
+        show::MediaType,
+        Copyright, CountryCode, ExternalUrls, Image,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonAudiobookFields;
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonAudiobookFields {
+        pub(crate) name: String,
+        pub(crate) id: Id<'static, AudiobookId>,
+        pub(crate) authors: Vec<Person>,
+        pub(crate) narrators: Vec<Person>,
+        pub(crate) publisher: String,
+        pub(crate) description: String,
+        #[serde(default)]
+        pub(crate) html_description: String,
+        pub(crate) edition: Option<String>,
+        pub(crate) explicit: bool,
+        pub(crate) languages: Vec<String>,
+        pub(crate) media_type: MediaType,
+        pub(crate) images: Vec<Image>,
+        #[serde(default)]
+        pub(crate) total_chapters: u32,
+        #[serde(default)]
+        pub(crate) copyrights: Vec<Copyright>,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(default)]
+        pub(crate) available_markets: HashSet<CountryCode>,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypeAudiobook,
+    }
+}
+
+use std::{collections::HashSet, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
+
+use self::private::CommonAudiobookFields;
+use super::{
+    chapter::PartialChapter,
+    id::{AudiobookId, Id, IdTrait},
+    page::{Page, PageInformation, PageObject},
+    show::MediaType,
+    Copyright, CountryCode, ExternalUrls, Image,
+};
+
+/// Functions for retrieving information that is common to every audiobook type.
+pub trait CommonAudiobookInformation: crate::private::Sealed {
+    /// The audiobook's name.
+    fn name(&self) -> &str;
+    /// The audiobook's Spotify ID.
+    fn id(&self) -> Id<'_, AudiobookId>;
+    /// The audiobook's authors.
+    fn authors(&self) -> &[Person];
+    /// The audiobook's narrators.
+    fn narrators(&self) -> &[Person];
+    /// The audiobook's publisher.
+    fn publisher(&self) -> &str;
+    /// The audiobook's description without HTML tags.
+    fn description(&self) -> &str;
+    /// The audiobook's description, which may contain HTML tags.
+    fn html_description(&self) -> &str;
+    /// The audiobook's edition, such as `Unabridged`, if known.
+    fn edition(&self) -> Option<&str>;
+    /// Whether the audiobook has explicit content.
+    fn explicit(&self) -> bool;
+    /// The languages used in the audiobook, as ISO 639 codes.
+    fn languages(&self) -> &[String];
+    /// The media type of the audiobook's chapters.
+    fn media_type(&self) -> MediaType;
+    /// The images for the audiobook.
+    fn images(&self) -> &[Image];
+    /// The total amount of chapters in the audiobook.
+    fn total_chapters(&self) -> u32;
+    /// The audiobook's copyrights.
+    fn copyrights(&self) -> &[Copyright];
+    /// The external URLs for the audiobook.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The countries the audiobook is available in.
+    fn available_markets(&self) -> &HashSet<CountryCode>;
+}
+
+impl<T> CommonAudiobookInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn id(&self) -> Id<'_, AudiobookId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn authors(&self) -> &[Person] {
+        &self.common_fields().authors
+    }
+
+    fn narrators(&self) -> &[Person] {
+        &self.common_fields().narrators
+    }
+
+    fn publisher(&self) -> &str {
+        &self.common_fields().publisher
+    }
+
+    fn description(&self) -> &str {
+        &self.common_fields().description
+    }
+
+    fn html_description(&self) -> &str {
+        &self.common_fields().html_description
+    }
+
+    fn edition(&self) -> Option<&str> {
+        self.common_fields().edition.as_deref()
+    }
+
+    fn explicit(&self) -> bool {
+        self.common_fields().explicit
+    }
+
+    fn languages(&self) -> &[String] {
+        &self.common_fields().languages
+    }
+
+    fn media_type(&self) -> MediaType {
+        self.common_fields().media_type
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn total_chapters(&self) -> u32 {
+        self.common_fields().total_chapters
+    }
+
+    fn copyrights(&self) -> &[Copyright] {
+        &self.common_fields().copyrights
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn available_markets(&self) -> &HashSet<CountryCode> {
+        &self.common_fields().available_markets
+    }
+}
+
+/// An author or a narrator of an audiobook.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Person {
+    /// The person's name.
+    pub name: String,
+}
+
+/// A full audiobook. Contains all [common](self::CommonAudiobookInformation) information about an audiobook, in
+/// addition to the first page of its chapters.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct FullAudiobook {
+    #[serde(flatten)]
+    common: CommonAudiobookFields,
+    chapters: AudiobookChapters,
+}
+
+/// A partial audiobook. Contains all [common](self::CommonAudiobookInformation) information about an audiobook.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct PartialAudiobook {
+    #[serde(flatten)]
+    common: CommonAudiobookFields,
+}
+
+/// A page of an audiobook's chapters.
+///
+/// This object is retrieved only through the [chapters](FullAudiobook::chapters)-function. You won't be interacting
+/// objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct AudiobookChapters {
+    #[serde(flatten)]
+    page: PageObject<PartialChapter>,
+}
+
+impl FullAudiobook {
+    /// The first page of the audiobook's chapters.
+    pub fn chapters(&self) -> Page<AudiobookChapters, PartialChapter> {
+        Page {
+            inner: self.chapters.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl PartialEq for FullAudiobook {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialAudiobook {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialAudiobook> for FullAudiobook {
+    fn eq(&self, other: &PartialAudiobook) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullAudiobook> for PartialAudiobook {
+    fn eq(&self, other: &FullAudiobook) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl From<FullAudiobook> for PartialAudiobook {
+    fn from(full: FullAudiobook) -> Self {
+        PartialAudiobook { common: full.common }
+    }
+}
+
+impl crate::private::Sealed for FullAudiobook {}
+impl crate::private::Sealed for PartialAudiobook {}
+
+impl private::CommonFields for FullAudiobook {
+    fn common_fields(&self) -> &CommonAudiobookFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialAudiobook {
+    fn common_fields(&self) -> &CommonAudiobookFields {
+        &self.common
+    }
+}
+
+impl From<AudiobookChapters> for Page<AudiobookChapters, PartialChapter> {
+    fn from(page: AudiobookChapters) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for AudiobookChapters {}
+
+impl PageInformation<PartialChapter> for AudiobookChapters {
+    type Items = Vec<PartialChapter>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PartialChapter> as PageInformation<PartialChapter>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PartialChapter> as PageInformation<PartialChapter>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PartialChapter> as PageInformation<PartialChapter>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PartialChapter> as PageInformation<PartialChapter>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<PartialChapter> as PageInformation<PartialChapter>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<PartialChapter> as PageInformation<PartialChapter>>::has_previous(&self.page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::chapter::CommonChapterInformation;
+
+    #[test]
+    fn full_audiobook_with_chapters() {
+        let audiobook: FullAudiobook = serde_json::from_value(json!({
+            "authors": [{ "name": "Jane Austen" }],
+            "available_markets": ["GB", "US"],
+            "chapters": {
+                "href": "https://api.spotify.com/v1/audiobooks/7iHfbu1YPACw6oZPAFJtqe/chapters?offset=0&limit=50",
+                "items": [
+                    {
+                        "audio_preview_url": null,
+                        "available_markets": ["GB", "US"],
+                        "chapter_number": 0,
+                        "description": "Opening credits.",
+                        "duration_ms": 18000,
+                        "explicit": false,
+                        "external_urls": {},
+                        "html_description": "<p>Opening credits.</p>",
+                        "id": "0D5wENdkdwbqlrHoaJ9g29",
+                        "images": [],
+                        "is_playable": true,
+                        "languages": ["en"],
+                        "name": "Opening Credits",
+                        "release_date": "2012-01-01",
+                        "release_date_precision": "day",
+                        "type": "episode",
+                        "uri": "spotify:episode:0D5wENdkdwbqlrHoaJ9g29"
+                    }
+                ],
+                "limit": 50,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 1
+            },
+            "copyrights": [],
+            "description": "A classic novel of manners.",
+            "edition": "Unabridged",
+            "explicit": false,
+            "external_urls": { "spotify": "https://open.spotify.com/show/7iHfbu1YPACw6oZPAFJtqe" },
+            "href": "https://api.spotify.com/v1/audiobooks/7iHfbu1YPACw6oZPAFJtqe",
+            "html_description": "<p>A classic novel of manners.</p>",
+            "id": "7iHfbu1YPACw6oZPAFJtqe",
+            "images": [],
+            "languages": ["en"],
+            "media_type": "audio",
+            "name": "Pride and Prejudice",
+            "narrators": [{ "name": "Rosamund Pike" }],
+            "publisher": "Audible Studios",
+            "total_chapters": 1,
+            "type": "audiobook",
+            "uri": "spotify:show:7iHfbu1YPACw6oZPAFJtqe"
+        }))
+        .unwrap();
+
+        assert_eq!(audiobook.name(), "Pride and Prejudice");
+        assert_eq!(audiobook.authors()[0].name, "Jane Austen");
+        assert_eq!(audiobook.narrators()[0].name, "Rosamund Pike");
+        assert_eq!(audiobook.publisher(), "Audible Studios");
+        assert_eq!(audiobook.edition(), Some("Unabridged"));
+        assert_eq!(audiobook.media_type(), MediaType::Audio);
+
+        // audiobooks are available only in certain markets
+        assert!(audiobook.available_markets().contains(&CountryCode::GB));
+        assert!(!audiobook.available_markets().contains(&CountryCode::FI));
+
+        let chapters = audiobook.chapters();
+        assert_eq!(chapters.total(), 1);
+
+        let chapter = &chapters.items()[0];
+        assert_eq!(chapter.id().as_str(), "0D5wENdkdwbqlrHoaJ9g29");
+        assert_eq!(chapter.chapter_number(), 0);
+        assert_eq!(chapter.duration(), Duration::from_secs(18));
+        assert_eq!(chapter.audio_preview_url(), None);
+
+        assert_eq!(PartialAudiobook::from(audiobook.clone()), audiobook);
+    }
+}
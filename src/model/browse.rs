@@ -0,0 +1,415 @@
+//! Everything related to browsing Spotify's catalog, such as new album releases, featured playlists and categories.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    album::{AlbumObject, PartialAlbum},
+    page::{Page, PageInformation, PageObject},
+    playlist::PartialPlaylist,
+    Image,
+};
+
+/// A category used to tag items in Spotify, such as "Mood" or "Top Lists".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Category {
+    /// The category's ID, such as `toplists`.
+    pub id: String,
+    /// The category's name.
+    pub name: String,
+    /// The category's icon in various sizes.
+    pub icons: Vec<Image>,
+}
+
+/// A page of new album releases.
+///
+/// This object is retrieved only through the [new_releases](crate::client::UnscopedClient::new_releases)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct NewReleases {
+    albums: PageObject<AlbumObject>,
+}
+
+/// A page of categories.
+///
+/// This object is retrieved only through the [categories](crate::client::UnscopedClient::categories)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct Categories {
+    categories: PageObject<Category>,
+}
+
+/// A page of playlists returned from one of the browse endpoints.
+///
+/// This object is retrieved only through the [featured_playlists](crate::client::UnscopedClient::featured_playlists)-
+/// and [category_playlists](crate::client::UnscopedClient::category_playlists)-functions. You won't be interacting
+/// objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct BrowsePlaylists {
+    playlists: PageObject<PartialPlaylist>,
+}
+
+/// Playlists featured by Spotify, along with a message describing them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeaturedPlaylists {
+    message: Option<String>,
+    #[serde(flatten)]
+    playlists: BrowsePlaylists,
+}
+
+impl FeaturedPlaylists {
+    /// The message describing the featured playlists, such as "Monday morning music, coming right up!".
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The first page of the featured playlists.
+    pub fn playlists(&self) -> Page<BrowsePlaylists, PartialPlaylist> {
+        Page {
+            inner: self.playlists.clone(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// The first page of the featured playlists. Take ownership of the page.
+    pub fn take_playlists(self) -> Page<BrowsePlaylists, PartialPlaylist> {
+        Page {
+            inner: self.playlists,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl From<NewReleases> for Page<NewReleases, PartialAlbum> {
+    fn from(page: NewReleases) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for NewReleases {}
+
+impl PageInformation<PartialAlbum> for NewReleases {
+    type Items = Vec<PartialAlbum>;
+
+    fn items(&self) -> Self::Items {
+        self.albums.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.albums.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::next(self.albums)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::total(&self.albums)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::limit(&self.albums)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::offset(&self.albums)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::has_next(&self.albums)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::has_previous(&self.albums)
+    }
+}
+
+impl From<BrowsePlaylists> for Page<BrowsePlaylists, PartialPlaylist> {
+    fn from(page: BrowsePlaylists) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for BrowsePlaylists {}
+
+impl PageInformation<PartialPlaylist> for BrowsePlaylists {
+    type Items = Vec<PartialPlaylist>;
+
+    fn items(&self) -> Self::Items {
+        self.playlists.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.playlists.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::next(self.playlists)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::total(&self.playlists)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::limit(&self.playlists)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::offset(&self.playlists)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::has_next(&self.playlists)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::has_previous(&self.playlists)
+    }
+}
+
+impl From<Categories> for Page<Categories, Category> {
+    fn from(page: Categories) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for Categories {}
+
+impl PageInformation<Category> for Categories {
+    type Items = Vec<Category>;
+
+    fn items(&self) -> Self::Items {
+        self.categories.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.categories.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<Category> as PageInformation<Category>>::next(self.categories)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<Category> as PageInformation<Category>>::total(&self.categories)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<Category> as PageInformation<Category>>::limit(&self.categories)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<Category> as PageInformation<Category>>::offset(&self.categories)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<Category> as PageInformation<Category>>::has_next(&self.categories)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<Category> as PageInformation<Category>>::has_previous(&self.categories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::{album::CommonAlbumInformation, playlist::CommonPlaylistInformation};
+
+    #[test]
+    fn new_releases_page() {
+        let page: NewReleases = serde_json::from_value(json!({
+            "albums": {
+                "href": "https://api.spotify.com/v1/browse/new-releases?offset=0&limit=1",
+                "items": [
+                    {
+                        "album_type": "single",
+                        "artists": [],
+                        "available_markets": ["FI", "JP"],
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                        "images": [],
+                        "name": "World is Mine",
+                        "release_date": "2008-05-31",
+                        "release_date_precision": "day",
+                        "total_tracks": 1,
+                        "type": "album",
+                        "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+                    }
+                ],
+                "limit": 1,
+                "next": "https://api.spotify.com/v1/browse/new-releases?offset=1&limit=1",
+                "offset": 0,
+                "previous": null,
+                "total": 100
+            }
+        }))
+        .unwrap();
+
+        let page = Page::from(page);
+
+        assert_eq!(page.total(), 100);
+        assert_eq!(page.next_offset(), Some(1));
+        assert_eq!(page.items()[0].name(), "World is Mine");
+    }
+
+    #[test]
+    fn featured_playlists() {
+        let featured: FeaturedPlaylists = serde_json::from_value(json!({
+            "message": "Monday morning music, coming right up!",
+            "playlists": {
+                "href": "https://api.spotify.com/v1/browse/featured-playlists?offset=0&limit=1",
+                "items": [
+                    {
+                        "collaborative": false,
+                        "description": "The best of vocaloid.",
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DX6R7QUWePReA",
+                        "id": "37i9dQZF1DX6R7QUWePReA",
+                        "images": [],
+                        "name": "Vocaloid Hits",
+                        "owner": {
+                            "display_name": "Spotify",
+                            "external_urls": {},
+                            "href": "https://api.spotify.com/v1/users/spotify",
+                            "id": "spotify",
+                            "type": "user",
+                            "uri": "spotify:user:spotify"
+                        },
+                        "public": null,
+                        "snapshot_id": "MTY3ODg5NjAwMCwwMDAwMDAwMGQ0MWQ4Y2Q5OGYwMGIyMDRlOTgwMDk5OGVjZjg0Mjdl",
+                        "tracks": {
+                            "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DX6R7QUWePReA/tracks",
+                            "total": 50
+                        },
+                        "type": "playlist",
+                        "uri": "spotify:playlist:37i9dQZF1DX6R7QUWePReA"
+                    }
+                ],
+                "limit": 1,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 1
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(featured.message(), Some("Monday morning music, coming right up!"));
+
+        let playlists = featured.take_playlists();
+        assert_eq!(playlists.total(), 1);
+        assert_eq!(playlists.next_offset(), None);
+        assert_eq!(playlists.items()[0].name(), "Vocaloid Hits");
+    }
+
+    #[test]
+    fn categories_page() {
+        let page: Categories = serde_json::from_value(json!({
+            "categories": {
+                "href": "https://api.spotify.com/v1/browse/categories?offset=0&limit=2",
+                "items": [
+                    {
+                        "href": "https://api.spotify.com/v1/browse/categories/toplists",
+                        "icons": [
+                            {
+                                "height": 275,
+                                "url": "https://t.scdn.co/media/derived/toplists_11160599e6a04ac5d6f2757f5511778f_0_0_275_275.jpg",
+                                "width": 275
+                            }
+                        ],
+                        "id": "toplists",
+                        "name": "Top Lists"
+                    },
+                    {
+                        "href": "https://api.spotify.com/v1/browse/categories/anime",
+                        "icons": [],
+                        "id": "anime",
+                        "name": "Anime"
+                    }
+                ],
+                "limit": 2,
+                "next": "https://api.spotify.com/v1/browse/categories?offset=2&limit=2",
+                "offset": 0,
+                "previous": null,
+                "total": 50
+            }
+        }))
+        .unwrap();
+
+        let page = Page::from(page);
+        let categories = page.items();
+
+        assert_eq!(page.total(), 50);
+        assert_eq!(page.next_offset(), Some(2));
+        assert_eq!(categories[0].id, "toplists");
+        assert_eq!(categories[0].name, "Top Lists");
+        assert_eq!(categories[0].icons[0].dimensions.as_ref().unwrap().width, 275);
+        assert!(categories[1].icons.is_empty());
+    }
+
+    #[test]
+    fn category_playlists_page() {
+        let page: BrowsePlaylists = serde_json::from_value(json!({
+            "playlists": {
+                "href": "https://api.spotify.com/v1/browse/categories/anime/playlists?offset=0&limit=1",
+                "items": [
+                    {
+                        "collaborative": false,
+                        "description": "The biggest anime songs.",
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DX6XceWZP1znY",
+                        "id": "37i9dQZF1DX6XceWZP1znY",
+                        "images": [],
+                        "name": "Anime Now",
+                        "owner": {
+                            "display_name": "Spotify",
+                            "external_urls": {},
+                            "href": "https://api.spotify.com/v1/users/spotify",
+                            "id": "spotify",
+                            "type": "user",
+                            "uri": "spotify:user:spotify"
+                        },
+                        "public": null,
+                        "snapshot_id": "MTY3ODg5NjAwMCwwMDAwMDAwMGQ0MWQ4Y2Q5OGYwMGIyMDRlOTgwMDk5OGVjZjg0Mjdl",
+                        "tracks": {
+                            "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DX6XceWZP1znY/tracks",
+                            "total": 100
+                        },
+                        "type": "playlist",
+                        "uri": "spotify:playlist:37i9dQZF1DX6XceWZP1znY"
+                    }
+                ],
+                "limit": 1,
+                "next": "https://api.spotify.com/v1/browse/categories/anime/playlists?offset=1&limit=1",
+                "offset": 0,
+                "previous": null,
+                "total": 12
+            }
+        }))
+        .unwrap();
+
+        let page = Page::from(page);
+
+        assert_eq!(page.total(), 12);
+        assert_eq!(page.next_offset(), Some(1));
+        assert_eq!(page.items()[0].name(), "Anime Now");
+    }
+}
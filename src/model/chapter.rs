@@ -0,0 +1,436 @@
+//! Everything related to chapters of audiobooks.
+//!
+//! Contains the two different kinds of chapters; [FullChapter] and [PartialChapter].
+//!
+//! - [FullChapter]: contains all information about a chapter, including the audiobook it belongs to. Retrieved from the
+//!   [chapter](crate::client::UnscopedClient::chapter)-function.
+//! - [PartialChapter]: contains all information about a chapter except the audiobook it belongs to. Retrieved as part
+//!   of an [audiobook's chapters](crate::model::audiobook::FullAudiobook::chapters).
+//!
+//! Like audiobooks, chapters are available only in certain markets.
+//!
+//! # Chapter equality
+//!
+//! Two chapters are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use std::{collections::HashSet, time::Duration};
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        model::{
+            audiobook::PartialAudiobook,
+            episode::ResumePoint,
+            id::{ChapterId, Id},
+            object_type::{object_type_serialize, TypeEpisode},
+            CountryCode, ExternalUrls, Image, ReleaseDate, Restrictions,
+        },
+        util::duration_millis,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonChapterFields;
+    }
+
+    pub(super) trait FullFields {
+        fn full_fields(&self) -> &FullChapterFields;
+    }
+
+    /// This struct covers all the possible chapter responses from Spotify's API. It has functions that convert it into
+    /// either a [FullChapter](super::FullChapter) or a [PartialChapter](super::PartialChapter), depending on which
+    /// fields are set.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ChapterObject {
+        /// Fields available in every chapter
+        #[serde(flatten)]
+        pub(crate) common: CommonChapterFields,
+
+        /// Fields only in full chapters
+        #[serde(flatten)]
+        pub(crate) full: Option<FullChapterFields>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonChapterFields {
+        pub(crate) name: String,
+        pub(crate) id: Id<'static, ChapterId>,
+        pub(crate) chapter_number: u32,
+        pub(crate) description: String,
+        #[serde(default)]
+        pub(crate) html_description: String,
+        #[serde(rename = "duration_ms", with = "duration_millis")]
+        pub(crate) duration: Duration,
+        pub(crate) explicit: bool,
+        pub(crate) audio_preview_url: Option<String>,
+        pub(crate) images: Vec<Image>,
+        pub(crate) is_playable: Option<bool>,
+        #[serde(default)]
+        pub(crate) languages: Vec<String>,
+        #[serde(flatten)]
+        pub(crate) release_date: ReleaseDate,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(default)]
+        pub(crate) restrictions: Restrictions,
+        #[serde(default)]
+        pub(crate) resume_point: Option<ResumePoint>,
+        #[serde(default)]
+        pub(crate) available_markets: HashSet<CountryCode>,
+        // Spotify returns chapters with the episode type
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypeEpisode,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct FullChapterFields {
+        pub(crate) audiobook: PartialAudiobook,
+    }
+}
+
+use std::{collections::HashSet, time::Duration};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+pub(crate) use self::private::ChapterObject;
+use self::private::{CommonChapterFields, FullChapterFields};
+use super::{
+    audiobook::PartialAudiobook,
+    episode::ResumePoint,
+    id::{ChapterId, Id, IdTrait},
+    CountryCode, DatePrecision, ExternalUrls, Image, ReleaseDate, Restrictions,
+};
+use crate::error::ConversionError;
+
+/// Functions for retrieving information that is common to every chapter type.
+pub trait CommonChapterInformation: crate::private::Sealed {
+    /// The chapter's name.
+    fn name(&self) -> &str;
+    /// The chapter's Spotify ID.
+    fn id(&self) -> Id<'_, ChapterId>;
+    /// The chapter's zero-based number in its audiobook.
+    fn chapter_number(&self) -> u32;
+    /// The chapter's description without HTML tags.
+    fn description(&self) -> &str;
+    /// The chapter's description, which may contain HTML tags.
+    fn html_description(&self) -> &str;
+    /// The chapter's duration.
+    fn duration(&self) -> Duration;
+    /// Whether the chapter has explicit content.
+    fn explicit(&self) -> bool;
+    /// An URL to a 30 second preview of the chapter, if available.
+    fn audio_preview_url(&self) -> Option<&str>;
+    /// The images for the chapter.
+    fn images(&self) -> &[Image];
+    /// Whether the chapter is playable in the given market. `None` if no market was given in the request.
+    fn is_playable(&self) -> Option<bool>;
+    /// The languages used in the chapter, as ISO 639 codes.
+    fn languages(&self) -> &[String];
+    /// The chapter's release date.
+    fn release_date(&self) -> ReleaseDate;
+    /// The chapter's release date's precision.
+    fn release_date_precision(&self) -> DatePrecision;
+    /// The external URLs for the chapter.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The restrictions on the chapter.
+    fn restrictions(&self) -> &Restrictions;
+    /// The user's most recent position in the chapter. Available only when the request was made with a user
+    /// authenticated client that has the [UserReadPlaybackPosition](crate::scope::Scope::UserReadPlaybackPosition)
+    /// scope.
+    fn resume_point(&self) -> Option<&ResumePoint>;
+    /// The countries the chapter is available in.
+    fn available_markets(&self) -> &HashSet<CountryCode>;
+}
+
+/// Functions for retrieving information only in full chapters.
+pub trait FullChapterInformation: crate::private::Sealed {
+    /// The audiobook the chapter belongs to.
+    fn audiobook(&self) -> &PartialAudiobook;
+}
+
+impl<T> CommonChapterInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn id(&self) -> Id<'_, ChapterId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn chapter_number(&self) -> u32 {
+        self.common_fields().chapter_number
+    }
+
+    fn description(&self) -> &str {
+        &self.common_fields().description
+    }
+
+    fn html_description(&self) -> &str {
+        &self.common_fields().html_description
+    }
+
+    fn duration(&self) -> Duration {
+        self.common_fields().duration
+    }
+
+    fn explicit(&self) -> bool {
+        self.common_fields().explicit
+    }
+
+    fn audio_preview_url(&self) -> Option<&str> {
+        self.common_fields().audio_preview_url.as_deref()
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn is_playable(&self) -> Option<bool> {
+        self.common_fields().is_playable
+    }
+
+    fn languages(&self) -> &[String] {
+        &self.common_fields().languages
+    }
+
+    fn release_date(&self) -> ReleaseDate {
+        self.common_fields().release_date
+    }
+
+    fn release_date_precision(&self) -> DatePrecision {
+        self.common_fields().release_date.precision()
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn restrictions(&self) -> &Restrictions {
+        &self.common_fields().restrictions
+    }
+
+    fn resume_point(&self) -> Option<&ResumePoint> {
+        self.common_fields().resume_point.as_ref()
+    }
+
+    fn available_markets(&self) -> &HashSet<CountryCode> {
+        &self.common_fields().available_markets
+    }
+}
+
+impl<T> FullChapterInformation for T
+where
+    T: private::FullFields + crate::private::Sealed,
+{
+    fn audiobook(&self) -> &PartialAudiobook {
+        &self.full_fields().audiobook
+    }
+}
+
+/// This struct's only purpose is to make serializing more efficient by holding only references to its data. When
+/// attempting to serialize a chapter object, its fields will be passed as references to this object which is then
+/// serialized. This avoids having to clone the entire chapter in order to reconstruct a ChapterObject.
+#[derive(Serialize)]
+struct ChapterObjectRef<'a> {
+    #[serde(flatten)]
+    common: &'a CommonChapterFields,
+    #[serde(flatten)]
+    full: Option<&'a FullChapterFields>,
+}
+
+/// A full chapter. Contains [full information](self::FullChapterInformation), in addition to all
+/// [common](self::CommonChapterInformation) information about a chapter.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(try_from = "ChapterObject")]
+pub struct FullChapter {
+    common: CommonChapterFields,
+    full: FullChapterFields,
+}
+
+/// A partial chapter. Contains all [common](self::CommonChapterInformation) information about a chapter.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(from = "ChapterObject")]
+pub struct PartialChapter {
+    common: CommonChapterFields,
+}
+
+impl PartialEq for FullChapter {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialChapter {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialChapter> for FullChapter {
+    fn eq(&self, other: &PartialChapter) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullChapter> for PartialChapter {
+    fn eq(&self, other: &FullChapter) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl TryFrom<ChapterObject> for FullChapter {
+    type Error = ConversionError;
+
+    fn try_from(obj: ChapterObject) -> Result<Self, Self::Error> {
+        match obj.full {
+            Some(full) => Ok(FullChapter {
+                common: obj.common,
+                full,
+            }),
+
+            None => Err(ConversionError(
+                "attempt to convert partial chapter object into full chapter".into(),
+            )),
+        }
+    }
+}
+
+impl From<ChapterObject> for PartialChapter {
+    fn from(obj: ChapterObject) -> Self {
+        PartialChapter { common: obj.common }
+    }
+}
+
+impl From<FullChapter> for PartialChapter {
+    fn from(full: FullChapter) -> Self {
+        PartialChapter { common: full.common }
+    }
+}
+
+impl crate::private::Sealed for FullChapter {}
+impl crate::private::Sealed for PartialChapter {}
+
+impl private::CommonFields for FullChapter {
+    fn common_fields(&self) -> &CommonChapterFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialChapter {
+    fn common_fields(&self) -> &CommonChapterFields {
+        &self.common
+    }
+}
+
+impl private::FullFields for FullChapter {
+    fn full_fields(&self) -> &FullChapterFields {
+        &self.full
+    }
+}
+
+impl Serialize for FullChapter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ChapterObjectRef {
+            common: &self.common,
+            full: Some(&self.full),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for PartialChapter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ChapterObjectRef {
+            common: &self.common,
+            full: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::model::audiobook::CommonAudiobookInformation;
+
+    fn full_chapter_json() -> Value {
+        json!({
+            "audio_preview_url": "https://p.scdn.co/mp3-preview/2f37da1d4221f40b9d1a98cd191f4d6f1646ad17",
+            "audiobook": {
+                "authors": [{ "name": "Jane Austen" }],
+                "available_markets": ["GB", "US"],
+                "copyrights": [],
+                "description": "A classic novel of manners.",
+                "edition": "Unabridged",
+                "explicit": false,
+                "external_urls": {},
+                "id": "7iHfbu1YPACw6oZPAFJtqe",
+                "images": [],
+                "languages": ["en"],
+                "media_type": "audio",
+                "name": "Pride and Prejudice",
+                "narrators": [{ "name": "Rosamund Pike" }],
+                "publisher": "Audible Studios",
+                "total_chapters": 62,
+                "type": "audiobook"
+            },
+            "available_markets": ["GB", "US"],
+            "chapter_number": 1,
+            "description": "Chapter one.",
+            "duration_ms": 1686230,
+            "explicit": false,
+            "external_urls": { "spotify": "https://open.spotify.com/episode/0D5wENdkdwbqlrHoaJ9g29" },
+            "href": "https://api.spotify.com/v1/chapters/0D5wENdkdwbqlrHoaJ9g29",
+            "html_description": "<p>Chapter one.</p>",
+            "id": "0D5wENdkdwbqlrHoaJ9g29",
+            "images": [],
+            "is_playable": true,
+            "languages": ["en"],
+            "name": "Chapter 1",
+            "release_date": "2012-01-01",
+            "release_date_precision": "day",
+            "resume_point": { "fully_played": false, "resume_position_ms": 1000 },
+            "type": "episode",
+            "uri": "spotify:episode:0D5wENdkdwbqlrHoaJ9g29"
+        })
+    }
+
+    #[test]
+    fn full_chapter_information() {
+        let chapter: FullChapter = serde_json::from_value(full_chapter_json()).unwrap();
+
+        assert_eq!(chapter.id().as_str(), "0D5wENdkdwbqlrHoaJ9g29");
+        assert_eq!(chapter.chapter_number(), 1);
+        assert_eq!(chapter.duration(), Duration::from_millis(1686230));
+        assert_eq!(
+            chapter.audio_preview_url(),
+            Some("https://p.scdn.co/mp3-preview/2f37da1d4221f40b9d1a98cd191f4d6f1646ad17")
+        );
+        assert_eq!(chapter.release_date_precision(), DatePrecision::Day);
+        assert_eq!(chapter.resume_point().unwrap().resume_position, Duration::from_secs(1));
+        assert!(chapter.available_markets().contains(&CountryCode::US));
+        assert_eq!(chapter.audiobook().name(), "Pride and Prejudice");
+        assert_eq!(chapter.audiobook().total_chapters(), 62);
+    }
+
+    #[test]
+    fn partial_chapter_from_object_without_audiobook() {
+        let mut value = full_chapter_json();
+        value.as_object_mut().unwrap().remove("audiobook");
+
+        let obj: ChapterObject = serde_json::from_value(value).unwrap();
+        assert_eq!(PartialChapter::from(obj.clone()).chapter_number(), 1);
+        assert!(FullChapter::try_from(obj).is_err());
+    }
+}
@@ -1,276 +1,320 @@
 //! Contains the [CountryCode] enum.
 
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
-/// Represents all possible two-letter country codes.
-///
-/// Most of the codes here are from the [ISO-3166](https://en.wikipedia.org/wiki/ISO_3166)-specification, however
-/// Spotify may still return some country codes not recognised in ISO-3166. Such are included here as well.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub enum CountryCode {
-    // ====================================
-    // country codes recognised in ISO-3166
-    // ====================================
-    AD,
-    AE,
-    AF,
-    AG,
-    AI,
-    AL,
-    AM,
-    AO,
-    AQ,
-    AR,
-    AS,
-    AT,
-    AU,
-    AW,
-    AX,
-    AZ,
-    BA,
-    BB,
-    BD,
-    BE,
-    BF,
-    BG,
-    BH,
-    BI,
-    BJ,
-    BL,
-    BM,
-    BN,
-    BO,
-    BQ,
-    BR,
-    BS,
-    BT,
-    BV,
-    BW,
-    BY,
-    BZ,
-    CA,
-    CC,
-    CD,
-    CF,
-    CG,
-    CH,
-    CI,
-    CK,
-    CL,
-    CM,
-    CN,
-    CO,
-    CR,
-    CU,
-    CV,
-    CW,
-    CX,
-    CY,
-    CZ,
-    DE,
-    DJ,
-    DK,
-    DM,
-    DO,
-    DZ,
-    EC,
-    EE,
-    EG,
-    EH,
-    ER,
-    ES,
-    ET,
-    FI,
-    FJ,
-    FK,
-    FM,
-    FO,
-    FR,
-    GA,
-    GB,
-    GD,
-    GE,
-    GF,
-    GG,
-    GH,
-    GI,
-    GL,
-    GM,
-    GN,
-    GP,
-    GQ,
-    GR,
-    GS,
-    GT,
-    GU,
-    GW,
-    GY,
-    HK,
-    HM,
-    HN,
-    HR,
-    HT,
-    HU,
-    ID,
-    IE,
-    IL,
-    IM,
-    IN,
-    IO,
-    IQ,
-    IR,
-    IS,
-    IT,
-    JE,
-    JM,
-    JO,
-    JP,
-    KE,
-    KG,
-    KH,
-    KI,
-    KM,
-    KN,
-    KP,
-    KR,
-    KW,
-    KY,
-    KZ,
-    LA,
-    LB,
-    LC,
-    LI,
-    LK,
-    LR,
-    LS,
-    LT,
-    LU,
-    LV,
-    LY,
-    MA,
-    MC,
-    MD,
-    ME,
-    MF,
-    MG,
-    MH,
-    MK,
-    ML,
-    MM,
-    MN,
-    MO,
-    MP,
-    MQ,
-    MR,
-    MS,
-    MT,
-    MU,
-    MV,
-    MW,
-    MX,
-    MY,
-    MZ,
-    NA,
-    NC,
-    NE,
-    NF,
-    NG,
-    NI,
-    NL,
-    NO,
-    NP,
-    NR,
-    NU,
-    NZ,
-    OM,
-    PA,
-    PE,
-    PF,
-    PG,
-    PH,
-    PK,
-    PL,
-    PM,
-    PN,
-    PR,
-    PS,
-    PT,
-    PW,
-    PY,
-    QA,
-    RE,
-    RO,
-    RS,
-    RU,
-    RW,
-    SA,
-    SB,
-    SC,
-    SD,
-    SE,
-    SG,
-    SH,
-    SI,
-    SJ,
-    SK,
-    SL,
-    SM,
-    SN,
-    SO,
-    SR,
-    SS,
-    ST,
-    SV,
-    SX,
-    SY,
-    SZ,
-    TC,
-    TD,
-    TF,
-    TG,
-    TH,
-    TJ,
-    TK,
-    TL,
-    TM,
-    TN,
-    TO,
-    TR,
-    TT,
-    TV,
-    TW,
-    TZ,
-    UA,
-    UG,
-    UM,
-    US,
-    UY,
-    UZ,
-    VA,
-    VC,
-    VE,
-    VG,
-    VI,
-    VN,
-    VU,
-    WF,
-    WS,
-    YE,
-    YT,
-    ZA,
-    ZM,
-    ZW,
+use crate::error::CountryCodeError;
 
-    // ========================================
-    // country codes not recognised by ISO-3166
-    // ========================================
-    XK,
+macro_rules! country_codes {
+    ($(#[$meta:meta])* pub enum $name:ident { $($code:ident,)* }) => {
+        $(#[$meta])*
+        pub enum $name {
+            $($code,)*
+        }
+
+        impl $name {
+            /// Returns the two-letter country code as a string.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$code => stringify!($code),)*
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = CountryCodeError;
+
+            /// Parses a two-letter country code. The input is case-insensitive.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.len() != 2 {
+                    return Err(CountryCodeError(s.to_owned()));
+                }
+
+                match s.to_ascii_uppercase().as_str() {
+                    $(stringify!($code) => Ok(Self::$code),)*
+                    _ => Err(CountryCodeError(s.to_owned())),
+                }
+            }
+        }
+    };
+}
+
+country_codes! {
+    /// Represents all possible two-letter country codes.
+    ///
+    /// Most of the codes here are from the [ISO-3166](https://en.wikipedia.org/wiki/ISO_3166)-specification, however
+    /// Spotify may still return some country codes not recognised in ISO-3166. Such are included here as well.
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum CountryCode {
+        // ====================================
+        // country codes recognised in ISO-3166
+        // ====================================
+        AD,
+        AE,
+        AF,
+        AG,
+        AI,
+        AL,
+        AM,
+        AO,
+        AQ,
+        AR,
+        AS,
+        AT,
+        AU,
+        AW,
+        AX,
+        AZ,
+        BA,
+        BB,
+        BD,
+        BE,
+        BF,
+        BG,
+        BH,
+        BI,
+        BJ,
+        BL,
+        BM,
+        BN,
+        BO,
+        BQ,
+        BR,
+        BS,
+        BT,
+        BV,
+        BW,
+        BY,
+        BZ,
+        CA,
+        CC,
+        CD,
+        CF,
+        CG,
+        CH,
+        CI,
+        CK,
+        CL,
+        CM,
+        CN,
+        CO,
+        CR,
+        CU,
+        CV,
+        CW,
+        CX,
+        CY,
+        CZ,
+        DE,
+        DJ,
+        DK,
+        DM,
+        DO,
+        DZ,
+        EC,
+        EE,
+        EG,
+        EH,
+        ER,
+        ES,
+        ET,
+        FI,
+        FJ,
+        FK,
+        FM,
+        FO,
+        FR,
+        GA,
+        GB,
+        GD,
+        GE,
+        GF,
+        GG,
+        GH,
+        GI,
+        GL,
+        GM,
+        GN,
+        GP,
+        GQ,
+        GR,
+        GS,
+        GT,
+        GU,
+        GW,
+        GY,
+        HK,
+        HM,
+        HN,
+        HR,
+        HT,
+        HU,
+        ID,
+        IE,
+        IL,
+        IM,
+        IN,
+        IO,
+        IQ,
+        IR,
+        IS,
+        IT,
+        JE,
+        JM,
+        JO,
+        JP,
+        KE,
+        KG,
+        KH,
+        KI,
+        KM,
+        KN,
+        KP,
+        KR,
+        KW,
+        KY,
+        KZ,
+        LA,
+        LB,
+        LC,
+        LI,
+        LK,
+        LR,
+        LS,
+        LT,
+        LU,
+        LV,
+        LY,
+        MA,
+        MC,
+        MD,
+        ME,
+        MF,
+        MG,
+        MH,
+        MK,
+        ML,
+        MM,
+        MN,
+        MO,
+        MP,
+        MQ,
+        MR,
+        MS,
+        MT,
+        MU,
+        MV,
+        MW,
+        MX,
+        MY,
+        MZ,
+        NA,
+        NC,
+        NE,
+        NF,
+        NG,
+        NI,
+        NL,
+        NO,
+        NP,
+        NR,
+        NU,
+        NZ,
+        OM,
+        PA,
+        PE,
+        PF,
+        PG,
+        PH,
+        PK,
+        PL,
+        PM,
+        PN,
+        PR,
+        PS,
+        PT,
+        PW,
+        PY,
+        QA,
+        RE,
+        RO,
+        RS,
+        RU,
+        RW,
+        SA,
+        SB,
+        SC,
+        SD,
+        SE,
+        SG,
+        SH,
+        SI,
+        SJ,
+        SK,
+        SL,
+        SM,
+        SN,
+        SO,
+        SR,
+        SS,
+        ST,
+        SV,
+        SX,
+        SY,
+        SZ,
+        TC,
+        TD,
+        TF,
+        TG,
+        TH,
+        TJ,
+        TK,
+        TL,
+        TM,
+        TN,
+        TO,
+        TR,
+        TT,
+        TV,
+        TW,
+        TZ,
+        UA,
+        UG,
+        UM,
+        US,
+        UY,
+        UZ,
+        VA,
+        VC,
+        VE,
+        VG,
+        VI,
+        VN,
+        VU,
+        WF,
+        WS,
+        YE,
+        YT,
+        ZA,
+        ZM,
+        ZW,
+
+        // ========================================
+        // country codes not recognised by ISO-3166
+        // ========================================
+        XK,
+    }
 }
 
 impl fmt::Display for CountryCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
@@ -0,0 +1,519 @@
+//! Everything related to episodes of shows.
+//!
+//! Contains the two different kinds of episodes; [FullEpisode] and [PartialEpisode].
+//!
+//! - [FullEpisode]: contains all information about an episode, including the show it belongs to. Retrieved from the
+//!   [episode](crate::client::UnscopedClient::episode)-function, or as part of a playlist.
+//! - [PartialEpisode]: contains all information about an episode except the show it belongs to. Retrieved as part of a
+//!   [show's episodes](crate::model::show::FullShow::episodes).
+//!
+//! Additionally, there is the [Episode] enum that encompasses both kinds of episodes.
+//!
+//! The episode object Spotify returns from the API is not directly available. The two episode objects, or the [Episode]
+//! enum, may be serialized to get almost all of the original API response back.
+//!
+//! # Episode equality
+//!
+//! Two episodes are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        model::{
+            episode::ResumePoint,
+            id::{EpisodeId, Id},
+            object_type::{object_type_serialize, TypeEpisode},
+            show::PartialShow,
+            ExternalUrls, Image, ReleaseDate, Restrictions,
+        },
+        util::duration_millis,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonEpisodeFields;
+    }
+
+    pub(super) trait FullFields {
+        fn full_fields(&self) -> &FullEpisodeFields;
+    }
+
+    /// This struct covers all the possible episode responses from Spotify's API. It has a function that converts it
+    /// into an [Episode], depending on which fields are set.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct EpisodeObject {
+        /// Fields available in every episode
+        #[serde(flatten)]
+        pub(crate) common: CommonEpisodeFields,
+
+        /// Fields only in full episodes
+        #[serde(flatten)]
+        pub(crate) full: Option<FullEpisodeFields>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonEpisodeFields {
+        pub(crate) name: String,
+        pub(crate) id: Id<'static, EpisodeId>,
+        pub(crate) description: String,
+        #[serde(default)]
+        pub(crate) html_description: String,
+        #[serde(rename = "duration_ms", with = "duration_millis")]
+        pub(crate) duration: Duration,
+        pub(crate) explicit: bool,
+        pub(crate) audio_preview_url: Option<String>,
+        pub(crate) images: Vec<Image>,
+        pub(crate) is_externally_hosted: bool,
+        pub(crate) is_playable: Option<bool>,
+        #[serde(default)]
+        pub(crate) languages: Vec<String>,
+        #[serde(flatten)]
+        pub(crate) release_date: ReleaseDate,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(default)]
+        pub(crate) restrictions: Restrictions,
+        #[serde(default)]
+        pub(crate) resume_point: Option<ResumePoint>,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypeEpisode,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct FullEpisodeFields {
+        pub(crate) show: PartialShow,
+    }
+}
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+pub(crate) use self::private::EpisodeObject;
+use self::private::{CommonEpisodeFields, FullEpisodeFields};
+use super::{
+    id::{EpisodeId, Id, IdTrait},
+    show::PartialShow,
+    DatePrecision, ExternalUrls, Image, ReleaseDate, Restrictions,
+};
+use crate::{error::ConversionError, util::duration_millis};
+
+/// Functions for retrieving information that is common to every episode type.
+pub trait CommonEpisodeInformation: crate::private::Sealed {
+    /// The episode's name.
+    fn name(&self) -> &str;
+    /// The episode's Spotify ID.
+    fn id(&self) -> Id<'_, EpisodeId>;
+    /// The episode's description without HTML tags.
+    fn description(&self) -> &str;
+    /// The episode's description, which may contain HTML tags.
+    fn html_description(&self) -> &str;
+    /// The episode's duration.
+    fn duration(&self) -> Duration;
+    /// Whether the episode has explicit content.
+    fn explicit(&self) -> bool;
+    /// An URL to a 30 second preview of the episode, if available.
+    fn audio_preview_url(&self) -> Option<&str>;
+    /// The images for the episode.
+    fn images(&self) -> &[Image];
+    /// Whether the episode is hosted outside of Spotify's CDN.
+    fn is_externally_hosted(&self) -> bool;
+    /// Whether the episode is playable in the given market. `None` if no market was given in the request.
+    fn is_playable(&self) -> Option<bool>;
+    /// The languages used in the episode, as ISO 639 codes.
+    fn languages(&self) -> &[String];
+    /// The episode's release date.
+    fn release_date(&self) -> ReleaseDate;
+    /// The episode's release date's precision.
+    fn release_date_precision(&self) -> DatePrecision;
+    /// The external URLs for the episode.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The restrictions on the episode.
+    fn restrictions(&self) -> &Restrictions;
+    /// The user's most recent position in the episode. Available only when the request was made with a user
+    /// authenticated client that has the [UserReadPlaybackPosition](crate::scope::Scope::UserReadPlaybackPosition)
+    /// scope.
+    fn resume_point(&self) -> Option<&ResumePoint>;
+}
+
+/// Functions for retrieving information only in full episodes.
+pub trait FullEpisodeInformation: crate::private::Sealed {
+    /// The show the episode belongs to.
+    fn show(&self) -> &PartialShow;
+}
+
+impl<T> CommonEpisodeInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn id(&self) -> Id<'_, EpisodeId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn description(&self) -> &str {
+        &self.common_fields().description
+    }
+
+    fn html_description(&self) -> &str {
+        &self.common_fields().html_description
+    }
+
+    fn duration(&self) -> Duration {
+        self.common_fields().duration
+    }
+
+    fn explicit(&self) -> bool {
+        self.common_fields().explicit
+    }
+
+    fn audio_preview_url(&self) -> Option<&str> {
+        self.common_fields().audio_preview_url.as_deref()
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn is_externally_hosted(&self) -> bool {
+        self.common_fields().is_externally_hosted
+    }
+
+    fn is_playable(&self) -> Option<bool> {
+        self.common_fields().is_playable
+    }
+
+    fn languages(&self) -> &[String] {
+        &self.common_fields().languages
+    }
+
+    fn release_date(&self) -> ReleaseDate {
+        self.common_fields().release_date
+    }
+
+    fn release_date_precision(&self) -> DatePrecision {
+        self.common_fields().release_date.precision()
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn restrictions(&self) -> &Restrictions {
+        &self.common_fields().restrictions
+    }
+
+    fn resume_point(&self) -> Option<&ResumePoint> {
+        self.common_fields().resume_point.as_ref()
+    }
+}
+
+impl<T> FullEpisodeInformation for T
+where
+    T: private::FullFields + crate::private::Sealed,
+{
+    fn show(&self) -> &PartialShow {
+        &self.full_fields().show
+    }
+}
+
+/// The user's most recent position in an episode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumePoint {
+    /// Whether the episode has been fully played by the user.
+    pub fully_played: bool,
+    /// The user's most recent position in the episode.
+    #[serde(rename = "resume_position_ms", with = "duration_millis")]
+    pub resume_position: Duration,
+}
+
+/// An enum that encompasses all episode types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Episode {
+    Full(Box<FullEpisode>),
+    Partial(Box<PartialEpisode>),
+}
+
+/// This struct's only purpose is to make serializing more efficient by holding only references to its data. When
+/// attempting to serialize an episode object, its fields will be passed as references to this object which is then
+/// serialized. This avoids having to clone the entire episode in order to reconstruct an EpisodeObject.
+#[derive(Serialize)]
+struct EpisodeObjectRef<'a> {
+    #[serde(flatten)]
+    common: &'a CommonEpisodeFields,
+    #[serde(flatten)]
+    full: Option<&'a FullEpisodeFields>,
+}
+
+/// A full episode. Contains [full information](self::FullEpisodeInformation), in addition to all
+/// [common](self::CommonEpisodeInformation) information about an episode.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(try_from = "EpisodeObject")]
+pub struct FullEpisode {
+    common: CommonEpisodeFields,
+    full: FullEpisodeFields,
+}
+
+/// A partial episode. Contains all [common](self::CommonEpisodeInformation) information about an episode.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(from = "EpisodeObject")]
+pub struct PartialEpisode {
+    common: CommonEpisodeFields,
+}
+
+impl PartialEq for FullEpisode {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialEpisode {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialEpisode> for FullEpisode {
+    fn eq(&self, other: &PartialEpisode) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullEpisode> for PartialEpisode {
+    fn eq(&self, other: &FullEpisode) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl From<EpisodeObject> for Episode {
+    fn from(obj: EpisodeObject) -> Self {
+        match obj.full {
+            Some(full) => Self::Full(Box::new(FullEpisode {
+                common: obj.common,
+                full,
+            })),
+
+            None => Self::Partial(Box::new(PartialEpisode { common: obj.common })),
+        }
+    }
+}
+
+impl From<FullEpisode> for Episode {
+    fn from(full: FullEpisode) -> Self {
+        Self::Full(Box::new(full))
+    }
+}
+
+impl From<PartialEpisode> for Episode {
+    fn from(partial: PartialEpisode) -> Self {
+        Self::Partial(Box::new(partial))
+    }
+}
+
+impl TryFrom<Episode> for FullEpisode {
+    type Error = ConversionError;
+
+    fn try_from(episode: Episode) -> Result<Self, Self::Error> {
+        match episode {
+            Episode::Full(full) => Ok(*full),
+            Episode::Partial(_) => Err(ConversionError(
+                "attempt to convert partial episode into full episode".into(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<EpisodeObject> for FullEpisode {
+    type Error = ConversionError;
+
+    fn try_from(obj: EpisodeObject) -> Result<Self, Self::Error> {
+        match obj.full {
+            Some(full) => Ok(FullEpisode {
+                common: obj.common,
+                full,
+            }),
+
+            None => Err(ConversionError(
+                "attempt to convert partial episode object into full episode".into(),
+            )),
+        }
+    }
+}
+
+impl From<Episode> for PartialEpisode {
+    fn from(episode: Episode) -> Self {
+        match episode {
+            Episode::Full(full) => PartialEpisode { common: full.common },
+            Episode::Partial(partial) => *partial,
+        }
+    }
+}
+
+impl From<EpisodeObject> for PartialEpisode {
+    fn from(obj: EpisodeObject) -> Self {
+        PartialEpisode { common: obj.common }
+    }
+}
+
+impl From<FullEpisode> for EpisodeObject {
+    fn from(value: FullEpisode) -> Self {
+        Self {
+            common: value.common,
+            full: Some(value.full),
+        }
+    }
+}
+
+impl From<PartialEpisode> for EpisodeObject {
+    fn from(value: PartialEpisode) -> Self {
+        Self {
+            common: value.common,
+            full: None,
+        }
+    }
+}
+
+impl crate::private::Sealed for FullEpisode {}
+impl crate::private::Sealed for PartialEpisode {}
+
+impl private::CommonFields for FullEpisode {
+    fn common_fields(&self) -> &CommonEpisodeFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialEpisode {
+    fn common_fields(&self) -> &CommonEpisodeFields {
+        &self.common
+    }
+}
+
+impl private::FullFields for FullEpisode {
+    fn full_fields(&self) -> &FullEpisodeFields {
+        &self.full
+    }
+}
+
+impl Serialize for Episode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Episode::Full(full_episode) => full_episode.serialize(serializer),
+            Episode::Partial(partial_episode) => partial_episode.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for FullEpisode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        EpisodeObjectRef {
+            common: &self.common,
+            full: Some(&self.full),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for PartialEpisode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        EpisodeObjectRef {
+            common: &self.common,
+            full: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::model::show::{CommonShowInformation, MediaType};
+
+    fn full_episode_json() -> Value {
+        json!({
+            "audio_preview_url": "https://p.scdn.co/mp3-preview/2f37da1d4221f40b9d1a98cd191f4d6f1646ad17",
+            "description": "A Spotify podcast sharing fresh insights on important topics of the moment.",
+            "duration_ms": 1686230,
+            "explicit": false,
+            "external_urls": { "spotify": "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ" },
+            "href": "https://api.spotify.com/v1/episodes/512ojhOuo1ktJprKbVcKyQ",
+            "html_description": "<p>A Spotify podcast sharing fresh insights on important topics of the moment.</p>",
+            "id": "512ojhOuo1ktJprKbVcKyQ",
+            "images": [],
+            "is_externally_hosted": false,
+            "is_playable": true,
+            "languages": ["en"],
+            "name": "Starting Your Own Podcast: Tips, Tricks, and Advice From Anchor Creators",
+            "release_date": "1981-12-15",
+            "release_date_precision": "day",
+            "resume_point": { "fully_played": false, "resume_position_ms": 1000 },
+            "show": {
+                "available_markets": ["FI"],
+                "copyrights": [],
+                "description": "Candid conversations about the podcasting industry.",
+                "explicit": false,
+                "external_urls": {},
+                "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+                "id": "38bS44xjbVVZ3No3ByF1dJ",
+                "images": [],
+                "is_externally_hosted": false,
+                "languages": ["en"],
+                "media_type": "audio",
+                "name": "Spotify Sessions",
+                "publisher": "Spotify",
+                "total_episodes": 10,
+                "type": "show",
+                "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+            },
+            "type": "episode",
+            "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ"
+        })
+    }
+
+    #[test]
+    fn full_episode_information() {
+        let episode: FullEpisode = serde_json::from_value(full_episode_json()).unwrap();
+
+        assert_eq!(episode.id().as_str(), "512ojhOuo1ktJprKbVcKyQ");
+        assert_eq!(episode.duration(), Duration::from_millis(1686230));
+        assert_eq!(episode.languages(), ["en"]);
+        assert_eq!(episode.release_date_precision(), DatePrecision::Day);
+        assert_eq!(episode.show().name(), "Spotify Sessions");
+        assert_eq!(
+            episode.resume_point(),
+            Some(&ResumePoint {
+                fully_played: false,
+                resume_position: Duration::from_secs(1)
+            })
+        );
+        assert_eq!(episode.show().media_type(), MediaType::Audio);
+    }
+
+    #[test]
+    fn partial_episode_from_object_without_show() {
+        let mut value = full_episode_json();
+        value.as_object_mut().unwrap().remove("show");
+
+        value.as_object_mut().unwrap().remove("resume_point");
+
+        let obj: EpisodeObject = serde_json::from_value(value).unwrap();
+        assert!(PartialEpisode::from(obj.clone()).resume_point().is_none());
+        assert!(FullEpisode::try_from(obj.clone()).is_err());
+        assert!(matches!(Episode::from(obj), Episode::Partial(_)));
+    }
+}
@@ -1,13 +1,16 @@
 //! Abstraction over the different errors the Spotify API may return.
 
-use serde::{de::Visitor, Deserialize};
+use std::fmt;
 
-#[cfg(any(feature = "async", feature = "sync"))]
-use crate::error::Error;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub(crate) struct AuthenticationErrorResponse {
+/// An error response from an OAuth authentication endpoint, such as when requesting or refreshing an access token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AuthError {
+    /// The OAuth error code.
     pub error: AuthenticationErrorKind,
+    /// Human-readable description of the error. May be empty.
     #[serde(default)]
     pub error_description: String,
 }
@@ -17,23 +20,35 @@ pub(crate) struct ApiErrorResponse {
     pub(crate) error: ApiError,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-pub(crate) struct ApiError {
+/// An error response from a Spotify Web API endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ApiError {
+    /// The HTTP status code of the response.
     pub status: u16,
-    pub message: ApiErrorMessage,
+    /// Human-readable description of the error.
+    pub message: String,
 }
 
-// TODO: can this be made crate-public?
 /// The different causes for OAuth-authentication to fail.
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AuthenticationErrorKind {
+    /// The request is missing a parameter, has an invalid parameter or is otherwise malformed.
     InvalidRequest,
+    /// Authenticating the client failed.
     InvalidClient,
+    /// The authorization code or refresh token is invalid, expired or revoked.
     InvalidGrant,
+    /// The client is not authorized to use the grant type.
     UnauthorizedClient,
+    /// The grant type is not supported.
     UnsupportedGrantType,
+    /// The requested scope is invalid or unknown.
     InvalidScope,
+    /// An error this library doesn't know of. The error's description may tell more.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -49,53 +64,99 @@ pub(crate) enum ApiErrorMessage {
     Other(String),
 }
 
-#[cfg(any(feature = "async", feature = "sync"))]
-impl AuthenticationErrorResponse {
-    pub fn into_unhandled_error(self) -> Error {
-        Error::UnhandledAuthenticationError(self.error, self.error_description)
+impl ApiError {
+    pub(crate) fn message_kind(&self) -> ApiErrorMessage {
+        match self.message.as_str() {
+            "Permissions missing" => ApiErrorMessage::PermissionsMissing,
+            "The access token expired" => ApiErrorMessage::TokenExpired,
+            // TODO: oh god this is ugly. there's actually a "reason" field that says NO_ACTIVE_DEVICE but that field is
+            // not in every error response (because of course it isn't)
+            "Player command failed: No active device found" => ApiErrorMessage::NoActiveDevice,
+            "Not found." => ApiErrorMessage::NotFound,
+            "Player command failed: Restriction violated" => ApiErrorMessage::RestrictionViolated,
+            "Player command failed: Premium required" => ApiErrorMessage::PremiumRequired,
+
+            other => ApiErrorMessage::Other(other.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.status, self.message)
+    }
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.error, self.error_description)
     }
 }
 
-impl<'de> Deserialize<'de> for ApiErrorMessage {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        struct SpotifyErrorMessageVisitor;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        impl<'de> Visitor<'de> for SpotifyErrorMessageVisitor {
-            type Value = ApiErrorMessage;
+    #[test]
+    fn api_error_response() {
+        let error_response: ApiErrorResponse =
+            serde_json::from_str(r#"{ "error": { "status": 404, "message": "Not found." } }"#).unwrap();
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a string")
+        assert_eq!(
+            error_response.error,
+            ApiError {
+                status: 404,
+                message: "Not found.".to_owned()
             }
+        );
+        assert_eq!(error_response.error.message_kind(), ApiErrorMessage::NotFound);
+        assert_eq!(error_response.error.to_string(), "404: Not found.");
+    }
 
-            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                self.visit_string(v.to_owned())
-            }
+    #[test]
+    fn api_error_message_kind() {
+        let error = ApiError {
+            status: 401,
+            message: "The access token expired".to_owned(),
+        };
+        assert_eq!(error.message_kind(), ApiErrorMessage::TokenExpired);
+
+        let error = ApiError {
+            status: 500,
+            message: "Something went wrong".to_owned(),
+        };
+        assert_eq!(
+            error.message_kind(),
+            ApiErrorMessage::Other("Something went wrong".to_owned())
+        );
+    }
 
-            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match v.as_str() {
-                    "Permissions missing" => Ok(ApiErrorMessage::PermissionsMissing),
-                    "The access token expired" => Ok(ApiErrorMessage::TokenExpired),
-                    // TODO: oh god this is ugly. there's actually a "reason" field that says NO_ACTIVE_DEVICE but that
-                    // field is not in every error response (because of course it isn't)
-                    "Player command failed: No active device found" => Ok(ApiErrorMessage::NoActiveDevice),
-                    "Not found." => Ok(ApiErrorMessage::NotFound),
-                    "Player command failed: Restriction violated" => Ok(ApiErrorMessage::RestrictionViolated),
-                    "Player command failed: Premium required" => Ok(ApiErrorMessage::PremiumRequired),
-
-                    _ => Ok(ApiErrorMessage::Other(v)),
-                }
+    #[test]
+    fn auth_error_response() {
+        let error: AuthError =
+            serde_json::from_str(r#"{ "error": "invalid_grant", "error_description": "Invalid refresh token" }"#)
+                .unwrap();
+
+        assert_eq!(
+            error,
+            AuthError {
+                error: AuthenticationErrorKind::InvalidGrant,
+                error_description: "Invalid refresh token".to_owned()
             }
-        }
+        );
+    }
+
+    #[test]
+    fn auth_error_response_without_description() {
+        let error: AuthError = serde_json::from_str(r#"{ "error": "invalid_client" }"#).unwrap();
+
+        assert_eq!(error.error, AuthenticationErrorKind::InvalidClient);
+        assert!(error.error_description.is_empty());
+    }
 
-        deserializer.deserialize_str(SpotifyErrorMessageVisitor)
+    #[test]
+    fn unknown_auth_error_kind() {
+        let error: AuthError = serde_json::from_str(r#"{ "error": "access_denied" }"#).unwrap();
+        assert_eq!(error.error, AuthenticationErrorKind::Unknown);
     }
 }
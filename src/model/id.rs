@@ -13,8 +13,11 @@
 //!   shows. There is a special playable context, [Collection](PlayableContext::Collection), which refers to a user's
 //!   Liked Songs playlist.
 //! - Users.
+//! - Audiobooks and their chapters. These can't be played through the API, so they're available only as [Id]s and not
+//!   through the common ID types below.
 //!
-//! All the different kinds of IDs are grouped into [SpotifyId] that encompasses all of them.
+//! All the different kinds of IDs, except audiobooks and chapters, are grouped into [SpotifyId] that encompasses all of
+//! them.
 //!
 //! # The core ID type
 //!
@@ -68,13 +71,26 @@
 //!         .unwrap();
 //! ```
 //!
+//! Finally, [Id] implements [TryFrom] for string types and [FromStr](std::str::FromStr), which accept any of the three
+//! formats. In every case, the ID is validated to be a 22-character base-62 string (or, for user IDs, a non-empty
+//! alphanumeric string).
+//!
+//! ```
+//! # use ferrispot::model::id::*;
+//! let track_from_str: Id<TrackId> = "2pDPOMX0kWA7kcPBcDCQBu".try_into().unwrap();
+//! let track_from_uri: Id<TrackId> = "spotify:track:2pDPOMX0kWA7kcPBcDCQBu".parse().unwrap();
+//!
+//! // too short
+//! assert!(Id::<TrackId>::try_from("2pDPOMX0kWA7kcPBcD").is_err());
+//! ```
+//!
 //! ## Efficiency
 //!
 //! [Id] internally stores the originally given string in a [Cow]. This means it will borrow the input string if it is
 //! given as an `&str`, which helps avoid string allocations in certain cases. For example, most Spotify API endpoints
 //! require an input ID in the URI form, so if the ID is originally parsed from an URI, the entire original string can
 //! be used instead of having to allocate a new string. You may also retrieve the ID in an URL form, so if the original
-//! string was also an URL, no new strings are allocated.
+//! string was also an URL without a locale or query parameters, no new strings are allocated.
 //!
 //! ```
 //! # use ferrispot::model::id::*;
@@ -349,7 +365,7 @@
 //! assert!(matches!(context_id, SpotifyId::Context(_)));
 //! ```
 
-use std::{borrow::Cow, fmt, marker::PhantomData};
+use std::{borrow::Cow, fmt, marker::PhantomData, str::FromStr};
 
 use serde::{
     de::{self, Visitor},
@@ -361,6 +377,7 @@ use crate::{error::IdError, util::maybe_split_once::MaybeSplitOnce};
 
 const ID_LENGTH: usize = 22; // I hope Spotify never changes this length
 const URL_PREFIX: &str = "https://open.spotify.com/";
+const URL_LOCALE_PREFIX: &str = "intl-";
 const URI_PREFIX: &str = "spotify:";
 const URI_COLLECTION_SUFFIX: &str = ":collection";
 const URL_COLLECTION_SUFFIX: &str = "/collection";
@@ -393,16 +410,19 @@ pub trait IdTrait<'a>: private::Sealed {
     /// Returns this ID as a bare Spotify ID.
     fn as_str(&'a self) -> &'a str;
 
-    /// Returns this ID as a Spotify URI.
+    /// Returns this ID as a canonical Spotify URI, such as `spotify:track:2pDPOMX0kWA7kcPBcDCQBu`.
     ///
     /// This function returns a [Cow], since it allows the function to avoid needlessly allocating a new string if the
-    /// original ID string this ID was constructed from is already an URI. Otherwise, it will allocate a new URI string.
+    /// original ID string this ID was constructed from is already a canonical URI. Otherwise, it will allocate a new
+    /// URI string.
     fn as_uri(&'a self) -> Cow<'a, str>;
 
-    /// Returns this ID as a Spotify URL.
+    /// Returns this ID as a canonical Spotify URL, such as `https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu`. The
+    /// URL never contains a locale or query parameters, even if the original URL did.
     ///
     /// This function returns a [Cow], since it allows the function to avoid needlessly allocating a new string if the
-    /// original ID string this ID was constructed from is already an URL. Otherwise, it will allocate a new URL string.
+    /// original ID string this ID was constructed from is already a canonical URL. Otherwise, it will allocate a new
+    /// URL string.
     fn as_url(&'a self) -> Cow<'a, str>;
 
     /// Returns a new Id that clones the value from this Id and owns it.
@@ -567,6 +587,18 @@ pub struct PlaylistId;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ShowId;
 
+/// Signifies an audiobook ID.
+///
+/// See the [module-level docs](self) for information on how to work with IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudiobookId;
+
+/// Signifies an audiobook chapter ID.
+///
+/// See the [module-level docs](self) for information on how to work with IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterId;
+
 /// Signifies a user ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
@@ -580,6 +612,8 @@ impl private::Sealed for ArtistId {}
 impl private::Sealed for AlbumId {}
 impl private::Sealed for PlaylistId {}
 impl private::Sealed for ShowId {}
+impl private::Sealed for AudiobookId {}
+impl private::Sealed for ChapterId {}
 impl private::Sealed for UserId {}
 
 impl private::Sealed for SpotifyId<'_> {}
@@ -610,6 +644,14 @@ impl ItemTypeId for ShowId {
     const ITEM_TYPE: ItemType = ItemType::Show;
 }
 
+impl ItemTypeId for AudiobookId {
+    const ITEM_TYPE: ItemType = ItemType::Audiobook;
+}
+
+impl ItemTypeId for ChapterId {
+    const ITEM_TYPE: ItemType = ItemType::Chapter;
+}
+
 impl ItemTypeId for UserId {
     const ITEM_TYPE: ItemType = ItemType::User;
 }
@@ -693,6 +735,58 @@ where
     }
 }
 
+impl<'a, T> Id<'a, T>
+where
+    T: ItemTypeId,
+{
+    /// Parses either a Spotify URL, a Spotify URI or a bare Spotify ID into an ID.
+    fn from_any<C>(value: C) -> Result<Self, IdError>
+    where
+        C: Into<Cow<'a, str>>,
+    {
+        let value = value.into();
+
+        if value.starts_with(URI_PREFIX) || value.starts_with(URL_PREFIX) {
+            Self::from_url_or_uri(value)
+        } else {
+            Self::from_bare(value)
+        }
+    }
+}
+
+impl<'a, T> TryFrom<&'a str> for Id<'a, T>
+where
+    T: ItemTypeId,
+{
+    type Error = IdError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Self::from_any(value)
+    }
+}
+
+impl<T> TryFrom<String> for Id<'static, T>
+where
+    T: ItemTypeId,
+{
+    type Error = IdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_any(value)
+    }
+}
+
+impl<T> FromStr for Id<'static, T>
+where
+    T: ItemTypeId,
+{
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_any(s.to_owned())
+    }
+}
+
 impl<'a> IdFromKnownKind<'a> for PlayableItem<'a> {
     fn from_uri<C>(uri: C) -> Result<Self, IdError>
     where
@@ -785,6 +879,9 @@ impl<'a> IdFromKnownKind<'a> for SpotifyId<'a> {
             ItemType::Show => Ok(Self::Context(PlayableContext::Show(Id::new(uri, kind)))),
             ItemType::Collection => Ok(Self::Context(PlayableContext::Collection(Id::new(uri, kind)))),
             ItemType::User => Ok(Self::User(Id::new(uri, kind))),
+
+            // audiobooks and chapters can't be played through the API, so they have no place in the common ID types
+            item_type @ (ItemType::Audiobook | ItemType::Chapter) => Err(IdError::WrongItemType(item_type)),
         }
     }
 
@@ -805,6 +902,9 @@ impl<'a> IdFromKnownKind<'a> for SpotifyId<'a> {
             ItemType::Show => Ok(Self::Context(PlayableContext::Show(Id::new(url, kind)))),
             ItemType::Collection => Ok(Self::Context(PlayableContext::Collection(Id::new(url, kind)))),
             ItemType::User => Ok(Self::User(Id::new(url, kind))),
+
+            // audiobooks and chapters can't be played through the API, so they have no place in the common ID types
+            item_type @ (ItemType::Audiobook | ItemType::Chapter) => Err(IdError::WrongItemType(item_type)),
         }
     }
 }
@@ -827,36 +927,25 @@ where
     }
 
     fn as_uri(&'a self) -> Cow<'a, str> {
-        match &self.kind {
-            IdKind::Uri { .. } => match &self.value {
-                Cow::Borrowed(b) => Cow::Borrowed(b),
-                Cow::Owned(o) => Cow::Borrowed(o),
-            },
-
-            IdKind::Url { id_index, id_len } => Cow::Owned(format!(
-                "spotify:{}:{}",
-                T::ITEM_TYPE,
-                &self.value[*id_index..*id_index + id_len]
-            )),
-
-            IdKind::Bare => Cow::Owned(format!("spotify:{}:{}", T::ITEM_TYPE, self.value)),
+        match self.kind {
+            // the original URI is canonical only if there's nothing after the ID, such as the collection suffix
+            IdKind::Uri { id_index, id_len } if id_index + id_len == self.value.len() => Cow::Borrowed(&self.value),
+            _ => Cow::Owned(format!("{URI_PREFIX}{}:{}", T::ITEM_TYPE, self.as_str())),
         }
     }
 
     fn as_url(&'a self) -> Cow<'a, str> {
-        match &self.kind {
-            IdKind::Url { .. } => match &self.value {
-                Cow::Borrowed(b) => Cow::Borrowed(b),
-                Cow::Owned(o) => Cow::Borrowed(o),
-            },
-
-            IdKind::Uri { id_index, id_len } => Cow::Owned(format!(
-                "https://open.spotify.com/{}/{}",
-                T::ITEM_TYPE,
-                &self.value[*id_index..*id_index + id_len]
-            )),
-
-            IdKind::Bare => Cow::Owned(format!("https://open.spotify.com/{}/{}", T::ITEM_TYPE, self.value)),
+        match self.kind {
+            // the original URL is canonical only if it has no locale and there's nothing after the ID, such as a query
+            // or the collection suffix
+            IdKind::Url { id_index, id_len }
+                if id_index + id_len == self.value.len()
+                    && !self.value[URL_PREFIX.len()..].starts_with(URL_LOCALE_PREFIX) =>
+            {
+                Cow::Borrowed(&self.value)
+            }
+
+            _ => Cow::Owned(format!("{URL_PREFIX}{}/{}", T::ITEM_TYPE, self.as_str())),
         }
     }
 
@@ -1293,7 +1382,9 @@ impl<'de> Deserialize<'de> for SpotifyId<'static> {
                     ItemType::Show => Ok(SpotifyId::Context(PlayableContext::Show(Id::new(Cow::Owned(v), kind)))),
                     ItemType::User => Ok(SpotifyId::User(Id::new(Cow::Owned(v), kind))),
 
-                    ItemType::Collection => Err(de::Error::invalid_value(de::Unexpected::Str(&v), &self)),
+                    ItemType::Collection | ItemType::Audiobook | ItemType::Chapter => {
+                        Err(de::Error::invalid_value(de::Unexpected::Str(&v), &self))
+                    }
                 }
             }
         }
@@ -1513,10 +1604,22 @@ fn parse_item_type_and_id_from_uri(uri: &str) -> Result<(ItemType, usize, usize)
 fn parse_item_type_and_id_from_url(url: &str) -> Result<(ItemType, usize, usize), IdError> {
     // TODO: bringing in the url crate could help with parsing the URL but then again this does work so far
     // a whole URL could look like: https://open.spotify.com/track/3mXLyNsVeLelMakgpGUp1f?si=AAAAAAAAAAAAAAAA
+    // shared links may also contain a locale after the domain: https://open.spotify.com/intl-de/track/...
+
+    let mut locale_len = 0;
 
     if let Some((item_type_str, id)) = url
         // remove the leading domain
         .strip_prefix(URL_PREFIX)
+        // remove the possible locale
+        .map(|prefix_removed| match prefix_removed.split_once('/') {
+            Some((locale, locale_removed)) if locale.starts_with(URL_LOCALE_PREFIX) => {
+                locale_len = locale.len() + 1;
+                locale_removed
+            }
+
+            _ => prefix_removed,
+        })
         // split by / to get "track" and "3mXLyNsVeLelMakgpGUp1f?si=AAAAAAAAAAAAAAAA"
         .and_then(|prefix_removed| prefix_removed.split_once('/'))
         // remove the possible query from the path to get just the ID
@@ -1527,8 +1630,8 @@ fn parse_item_type_and_id_from_url(url: &str) -> Result<(ItemType, usize, usize)
     {
         let item_type: ItemType = item_type_str.parse()?;
 
-        // the position of the ID in the string is the domain + the item type + /
-        let id_index = URL_PREFIX.len() + item_type_str.len() + 1;
+        // the position of the ID in the string is the domain + the possible locale + the item type + /
+        let id_index = URL_PREFIX.len() + locale_len + item_type_str.len() + 1;
 
         match item_type {
             // special case #1: user ID with the collection suffix (/collection), in which case the URL is the user's
@@ -1630,6 +1733,84 @@ mod tests {
         assert_eq!(id.as_url(), "https://open.spotify.com/user/1337420asdasd");
     }
 
+    #[test]
+    fn uri_and_url_for_each_id_kind() {
+        fn assert_uri_and_url<'a, T>(id: &'a Id<'a, T>, uri: &str, url: &str)
+        where
+            T: ItemTypeId + 'static,
+        {
+            assert_eq!(id.as_uri(), uri);
+            assert_eq!(id.as_url(), url);
+        }
+
+        assert_uri_and_url(
+            &Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap(),
+            "spotify:track:2pDPOMX0kWA7kcPBcDCQBu",
+            "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu",
+        );
+
+        assert_uri_and_url(
+            &Id::<EpisodeId>::from_bare("512ojhOuo1ktJprKbVcKyQ").unwrap(),
+            "spotify:episode:512ojhOuo1ktJprKbVcKyQ",
+            "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ",
+        );
+
+        assert_uri_and_url(
+            &Id::<AlbumId>::from_bare("0tDsHtvN9YNuZjlqHvDY2P").unwrap(),
+            "spotify:album:0tDsHtvN9YNuZjlqHvDY2P",
+            "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P",
+        );
+
+        assert_uri_and_url(
+            &Id::<ArtistId>::from_bare("6pNgnvzBa6Bthsv8SrZJYl").unwrap(),
+            "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl",
+            "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl",
+        );
+
+        assert_uri_and_url(
+            &Id::<PlaylistId>::from_bare("37i9dQZF1DXcBWIGoYBM5M").unwrap(),
+            "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M",
+            "https://open.spotify.com/playlist/37i9dQZF1DXcBWIGoYBM5M",
+        );
+
+        assert_uri_and_url(
+            &Id::<ShowId>::from_bare("38bS44xjbVVZ3No3ByF1dJ").unwrap(),
+            "spotify:show:38bS44xjbVVZ3No3ByF1dJ",
+            "https://open.spotify.com/show/38bS44xjbVVZ3No3ByF1dJ",
+        );
+
+        assert_uri_and_url(
+            &Id::<UserId>::from_bare("1337420asdasd").unwrap(),
+            "spotify:user:1337420asdasd",
+            "https://open.spotify.com/user/1337420asdasd",
+        );
+    }
+
+    #[test]
+    fn url_from_url_with_query_and_locale_is_canonical() {
+        let id = Id::<TrackId>::from_url("https://open.spotify.com/intl-de/track/2pDPOMX0kWA7kcPBcDCQBu?si=AAAAAAAAAA")
+            .unwrap();
+
+        assert_eq!(id.as_url(), "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu");
+    }
+
+    #[test]
+    fn collection_uri_and_url_from_uri() {
+        let id = PlayableContext::from_uri("spotify:user:1337420asdasd:collection").unwrap();
+
+        assert_eq!(id.as_uri(), "spotify:user:1337420asdasd:collection");
+        assert_eq!(id.as_url(), "https://open.spotify.com/user/1337420asdasd/collection");
+    }
+
+    #[test]
+    fn collection_uri_and_url_from_url() {
+        let id =
+            PlayableContext::from_url("https://open.spotify.com/user/1337420asdasd/collection?si=AAAAAAAAAA").unwrap();
+
+        assert_eq!(id.as_uri(), "spotify:user:1337420asdasd:collection");
+        assert_eq!(id.as_url(), "https://open.spotify.com/user/1337420asdasd/collection");
+    }
+
     // ==========
     // conversion
     // ==========
@@ -2008,6 +2189,29 @@ mod tests {
         assert!(matches!(id, Err(IdError::WrongItemType(ItemType::Track))))
     }
 
+    #[test]
+    fn track_id_from_url_with_locale() {
+        let id = Id::<TrackId>::from_url("https://open.spotify.com/intl-de/track/2pDPOMX0kWA7kcPBcDCQBu?si=AAAAAAAAAA")
+            .unwrap();
+
+        assert_eq!(id.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(id.as_uri(), "spotify:track:2pDPOMX0kWA7kcPBcDCQBu");
+    }
+
+    #[test]
+    fn wrong_id_type_in_url_with_locale() {
+        let id = Id::<ArtistId>::from_url("https://open.spotify.com/intl-ja/track/2pDPOMX0kWA7kcPBcDCQBu");
+        assert!(matches!(id, Err(IdError::WrongItemType(ItemType::Track))))
+    }
+
+    #[test]
+    fn spotify_id_from_url_with_locale() {
+        let id = SpotifyId::from_url("https://open.spotify.com/intl-fi/album/0tDsHtvN9YNuZjlqHvDY2P").unwrap();
+
+        assert!(matches!(id, SpotifyId::Context(PlayableContext::Album(_))));
+        assert_eq!(id.as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+    }
+
     #[test]
     fn wrong_id_type_in_uri() {
         let id = Id::<ArtistId>::from_uri("spotify:track:2pDPOMX0kWA7kcPBcDCQBu");
@@ -2068,6 +2272,55 @@ mod tests {
         assert!(matches!(id, Err(IdError::InvalidId(_))))
     }
 
+    // ===========================
+    // TryFrom and FromStr parsing
+    // ===========================
+
+    #[test]
+    fn try_from_str_bare() {
+        let id = Id::<AlbumId>::try_from("0tDsHtvN9YNuZjlqHvDY2P").unwrap();
+        assert_eq!(id.as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+    }
+
+    #[test]
+    fn try_from_str_uri_and_url() {
+        let from_uri = Id::<TrackId>::try_from("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let from_url = Id::<TrackId>::try_from("https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+
+        assert_eq!(from_uri.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(from_url.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+    }
+
+    #[test]
+    fn try_from_string_user_id() {
+        let id = Id::<UserId>::try_from(String::from("1337420asdasd")).unwrap();
+        assert_eq!(id.as_str(), "1337420asdasd");
+    }
+
+    #[test]
+    fn from_str_artist_id() {
+        let id: Id<'static, ArtistId> = "6pNgnvzBa6Bthsv8SrZJYl".parse().unwrap();
+        assert_eq!(id.as_str(), "6pNgnvzBa6Bthsv8SrZJYl");
+    }
+
+    #[test]
+    fn try_from_str_too_short() {
+        let id = Id::<TrackId>::try_from("2pDPOMX0kWA7kcPBcD");
+        assert!(matches!(id, Err(IdError::InvalidId(_))))
+    }
+
+    #[test]
+    fn from_str_illegal_characters() {
+        let id = "2pDPOMX0kWA7kcPB-DCQBu".parse::<Id<PlaylistId>>();
+        assert!(matches!(id, Err(IdError::InvalidId(_))))
+    }
+
+    #[test]
+    fn try_from_str_wrong_item_type() {
+        let id = Id::<AlbumId>::try_from("spotify:track:2pDPOMX0kWA7kcPBcDCQBu");
+        assert!(matches!(id, Err(IdError::WrongItemType(ItemType::Track))))
+    }
+
     // =====================
     // allocation efficiency
     // =====================
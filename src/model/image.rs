@@ -0,0 +1,196 @@
+//! Contains the [Image] struct and the [ImageExt] trait for picking an image from a list of images.
+
+use serde::{Deserialize, Serialize};
+
+// TODO: maybe make the fields private and expose them through functions
+/// Contains an URL to an image and its dimensions, if specified.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Image {
+    pub url: String,
+    /// The image's dimensions. `None` if Spotify doesn't know either the image's width or its height, which is often
+    /// the case with playlist cover images.
+    #[serde(flatten)]
+    pub dimensions: Option<ImageDimensions>,
+}
+
+/// An image's dimensions.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageDimensions {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Functions for picking an image of a suitable size from a list of images, such as an artist's or an album's images.
+///
+/// Spotify doesn't always specify the dimensions of an image. Such images are never picked by these functions, since
+/// their size is unknown.
+///
+/// ```
+/// # use ferrispot::model::{Image, ImageDimensions, ImageExt};
+/// let images = [
+///     Image {
+///         url: "https://i.scdn.co/image/large".to_owned(),
+///         dimensions: Some(ImageDimensions { width: 640, height: 640 }),
+///     },
+///     Image {
+///         url: "https://i.scdn.co/image/small".to_owned(),
+///         dimensions: Some(ImageDimensions { width: 64, height: 64 }),
+///     },
+/// ];
+///
+/// assert_eq!(images.largest().unwrap().url, "https://i.scdn.co/image/large");
+/// assert_eq!(images.closest_to(100, 100).unwrap().url, "https://i.scdn.co/image/small");
+/// ```
+pub trait ImageExt: crate::private::Sealed {
+    /// Returns the image with the largest area.
+    fn largest(&self) -> Option<&Image>;
+
+    /// Returns the image with the smallest area.
+    fn smallest(&self) -> Option<&Image>;
+
+    /// Returns the image whose dimensions are closest to the given width and height, as measured by the sum of the
+    /// differences in width and height.
+    fn closest_to(&self, width: u32, height: u32) -> Option<&Image>;
+
+    /// Returns every image sorted by their area, largest first. Equally large images keep their order. Images whose
+    /// dimensions are unknown are last.
+    fn images_sorted(&self) -> Vec<&Image>;
+}
+
+impl crate::private::Sealed for [Image] {}
+
+impl ImageExt for [Image] {
+    fn largest(&self) -> Option<&Image> {
+        // max_by_key returns the last of equally large images, so reverse the images to keep the first one instead
+        with_dimensions(self)
+            .rev()
+            .max_by_key(|(_, dimensions)| area(dimensions))
+            .map(|(image, _)| image)
+    }
+
+    fn smallest(&self) -> Option<&Image> {
+        with_dimensions(self)
+            .min_by_key(|(_, dimensions)| area(dimensions))
+            .map(|(image, _)| image)
+    }
+
+    fn closest_to(&self, width: u32, height: u32) -> Option<&Image> {
+        with_dimensions(self)
+            .min_by_key(|(_, dimensions)| dimensions.width.abs_diff(width) + dimensions.height.abs_diff(height))
+            .map(|(image, _)| image)
+    }
+
+    fn images_sorted(&self) -> Vec<&Image> {
+        let mut images = self.iter().collect::<Vec<_>>();
+        // None sorts before any area, so reversing the order puts the images with unknown dimensions last
+        images.sort_by_key(|image| std::cmp::Reverse(image.dimensions.as_ref().map(area)));
+        images
+    }
+}
+
+/// Returns the images whose dimensions are known along with their dimensions.
+fn with_dimensions(images: &[Image]) -> impl DoubleEndedIterator<Item = (&Image, &ImageDimensions)> {
+    images
+        .iter()
+        .filter_map(|image| image.dimensions.as_ref().map(|dimensions| (image, dimensions)))
+}
+
+fn area(dimensions: &ImageDimensions) -> u64 {
+    u64::from(dimensions.width) * u64::from(dimensions.height)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn images() -> Vec<Image> {
+        serde_json::from_value(json!([
+            { "url": "unknown", "width": null, "height": null },
+            { "url": "medium", "width": 300, "height": 300 },
+            { "url": "large", "width": 640, "height": 640 },
+            { "url": "small", "width": 64, "height": 64 },
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn null_dimensions_are_unknown() {
+        assert_eq!(images()[0].dimensions, None);
+    }
+
+    // playlist cover images are returned with null dimensions
+    #[test]
+    fn playlist_image_with_null_dimensions() {
+        let image: Image = serde_json::from_value(json!({
+            "height": null,
+            "url": "https://mosaic.scdn.co/640/ab67616d0000b2730a0e9dc7c4b4fb0f06a9f4b5",
+            "width": null
+        }))
+        .unwrap();
+
+        assert_eq!(
+            image.url,
+            "https://mosaic.scdn.co/640/ab67616d0000b2730a0e9dc7c4b4fb0f06a9f4b5"
+        );
+        assert_eq!(image.dimensions, None);
+    }
+
+    #[test]
+    fn partially_null_dimensions_are_unknown() {
+        let image: Image = serde_json::from_value(json!({ "height": 300, "url": "url", "width": null })).unwrap();
+        assert_eq!(image.dimensions, None);
+    }
+
+    #[test]
+    fn image_with_dimensions() {
+        let image: Image = serde_json::from_value(json!({ "height": 300, "url": "url", "width": 640 })).unwrap();
+
+        assert_eq!(
+            image.dimensions,
+            Some(ImageDimensions {
+                width: 640,
+                height: 300
+            })
+        );
+    }
+
+    #[test]
+    fn largest_and_smallest() {
+        let images = images();
+
+        assert_eq!(images.largest().unwrap().url, "large");
+        assert_eq!(images.smallest().unwrap().url, "small");
+    }
+
+    #[test]
+    fn closest_to() {
+        let images = images();
+
+        assert_eq!(images.closest_to(0, 0).unwrap().url, "small");
+        assert_eq!(images.closest_to(250, 250).unwrap().url, "medium");
+        assert_eq!(images.closest_to(1000, 1000).unwrap().url, "large");
+    }
+
+    #[test]
+    fn images_sorted() {
+        let images = images();
+        let sorted = images
+            .images_sorted()
+            .into_iter()
+            .map(|image| image.url.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(sorted, ["large", "medium", "small", "unknown"]);
+    }
+
+    #[test]
+    fn only_unknown_dimensions() {
+        let images = &images()[..1];
+
+        assert!(images.largest().is_none());
+        assert!(images.smallest().is_none());
+        assert!(images.closest_to(300, 300).is_none());
+    }
+}
@@ -0,0 +1,444 @@
+//! Everything related to the items the user has saved in their library.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    album::FullAlbum,
+    page::{Page, PageInformation, PageObject},
+    show::PartialShow,
+    track::FullTrack,
+    Timestamp,
+};
+
+/// A track saved in the user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedTrack {
+    added_at: Timestamp,
+    track: FullTrack,
+}
+
+/// A page of tracks saved in the user's library.
+///
+/// This object is retrieved only through the [saved_tracks](crate::client::ScopedClient::saved_tracks)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedTracks {
+    #[serde(flatten)]
+    page: PageObject<SavedTrack>,
+}
+
+/// An album saved in the user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedAlbum {
+    added_at: Timestamp,
+    album: FullAlbum,
+}
+
+/// A page of albums saved in the user's library.
+///
+/// This object is retrieved only through the [saved_albums](crate::client::ScopedClient::saved_albums)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedAlbums {
+    #[serde(flatten)]
+    page: PageObject<SavedAlbum>,
+}
+
+/// A show saved in the user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedShow {
+    added_at: Timestamp,
+    show: PartialShow,
+}
+
+/// A page of shows saved in the user's library.
+///
+/// This object is retrieved only through the [saved_shows](crate::client::ScopedClient::saved_shows)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedShows {
+    #[serde(flatten)]
+    page: PageObject<SavedShow>,
+}
+
+impl SavedTrack {
+    /// When the track was saved.
+    pub fn added_at(&self) -> Timestamp {
+        self.added_at
+    }
+
+    /// The saved track.
+    pub fn track(&self) -> &FullTrack {
+        &self.track
+    }
+
+    /// The saved track. Take ownership of the track.
+    pub fn take_track(self) -> FullTrack {
+        self.track
+    }
+}
+
+impl SavedAlbum {
+    /// When the album was saved.
+    pub fn added_at(&self) -> Timestamp {
+        self.added_at
+    }
+
+    /// The saved album.
+    pub fn album(&self) -> &FullAlbum {
+        &self.album
+    }
+
+    /// The saved album. Take ownership of the album.
+    pub fn take_album(self) -> FullAlbum {
+        self.album
+    }
+}
+
+impl SavedShow {
+    /// When the show was saved.
+    pub fn added_at(&self) -> Timestamp {
+        self.added_at
+    }
+
+    /// The saved show.
+    pub fn show(&self) -> &PartialShow {
+        &self.show
+    }
+
+    /// The saved show. Take ownership of the show.
+    pub fn take_show(self) -> PartialShow {
+        self.show
+    }
+}
+
+impl From<SavedTracks> for Page<SavedTracks, SavedTrack> {
+    fn from(page: SavedTracks) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for SavedTracks {}
+
+impl PageInformation<SavedTrack> for SavedTracks {
+    type Items = Vec<SavedTrack>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::has_previous(&self.page)
+    }
+}
+
+impl From<SavedAlbums> for Page<SavedAlbums, SavedAlbum> {
+    fn from(page: SavedAlbums) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for SavedAlbums {}
+
+impl PageInformation<SavedAlbum> for SavedAlbums {
+    type Items = Vec<SavedAlbum>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::has_previous(&self.page)
+    }
+}
+
+impl From<SavedShows> for Page<SavedShows, SavedShow> {
+    fn from(page: SavedShows) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for SavedShows {}
+
+impl PageInformation<SavedShow> for SavedShows {
+    type Items = Vec<SavedShow>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::has_previous(&self.page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::{
+        album::{FullAlbumInformation, NonLocalAlbumInformation},
+        id::IdTrait,
+        show::CommonShowInformation,
+        track::NonLocalTrackInformation,
+    };
+
+    #[test]
+    fn saved_tracks_page() {
+        let page: SavedTracks = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/me/tracks?offset=0&limit=1",
+            "items": [
+                {
+                    "added_at": "2016-12-13T20:44:04Z",
+                    "track": {
+                        "album": {
+                            "album_type": "single",
+                            "artists": [],
+                            "external_urls": {},
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "World is Mine",
+                            "release_date": "2008-05-31",
+                            "release_date_precision": "day",
+                            "total_tracks": 1,
+                            "type": "album"
+                        },
+                        "artists": [],
+                        "disc_number": 1,
+                        "duration_ms": 254000,
+                        "explicit": false,
+                        "external_ids": {},
+                        "external_urls": {},
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "name": "World is Mine",
+                        "popularity": 60,
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track"
+                    }
+                }
+            ],
+            "limit": 1,
+            "next": "https://api.spotify.com/v1/me/tracks?offset=1&limit=1",
+            "offset": 0,
+            "previous": null,
+            "total": 2
+        }))
+        .unwrap();
+
+        let page = Page::from(page);
+        let items = page.items();
+
+        assert_eq!(page.total(), 2);
+        assert_eq!(page.next_offset(), Some(1));
+        assert_eq!(items[0].track().id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(
+            items[0]
+                .added_at()
+                .as_system_time()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap(),
+            Duration::from_secs(1_481_661_844)
+        );
+    }
+
+    #[test]
+    fn saved_albums_page() {
+        let page: SavedAlbums = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/me/albums?offset=0&limit=20",
+            "items": [
+                {
+                    "added_at": "2021-05-01T12:00:00Z",
+                    "album": {
+                        "album_type": "album",
+                        "artists": [],
+                        "copyrights": [{ "text": "2009 Sony Music Records", "type": "P" }],
+                        "external_ids": { "upc": "4547366046553" },
+                        "external_urls": {},
+                        "genres": [],
+                        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                        "images": [],
+                        "label": "Sony Music Records",
+                        "name": "Supercell",
+                        "popularity": 42,
+                        "release_date": "2009-03-04",
+                        "release_date_precision": "day",
+                        "total_tracks": 0,
+                        "tracks": {
+                            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=0&limit=50",
+                            "items": [],
+                            "limit": 50,
+                            "next": null,
+                            "offset": 0,
+                            "previous": null,
+                            "total": 0
+                        },
+                        "type": "album"
+                    }
+                }
+            ],
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        }))
+        .unwrap();
+
+        let page = Page::from(page);
+        let items = page.items();
+
+        assert_eq!(page.total(), 1);
+        assert_eq!(page.next_offset(), None);
+        assert_eq!(items[0].album().id().as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+        assert_eq!(items[0].album().label(), "Sony Music Records");
+        assert_eq!(
+            items[0]
+                .added_at()
+                .as_system_time()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap(),
+            Duration::from_secs(1_619_870_400)
+        );
+    }
+
+    #[test]
+    fn saved_shows_page() {
+        let page: SavedShows = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/me/shows?offset=0&limit=20",
+            "items": [
+                {
+                    "added_at": "2021-05-01T12:00:00Z",
+                    "show": {
+                        "available_markets": [],
+                        "copyrights": [],
+                        "description": "Candid conversations about the podcasting industry.",
+                        "explicit": false,
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+                        "html_description": "<p>Candid conversations about the podcasting industry.</p>",
+                        "id": "38bS44xjbVVZ3No3ByF1dJ",
+                        "images": [],
+                        "is_externally_hosted": false,
+                        "languages": ["en"],
+                        "media_type": "audio",
+                        "name": "Spotify Sessions",
+                        "publisher": "Spotify",
+                        "total_episodes": 10,
+                        "type": "show",
+                        "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+                    }
+                }
+            ],
+            "limit": 20,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        }))
+        .unwrap();
+
+        let items = Page::from(page).take_items();
+
+        assert_eq!(items[0].show().id().as_str(), "38bS44xjbVVZ3No3ByF1dJ");
+        assert_eq!(items[0].show().total_episodes(), 10);
+        assert_eq!(
+            items[0]
+                .added_at()
+                .as_system_time()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap(),
+            Duration::from_secs(1_619_870_400)
+        );
+    }
+}
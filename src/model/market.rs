@@ -0,0 +1,134 @@
+//! Contains the [Market] enum.
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Serialize};
+
+use super::CountryCode;
+use crate::error::CountryCodeError;
+
+const FROM_TOKEN: &str = "from_token";
+
+/// A market to restrict catalog content to.
+///
+/// A market is either a specific [country](CountryCode), or the special value [FromToken](Market::FromToken) which
+/// refers to the country associated with the user account of a user-authenticated client.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Market {
+    /// A specific country.
+    Country(CountryCode),
+    /// The country associated with the user account of the client's access token. Only applicable with
+    /// user-authenticated clients.
+    FromToken,
+}
+
+impl Market {
+    /// Returns the market as a string Spotify recognises; either the two-letter country code or `from_token`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Market::Country(country) => country.as_str(),
+            Market::FromToken => FROM_TOKEN,
+        }
+    }
+}
+
+impl From<CountryCode> for Market {
+    fn from(country: CountryCode) -> Self {
+        Self::Country(country)
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Market {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Market {
+    type Err = CountryCodeError;
+
+    /// Parses either a two-letter country code or `from_token`. Country codes are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == FROM_TOKEN {
+            Ok(Self::FromToken)
+        } else {
+            s.parse().map(Self::Country)
+        }
+    }
+}
+
+impl Serialize for Market {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Market {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&s), &"a two-letter country code or from_token"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn country_round_trip() {
+        for code in ["FI", "US", "JP", "XK"] {
+            let market: Market = code.parse().unwrap();
+            assert_eq!(market.to_string(), code);
+            assert_eq!(market.as_ref(), code);
+        }
+    }
+
+    #[test]
+    fn lowercase_country_is_normalized() {
+        let market: Market = "fi".parse().unwrap();
+        assert_eq!(market, Market::Country(CountryCode::FI));
+        assert_eq!(market.to_string(), "FI");
+    }
+
+    #[test]
+    fn from_token_round_trip() {
+        let market: Market = "from_token".parse().unwrap();
+        assert_eq!(market, Market::FromToken);
+        assert_eq!(market.to_string(), "from_token");
+    }
+
+    #[test]
+    fn invalid_length_is_rejected() {
+        assert!("FIN".parse::<Market>().is_err());
+        assert!("F".parse::<Market>().is_err());
+        assert!("".parse::<Market>().is_err());
+    }
+
+    #[test]
+    fn unknown_country_is_rejected() {
+        assert!("QQ".parse::<Market>().is_err());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let markets = vec![Market::Country(CountryCode::SE), Market::FromToken];
+        let serialized = serde_json::to_string(&markets).unwrap();
+        assert_eq!(serialized, r#"["SE","from_token"]"#);
+
+        let deserialized: Vec<Market> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, markets);
+    }
+}
@@ -2,6 +2,10 @@ pub const TYPE_ALBUM: &str = "album";
 pub const TYPE_TRACK: &str = "track";
 pub const TYPE_ARTIST: &str = "artist";
 pub const TYPE_USER: &str = "user";
+pub const TYPE_PLAYLIST: &str = "playlist";
+pub const TYPE_SHOW: &str = "show";
+pub const TYPE_EPISODE: &str = "episode";
+pub const TYPE_AUDIOBOOK: &str = "audiobook";
 
 pub(crate) mod object_type_serialize {
     use serde::{Deserialize, Deserializer, Serializer};
@@ -49,6 +53,18 @@ pub(crate) struct TypeArtist;
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub(crate) struct TypeUser;
 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct TypePlaylist;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct TypeShow;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct TypeEpisode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct TypeAudiobook;
+
 impl ObjectType for TypeAlbum {
     const OBJECT_TYPE: &'static str = TYPE_ALBUM;
 }
@@ -64,3 +80,19 @@ impl ObjectType for TypeArtist {
 impl ObjectType for TypeUser {
     const OBJECT_TYPE: &'static str = TYPE_USER;
 }
+
+impl ObjectType for TypePlaylist {
+    const OBJECT_TYPE: &'static str = TYPE_PLAYLIST;
+}
+
+impl ObjectType for TypeShow {
+    const OBJECT_TYPE: &'static str = TYPE_SHOW;
+}
+
+impl ObjectType for TypeEpisode {
+    const OBJECT_TYPE: &'static str = TYPE_EPISODE;
+}
+
+impl ObjectType for TypeAudiobook {
+    const OBJECT_TYPE: &'static str = TYPE_AUDIOBOOK;
+}
@@ -2,11 +2,13 @@
 use std::borrow::Cow;
 use std::{fmt::Debug, marker::PhantomData};
 
+#[cfg(feature = "async")]
+use futures_util::{Stream, StreamExt, TryStreamExt};
 #[cfg(any(feature = "async", feature = "sync"))]
 use log::trace;
 #[cfg(any(feature = "async", feature = "sync"))]
 use reqwest::Method;
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub(crate) use self::private::PageObject;
 #[cfg(feature = "async")]
@@ -29,14 +31,11 @@ mod private {
     {
         pub items: Vec<T>,
         pub next: Option<String>,
-
-        // these fields aren't actually needed but keep them around for logging purposes
-        #[allow(dead_code)]
-        limit: usize,
-        #[allow(dead_code)]
-        offset: usize,
-        #[allow(dead_code)]
-        total: usize,
+        #[serde(default)]
+        pub previous: Option<String>,
+        pub limit: usize,
+        pub offset: usize,
+        pub total: usize,
     }
 }
 
@@ -62,6 +61,21 @@ where
 
     /// Returns the URL for the next page from this page, if it exists.
     fn next(self) -> Option<String>;
+
+    /// Returns the total amount of items available across every page.
+    fn total(&self) -> usize;
+
+    /// Returns the maximum amount of items in this page.
+    fn limit(&self) -> usize;
+
+    /// Returns the offset of the first item in this page.
+    fn offset(&self) -> usize;
+
+    /// Returns whether there is a next page after this page.
+    fn has_next(&self) -> bool;
+
+    /// Returns whether there is a previous page before this page.
+    fn has_previous(&self) -> bool;
 }
 
 /// A page of items.
@@ -74,6 +88,39 @@ where
     pub(crate) phantom: PhantomData<TItem>,
 }
 
+/// A page of items that uses cursor-based paging instead of offset-based paging. Each page has
+/// [cursors](CursorPage::cursors) that point to the items before and after the page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CursorPage<T> {
+    items: Vec<T>,
+    next: Option<String>,
+    #[serde(default)]
+    cursors: Option<Cursors>,
+    limit: usize,
+    #[serde(default)]
+    total: Option<usize>,
+}
+
+/// Spotify returns some cursor pages wrapped in an object, such as the user's followed artists under an `artists` key.
+/// The following pages of such a cursor page are wrapped the same way.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum CursorPageResponse<T> {
+    Page(CursorPage<T>),
+    Artists { artists: CursorPage<T> },
+}
+
+/// The cursors of a [CursorPage].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursors {
+    /// The cursor to use as the `before` parameter to retrieve the items before the page.
+    #[serde(default)]
+    pub before: Option<String>,
+    /// The cursor to use as the `after` parameter to retrieve the items after the page.
+    #[serde(default)]
+    pub after: Option<String>,
+}
+
 #[cfg(any(feature = "async", feature = "sync"))]
 impl<TClient, TInner> BaseRequestBuilderContainer<TClient, TInner> for PageRequestBuilder<TClient, TInner> {
     fn new<S>(method: Method, base_url: S, client: TClient) -> Self
@@ -122,6 +169,26 @@ where
     fn next(self) -> Option<String> {
         self.next
     }
+
+    fn total(&self) -> usize {
+        self.total
+    }
+
+    fn limit(&self) -> usize {
+        self.limit
+    }
+
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+
+    fn has_previous(&self) -> bool {
+        self.previous.is_some()
+    }
 }
 
 impl<TInner, TItem> Page<TInner, TItem>
@@ -138,6 +205,140 @@ where
     pub fn take_items(self) -> TInner::Items {
         self.inner.take_items()
     }
+
+    /// Return the total amount of items available across every page.
+    pub fn total(&self) -> usize {
+        self.inner.total()
+    }
+
+    /// Return the maximum amount of items in this page.
+    pub fn limit(&self) -> usize {
+        self.inner.limit()
+    }
+
+    /// Return the offset of the first item in this page.
+    pub fn offset(&self) -> usize {
+        self.inner.offset()
+    }
+
+    /// Return the offset of the first item in the next page, if there is a next page.
+    pub fn next_offset(&self) -> Option<usize> {
+        if self.inner.has_next() {
+            Some(self.inner.offset() + self.inner.limit())
+        } else {
+            None
+        }
+    }
+
+    /// Return the offset of the first item in the previous page, if there is a previous page.
+    pub fn prev_offset(&self) -> Option<usize> {
+        if self.inner.has_previous() {
+            Some(self.inner.offset().saturating_sub(self.inner.limit()))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> CursorPage<T> {
+    /// Return the items in this page.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Return the items in this page while consuming the page.
+    pub fn take_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Return the cursors of this page, if any. Spotify doesn't return cursors for an empty page.
+    pub fn cursors(&self) -> Option<&Cursors> {
+        self.cursors.as_ref()
+    }
+
+    /// Return the maximum amount of items in this page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Return the total amount of items available across every page, if known.
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Returns whether there is a next page after this page.
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+}
+
+impl<T> From<CursorPageResponse<T>> for CursorPage<T> {
+    fn from(response: CursorPageResponse<T>) -> Self {
+        match response {
+            CursorPageResponse::Page(page) | CursorPageResponse::Artists { artists: page } => page,
+        }
+    }
+}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+impl<T> TryFromEmptyResponse for CursorPage<T> {}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+impl<T> TryFromEmptyResponse for CursorPageResponse<T> {}
+
+#[cfg(feature = "async")]
+impl<T> CursorPage<T>
+where
+    T: DeserializeOwned + Debug + Send + Sync,
+{
+    /// Return the next page from this page, if it exists.
+    pub async fn next_page_async<C>(self, client: &'_ C) -> crate::error::Result<Option<CursorPage<T>>>
+    where
+        C: crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + crate::client::private::GrantedScopes
+            + Clone
+            + Send
+            + Sync,
+    {
+        if let Some(url) = self.next {
+            let next_page =
+                RequestBuilder::<_, CursorPageResponse<T>, (), CursorPage<T>>::new(Method::GET, url, client.clone())
+                    .send_async()
+                    .await?;
+            trace!("Next page: {next_page:?}");
+
+            Ok(Some(next_page))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<T> CursorPage<T>
+where
+    T: DeserializeOwned + Debug,
+{
+    /// Return the next page from this page, if it exists.
+    pub fn next_page_sync<C>(self, client: &'_ C) -> crate::error::Result<Option<CursorPage<T>>>
+    where
+        C: crate::client::private::BuildHttpRequestSync
+            + crate::client::private::AccessTokenExpirySync
+            + crate::client::private::GrantedScopes
+            + Clone,
+    {
+        if let Some(url) = self.next {
+            let next_page =
+                RequestBuilder::<_, CursorPageResponse<T>, (), CursorPage<T>>::new(Method::GET, url, client.clone())
+                    .send_sync()?;
+            trace!("Next page: {next_page:?}");
+
+            Ok(Some(next_page))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "async")]
@@ -150,6 +351,7 @@ where
     where
         C: crate::client::private::BuildHttpRequestAsync
             + crate::client::private::AccessTokenExpiryAsync
+            + crate::client::private::GrantedScopes
             + Clone
             + Send
             + Sync,
@@ -168,6 +370,102 @@ where
             Ok(None)
         }
     }
+
+    /// Return a stream over every item in this page and all the pages after it. The next page is requested only once
+    /// the items in the current page are exhausted, and the stream ends after the last page.
+    ///
+    /// The items in each page are cloned in the same manner as in [`items`](Page::items). If requesting a page fails,
+    /// the stream yields the error and then ends.
+    ///
+    /// ```no_run
+    /// # use ferrispot::{client::SpotifyClientBuilder, model::ItemType, prelude::*};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn foo() {
+    /// # let spotify_client = SpotifyClientBuilder::new("").client_secret("").build_async().await.unwrap();
+    /// let first_page = spotify_client
+    ///     .search("hatsune miku")
+    ///     .types([ItemType::Track])
+    ///     .send_async()
+    ///     .await
+    ///     .unwrap()
+    ///     .tracks()
+    ///     .unwrap();
+    ///
+    /// let all_tracks = first_page
+    ///     .into_stream_async(spotify_client)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn into_stream_async<C>(self, client: C) -> impl Stream<Item = crate::error::Result<TItem>>
+    where
+        C: crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + crate::client::private::GrantedScopes
+            + Clone
+            + Send
+            + Sync,
+    {
+        let items = self.items().into_iter();
+
+        futures_util::stream::try_unfold(
+            (Some(self), items, client),
+            |(mut page, mut items, client)| async move {
+                loop {
+                    if let Some(item) = items.next() {
+                        return Ok(Some((item, (page, items, client))));
+                    }
+
+                    match page.take() {
+                        Some(current) => {
+                            if let Some(next_page) = current.next_page_async(&client).await? {
+                                items = next_page.items().into_iter();
+                                page = Some(next_page);
+                            }
+                        }
+
+                        None => return Ok(None),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Collect every item in this page and the pages after it into a list, stopping once the list has `max_items`
+    /// items. No more pages are requested after that. Pass `usize::MAX` to collect every item.
+    ///
+    /// The pages are requested one after another like in [`into_stream_async`](Page::into_stream_async). If requesting
+    /// a page fails, the error is returned and the items collected so far are discarded.
+    ///
+    /// ```no_run
+    /// # use ferrispot::{client::SpotifyClientBuilder, model::ItemType, prelude::*};
+    /// # async fn foo() {
+    /// # let spotify_client = SpotifyClientBuilder::new("").client_secret("").build_async().await.unwrap();
+    /// let first_page = spotify_client
+    ///     .search("hatsune miku")
+    ///     .types([ItemType::Track])
+    ///     .send_async()
+    ///     .await
+    ///     .unwrap()
+    ///     .tracks()
+    ///     .unwrap();
+    ///
+    /// let first_hundred_tracks = first_page.collect_all_async(spotify_client, 100).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn collect_all_async<C>(self, client: C, max_items: usize) -> crate::error::Result<Vec<TItem>>
+    where
+        C: crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + crate::client::private::GrantedScopes
+            + Clone
+            + Send
+            + Sync,
+    {
+        self.into_stream_async(client).take(max_items).try_collect().await
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -178,7 +476,10 @@ where
     /// Return the next page from this page, if it exists.
     pub fn next_page_sync<C>(self, client: &'_ C) -> crate::error::Result<Option<Page<TInner, TItem>>>
     where
-        C: crate::client::private::BuildHttpRequestSync + crate::client::private::AccessTokenExpirySync + Clone,
+        C: crate::client::private::BuildHttpRequestSync
+            + crate::client::private::AccessTokenExpirySync
+            + crate::client::private::GrantedScopes
+            + Clone,
     {
         if let Some(url) = self.inner.next() {
             let next_page = PageRequestBuilder::new(Method::GET, url, client.clone()).send_sync()?;
@@ -192,4 +493,436 @@ where
             Ok(None)
         }
     }
+
+    /// Return an iterator over every item in this page and all the pages after it. The next page is requested only once
+    /// the items in the current page are exhausted, and the iterator ends after the last page.
+    ///
+    /// The items in each page are cloned in the same manner as in [`items`](Page::items). If requesting a page fails,
+    /// the iterator yields the error and then ends.
+    ///
+    /// ```no_run
+    /// # use ferrispot::{client::SpotifyClientBuilder, model::ItemType, prelude::*};
+    /// # fn foo() {
+    /// # let spotify_client = SpotifyClientBuilder::new("").client_secret("").build_sync().unwrap();
+    /// let first_page = spotify_client
+    ///     .search("hatsune miku")
+    ///     .types([ItemType::Track])
+    ///     .send_sync()
+    ///     .unwrap()
+    ///     .tracks()
+    ///     .unwrap();
+    ///
+    /// let all_tracks = first_page
+    ///     .into_iter_sync(spotify_client)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn into_iter_sync<C>(self, client: C) -> impl Iterator<Item = crate::error::Result<TItem>>
+    where
+        C: crate::client::private::BuildHttpRequestSync
+            + crate::client::private::AccessTokenExpirySync
+            + crate::client::private::GrantedScopes
+            + Clone,
+    {
+        let mut items = self.items().into_iter();
+        let mut page = Some(self);
+
+        std::iter::from_fn(move || loop {
+            if let Some(item) = items.next() {
+                return Some(Ok(item));
+            }
+
+            match page.take()?.next_page_sync(&client) {
+                Ok(Some(next_page)) => {
+                    items = next_page.items().into_iter();
+                    page = Some(next_page);
+                }
+
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+
+    /// Collect every item in this page and the pages after it into a list, stopping once the list has `max_items`
+    /// items. No more pages are requested after that. Pass `usize::MAX` to collect every item.
+    ///
+    /// The pages are requested one after another like in [`into_iter_sync`](Page::into_iter_sync). If requesting a page
+    /// fails, the error is returned and the items collected so far are discarded.
+    pub fn collect_all_sync<C>(self, client: C, max_items: usize) -> crate::error::Result<Vec<TItem>>
+    where
+        C: crate::client::private::BuildHttpRequestSync
+            + crate::client::private::AccessTokenExpirySync
+            + crate::client::private::GrantedScopes
+            + Clone,
+    {
+        self.into_iter_sync(client).take(max_items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::{
+        artist::{ArtistObject, CommonArtistInformation, FullArtist, NonLocalArtistInformation, PartialArtist},
+        id::IdTrait,
+    };
+
+    fn page(
+        offset: usize,
+        next: Option<&str>,
+        previous: Option<&str>,
+    ) -> Page<PageObject<ArtistObject>, PartialArtist> {
+        let inner = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/me/following?type=artist",
+            "items": [
+                {
+                    "external_urls": {
+                        "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                    },
+                    "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                    "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                    "name": "Hatsune Miku",
+                    "type": "artist",
+                    "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                }
+            ],
+            "limit": 20,
+            "next": next,
+            "offset": offset,
+            "previous": previous,
+            "total": 41
+        }))
+        .unwrap();
+
+        Page {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    #[test]
+    fn page_information() {
+        let page = page(20, Some("https://next"), Some("https://previous"));
+
+        assert_eq!(page.items()[0].name(), "Hatsune Miku");
+        assert_eq!(page.total(), 41);
+        assert_eq!(page.limit(), 20);
+        assert_eq!(page.offset(), 20);
+        assert_eq!(page.next_offset(), Some(40));
+        assert_eq!(page.prev_offset(), Some(0));
+    }
+
+    #[test]
+    fn first_page_has_no_previous_offset() {
+        let page = page(0, Some("https://next"), None);
+
+        assert_eq!(page.next_offset(), Some(20));
+        assert_eq!(page.prev_offset(), None);
+    }
+
+    #[test]
+    fn last_page_has_no_next_offset() {
+        let page = page(40, None, Some("https://previous"));
+
+        assert_eq!(page.next_offset(), None);
+        assert_eq!(page.prev_offset(), Some(20));
+    }
+
+    #[test]
+    fn cursor_page() {
+        let page: CursorPage<String> = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/me/player/recently-played?limit=2",
+            "items": ["one", "two"],
+            "limit": 2,
+            "next": "https://api.spotify.com/v1/me/player/recently-played?before=1481661737016&limit=2",
+            "cursors": {
+                "after": "1481661844589",
+                "before": "1481661737016"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(page.items(), ["one", "two"]);
+        assert_eq!(page.limit(), 2);
+        assert_eq!(page.total(), None);
+        assert!(page.has_next());
+
+        let cursors = page.cursors().unwrap();
+        assert_eq!(cursors.before.as_deref(), Some("1481661737016"));
+        assert_eq!(cursors.after.as_deref(), Some("1481661844589"));
+    }
+
+    #[test]
+    fn empty_cursor_page() {
+        let page: CursorPage<String> = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/me/player/recently-played",
+            "items": [],
+            "limit": 20,
+            "next": null,
+            "cursors": null
+        }))
+        .unwrap();
+
+        assert!(page.items().is_empty());
+        assert!(page.cursors().is_none());
+        assert!(!page.has_next());
+    }
+
+    #[cfg(feature = "async")]
+    mod stream {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            thread,
+        };
+
+        use futures_util::TryStreamExt;
+        use serde_json::Value;
+
+        use super::*;
+        use crate::{
+            client::{implicit_grant::AsyncImplicitGrantUserClient, SpotifyClientBuilder},
+            model::search::{SearchResults, SearchResultsObject},
+        };
+
+        fn artist_json(id: &str, name: &str) -> Value {
+            json!({
+                "external_urls": {},
+                "followers": { "href": null, "total": 0 },
+                "genres": [],
+                "id": id,
+                "images": [],
+                "name": name,
+                "popularity": 0,
+                "type": "artist"
+            })
+        }
+
+        fn page_json(items: Vec<Value>, offset: usize, next: Option<String>) -> Value {
+            json!({
+                "artists": {
+                    "items": items,
+                    "limit": 2,
+                    "next": next,
+                    "offset": offset,
+                    "previous": null,
+                    "total": 5
+                }
+            })
+        }
+
+        /// Serves the given pages in order, one page per request, and then stops.
+        fn serve_pages(listener: TcpListener, pages: Vec<Value>) -> thread::JoinHandle<()> {
+            thread::spawn(move || {
+                for page in pages {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    // read and discard the request headers
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).unwrap() > 2 {
+                        line.clear();
+                    }
+
+                    let body = page.to_string();
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: \
+                         close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            })
+        }
+
+        fn user_client() -> AsyncImplicitGrantUserClient {
+            let incomplete_client = SpotifyClientBuilder::new("client ID")
+                .build_async()
+                .implicit_grant_client("http://localhost/callback")
+                .build();
+
+            let authorize_url = reqwest::Url::parse(&incomplete_client.get_authorize_url()).unwrap();
+            let (_, state) = authorize_url.query_pairs().find(|(key, _)| key == "state").unwrap();
+            let state = state.into_owned();
+
+            incomplete_client.finalize("access token", &state).unwrap()
+        }
+
+        #[tokio::test]
+        async fn stream_every_item_across_pages() {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+            let server = serve_pages(
+                listener,
+                vec![
+                    page_json(
+                        vec![
+                            artist_json("3333333333333333333333", "Three"),
+                            artist_json("4444444444444444444444", "Four"),
+                        ],
+                        2,
+                        Some(format!("{base_url}/search?offset=4&limit=2")),
+                    ),
+                    page_json(vec![artist_json("5555555555555555555555", "Five")], 4, None),
+                ],
+            );
+
+            let first_page: SearchResultsObject = serde_json::from_value(json!({
+                "artists": page_json(
+                    vec![
+                        artist_json("1111111111111111111111", "One"),
+                        artist_json("2222222222222222222222", "Two"),
+                    ],
+                    0,
+                    Some(format!("{base_url}/search?offset=2&limit=2"))
+                )["artists"]
+            }))
+            .unwrap();
+
+            let artists = SearchResults::from(first_page)
+                .artists()
+                .unwrap()
+                .into_stream_async(user_client())
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+
+            server.join().unwrap();
+
+            let names: Vec<_> = artists.iter().map(|artist| artist.name()).collect();
+            assert_eq!(names, ["One", "Two", "Three", "Four", "Five"]);
+        }
+
+        #[tokio::test]
+        async fn stream_single_page() {
+            let first_page: SearchResultsObject = serde_json::from_value(json!({
+                "artists": page_json(vec![artist_json("1111111111111111111111", "One")], 0, None)["artists"]
+            }))
+            .unwrap();
+
+            let artists = SearchResults::from(first_page)
+                .artists()
+                .unwrap()
+                .into_stream_async(user_client())
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+
+            assert_eq!(artists.len(), 1);
+        }
+
+        fn three_pages(base_url: &str) -> (SearchResultsObject, Vec<Value>) {
+            let first_page = serde_json::from_value(json!({
+                "artists": page_json(
+                    vec![
+                        artist_json("1111111111111111111111", "One"),
+                        artist_json("2222222222222222222222", "Two"),
+                    ],
+                    0,
+                    Some(format!("{base_url}/search?offset=2&limit=2"))
+                )["artists"]
+            }))
+            .unwrap();
+
+            let next_pages = vec![
+                page_json(
+                    vec![
+                        artist_json("3333333333333333333333", "Three"),
+                        artist_json("4444444444444444444444", "Four"),
+                    ],
+                    2,
+                    Some(format!("{base_url}/search?offset=4&limit=2")),
+                ),
+                page_json(vec![artist_json("5555555555555555555555", "Five")], 4, None),
+            ];
+
+            (first_page, next_pages)
+        }
+
+        #[tokio::test]
+        async fn collect_all_across_pages() {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let (first_page, next_pages) = three_pages(&format!("http://{}", listener.local_addr().unwrap()));
+            let server = serve_pages(listener, next_pages);
+
+            let artists = SearchResults::from(first_page)
+                .artists()
+                .unwrap()
+                .collect_all_async(user_client(), usize::MAX)
+                .await
+                .unwrap();
+
+            server.join().unwrap();
+
+            let names: Vec<_> = artists.iter().map(|artist| artist.name()).collect();
+            assert_eq!(names, ["One", "Two", "Three", "Four", "Five"]);
+        }
+
+        #[tokio::test]
+        async fn collect_all_stops_at_max_items() {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let (first_page, mut next_pages) = three_pages(&format!("http://{}", listener.local_addr().unwrap()));
+
+            // the last page must not be requested once the cap is reached in the second page
+            next_pages.truncate(1);
+            let server = serve_pages(listener, next_pages);
+
+            let artists = SearchResults::from(first_page)
+                .artists()
+                .unwrap()
+                .collect_all_async(user_client(), 3)
+                .await
+                .unwrap();
+
+            server.join().unwrap();
+
+            let names: Vec<_> = artists.iter().map(|artist| artist.name()).collect();
+            assert_eq!(names, ["One", "Two", "Three"]);
+        }
+    }
+
+    #[test]
+    fn followed_artists_cursor_page() {
+        let response: CursorPageResponse<FullArtist> = serde_json::from_value(json!({
+            "artists": {
+                "href": "https://api.spotify.com/v1/me/following?type=artist&limit=1",
+                "items": [
+                    {
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+                        },
+                        "followers": { "href": null, "total": 1224745 },
+                        "genres": ["j-pop", "vocaloid"],
+                        "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                        "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                        "images": [],
+                        "name": "Hatsune Miku",
+                        "popularity": 66,
+                        "type": "artist",
+                        "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                    }
+                ],
+                "limit": 1,
+                "next": "https://api.spotify.com/v1/me/following?type=artist&after=6pNgnvzBa6Bthsv8SrZJYl&limit=1",
+                "cursors": { "after": "6pNgnvzBa6Bthsv8SrZJYl" },
+                "total": 3
+            }
+        }))
+        .unwrap();
+
+        let page = CursorPage::from(response);
+
+        assert_eq!(page.items()[0].id().as_str(), "6pNgnvzBa6Bthsv8SrZJYl");
+        assert_eq!(page.items()[0].name(), "Hatsune Miku");
+        assert_eq!(page.total(), Some(3));
+        assert_eq!(page.cursors().unwrap().after.as_deref(), Some("6pNgnvzBa6Bthsv8SrZJYl"));
+        assert!(page.has_next());
+    }
 }
@@ -1,20 +1,27 @@
 //! Everything related to playback, such as the currently playing item and device it's playing on.
 
-use std::time::Duration;
+use std::{fmt, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
-use super::{id::PlayableContext, track::FullTrack, ExternalUrls, ItemType};
+use super::{
+    album::CommonAlbumInformation,
+    episode::FullEpisode,
+    id::{EpisodeId, Id, PlayableContext, PlayableItem, TrackId},
+    playlist::PlaylistItem,
+    track::{CommonTrackInformation, FullTrack, FullTrackInformation},
+    ExternalUrls, Timestamp,
+};
 use crate::{prelude::IdTrait, util::duration_millis};
 
 /// A device in an user's account that may be used for playback.
-#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Device {
     name: String,
-    // TODO: aspotify says this and the volume can be nonexistent for whatever reason but I haven't ever seen that
-    // happen so?
-    id: String,
-    volume_percent: u8,
+    id: Option<String>,
+    volume_percent: Option<u8>,
+    #[serde(default)]
+    supports_volume: bool,
     is_active: bool,
     is_private_session: bool,
     is_restricted: bool,
@@ -37,6 +44,8 @@ pub enum DeviceType {
     CastVideo,
     CastAudio,
     Automobile,
+    /// The device's type is unknown, either to Spotify or to this library.
+    #[serde(other)]
     Unknown,
 }
 
@@ -63,6 +72,21 @@ pub struct CurrentlyPlayingItem {
     public_playing_track: Option<PublicPlayingItem>,
 }
 
+/// A simplified view of the currently playing track, with its details ready to be displayed. Returned by the
+/// [now playing endpoint](crate::client::ScopedClient::now_playing), or from a
+/// [currently playing item](CurrentlyPlayingItem::now_playing).
+///
+/// Displaying the value formats it as `Artist – Track (1:23/3:45)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NowPlaying {
+    track_name: String,
+    artists: String,
+    album_name: String,
+    progress: Duration,
+    duration: Duration,
+    is_playing: bool,
+}
+
 /// A public playing item.
 ///
 /// Public refers to the playing item being available through the API. The item is not considered public when, but not
@@ -77,15 +101,54 @@ pub struct PublicPlayingItem {
 }
 
 /// The context of the current playback (i.e. album, artist, playlist or show).
+///
+/// The context's URI is parsed into the matching [typed ID](PlayableContext), which tells what the item is being played
+/// from.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Context {
     #[serde(rename = "type")]
-    context_type: ItemType,
+    context_type: ContextType,
+    #[serde(default)]
+    href: Option<String>,
     #[serde(default)]
     external_urls: ExternalUrls,
     uri: PlayableContext<'static>,
 }
 
+/// The type of a playback [Context].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextType {
+    Album,
+    Artist,
+    Playlist,
+    Show,
+    /// A user's Liked Songs playlist.
+    Collection,
+    /// A context type this library doesn't know of.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A track the user has played, returned by the [recently played
+/// endpoint](crate::client::ScopedClient::recently_played).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayHistory {
+    track: FullTrack,
+    played_at: Timestamp,
+    context: Option<Context>,
+}
+
+/// The user's playback queue, returned by the [queue endpoint](crate::client::ScopedClient::queue).
+///
+/// Both the currently playing item and the queued items are either tracks or episodes, so they're represented as
+/// [PlaylistItems](PlaylistItem).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaybackQueue {
+    currently_playing: Option<PlaylistItem>,
+    queue: Vec<PlaylistItem>,
+}
+
 /// What actions can be taken on the current playing item.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Actions {
@@ -124,10 +187,14 @@ pub struct Disallows {
 #[non_exhaustive]
 pub enum PlayingType {
     Track(FullTrack),
-    // TODO:
-    // Episode
-    // Ad
-    // Unknown
+    /// An episode. Spotify only includes the episode itself when it's explicitly asked for, which this library doesn't
+    /// do yet, so the episode is usually `None`.
+    Episode(Option<FullEpisode>),
+    /// An advertisement.
+    Ad,
+    /// An item type this library doesn't know of.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Possible item repeat states.
@@ -137,6 +204,22 @@ pub enum RepeatState {
     Off,
     Track,
     Context,
+    /// A repeat state this library doesn't know of. It can't be [set](crate::client::ScopedClient::set_repeat).
+    #[serde(other)]
+    Unknown,
+}
+
+/// Where in a context playback should start from, given to the [offset-function in the play context request
+/// builder](crate::client::request_builder::PlayContextRequestBuilder::offset).
+///
+/// A `u32` converts into a [position](PlaybackOffset::Position), and a [PlayableItem] or a track or an episode ID
+/// converts into an [item](PlaybackOffset::Item).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaybackOffset<'a> {
+    /// The zero-based index of the item in the context.
+    Position(u32),
+    /// An item in the context.
+    Item(PlayableItem<'a>),
 }
 
 impl Device {
@@ -145,16 +228,21 @@ impl Device {
         &self.name
     }
 
-    /// The device ID.
-    pub fn id(&self) -> &str {
-        &self.id
+    /// The device ID. Some devices, such as restricted devices, don't have an ID.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
     }
 
-    /// The current volume as a percentage between 0 and 100 inclusive.
-    pub fn volume_percent(&self) -> u8 {
+    /// The current volume as a percentage between 0 and 100 inclusive, if known.
+    pub fn volume_percent(&self) -> Option<u8> {
         self.volume_percent
     }
 
+    /// Whether the device's volume can be set.
+    pub fn supports_volume(&self) -> bool {
+        self.supports_volume
+    }
+
     /// If this device is the currently active device.
     pub fn is_active(&self) -> bool {
         self.is_active
@@ -177,12 +265,6 @@ impl Device {
     }
 }
 
-impl PartialEq for Device {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
-    }
-}
-
 impl PlaybackState {
     /// The device currently playing.
     pub fn device(&self) -> &Device {
@@ -240,6 +322,79 @@ impl CurrentlyPlayingItem {
     pub fn take_public_playing_item(self) -> Option<PublicPlayingItem> {
         self.public_playing_track
     }
+
+    /// A simplified view of the currently playing track. `None` if the playing item isn't
+    /// [public](Self::public_playing_item) or isn't a track.
+    pub fn now_playing(&self) -> Option<NowPlaying> {
+        let public_item = self.public_playing_item()?;
+        let PlayingType::Track(track) = public_item.item() else {
+            return None;
+        };
+
+        Some(NowPlaying {
+            track_name: track.name().to_owned(),
+            artists: track.artist_names_joined(", "),
+            album_name: track.album().name().to_owned(),
+            progress: public_item.progress(),
+            duration: track.duration(),
+            is_playing: self.is_playing,
+        })
+    }
+}
+
+impl NowPlaying {
+    /// The track's name.
+    pub fn track_name(&self) -> &str {
+        &self.track_name
+    }
+
+    /// The names of the track's artists, separated by commas.
+    pub fn artists(&self) -> &str {
+        &self.artists
+    }
+
+    /// The name of the track's album.
+    pub fn album_name(&self) -> &str {
+        &self.album_name
+    }
+
+    /// The track's playback progress.
+    pub fn progress(&self) -> Duration {
+        self.progress
+    }
+
+    /// The track's duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Whether or not the track is playing. `false` if playback is paused.
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+}
+
+impl fmt::Display for NowPlaying {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} – {} ({}/{})",
+            self.artists,
+            self.track_name,
+            MinutesAndSeconds(self.progress),
+            MinutesAndSeconds(self.duration)
+        )
+    }
+}
+
+/// Formats a duration as minutes and seconds, such as `3:45`.
+struct MinutesAndSeconds(Duration);
+
+impl fmt::Display for MinutesAndSeconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.0.as_secs();
+        write!(f, "{}:{:02}", seconds / 60, seconds % 60)
+    }
 }
 
 impl PublicPlayingItem {
@@ -265,29 +420,282 @@ impl PublicPlayingItem {
     }
 }
 
+impl PlayHistory {
+    /// The played track.
+    pub fn track(&self) -> &FullTrack {
+        &self.track
+    }
+
+    /// The played track. Take ownership of the track.
+    pub fn take_track(self) -> FullTrack {
+        self.track
+    }
+
+    /// When the track was played.
+    pub fn played_at(&self) -> Timestamp {
+        self.played_at
+    }
+
+    /// The context the track was played from, if any.
+    pub fn context(&self) -> Option<&Context> {
+        self.context.as_ref()
+    }
+}
+
+impl PlaybackQueue {
+    /// The currently playing item. `None` if there is no active playback.
+    pub fn currently_playing(&self) -> Option<&PlaylistItem> {
+        self.currently_playing.as_ref()
+    }
+
+    /// The items in the queue, in the order they will be played.
+    pub fn queue(&self) -> &[PlaylistItem] {
+        &self.queue
+    }
+
+    /// The items in the queue. Take ownership of the items.
+    pub fn take_queue(self) -> Vec<PlaylistItem> {
+        self.queue
+    }
+}
+
+impl From<u32> for PlaybackOffset<'_> {
+    fn from(position: u32) -> Self {
+        Self::Position(position)
+    }
+}
+
+impl<'a> From<PlayableItem<'a>> for PlaybackOffset<'a> {
+    fn from(item: PlayableItem<'a>) -> Self {
+        Self::Item(item)
+    }
+}
+
+impl<'a> From<Id<'a, TrackId>> for PlaybackOffset<'a> {
+    fn from(track: Id<'a, TrackId>) -> Self {
+        Self::Item(track.into())
+    }
+}
+
+impl<'a> From<Id<'a, EpisodeId>> for PlaybackOffset<'a> {
+    fn from(episode: Id<'a, EpisodeId>) -> Self {
+        Self::Item(episode.into())
+    }
+}
+
 impl RepeatState {
     pub fn as_str(self) -> &'static str {
         match self {
             RepeatState::Off => "off",
             RepeatState::Track => "track",
             RepeatState::Context => "context",
+            RepeatState::Unknown => "unknown",
         }
     }
 }
 
 impl Context {
+    /// The context's type.
+    pub fn context_type(&self) -> ContextType {
+        match self.uri {
+            PlayableContext::Album(_) => ContextType::Album,
+            PlayableContext::Artist(_) => ContextType::Artist,
+            PlayableContext::Playlist(_) => ContextType::Playlist,
+            PlayableContext::Show(_) => ContextType::Show,
+            PlayableContext::Collection(_) => ContextType::Collection,
+        }
+    }
+
+    /// A link to the context in the Web API, if Spotify returned one.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The external URLs for the context.
     pub fn external_urls(&self) -> &ExternalUrls {
         &self.external_urls
     }
 
-    pub fn id(&self) -> PlayableContext {
+    /// The context's typed ID.
+    pub fn id(&self) -> PlayableContext<'_> {
         self.uri.as_borrowed()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::SystemTime;
+
     use super::*;
+    use crate::model::{
+        episode::CommonEpisodeInformation,
+        track::{NonLocalTrackInformation, Track},
+    };
+
+    #[test]
+    fn deserialize_playback_state() {
+        let json = r#"{
+            "device": {
+                "id": "0123456789abcdef0123456789abcdef01234567",
+                "is_active": true,
+                "is_private_session": false,
+                "is_restricted": false,
+                "name": "Living Room",
+                "type": "Speaker",
+                "volume_percent": 42
+            },
+            "shuffle_state": true,
+            "repeat_state": "context",
+            "timestamp": 1668785224153,
+            "context": {
+                "external_urls": {
+                    "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P"
+                },
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                "type": "album",
+                "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "progress_ms": 12345,
+            "item": {
+                "album": {
+                    "album_type": "single",
+                    "artists": [],
+                    "external_urls": {},
+                    "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                    "images": [],
+                    "name": "World is Mine",
+                    "release_date": "2008-05-31",
+                    "release_date_precision": "day",
+                    "total_tracks": 1,
+                    "type": "album"
+                },
+                "artists": [],
+                "disc_number": 1,
+                "duration_ms": 254000,
+                "explicit": false,
+                "external_ids": {},
+                "external_urls": {},
+                "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                "is_local": false,
+                "name": "World is Mine",
+                "popularity": 60,
+                "preview_url": null,
+                "track_number": 1,
+                "type": "track"
+            },
+            "currently_playing_type": "track",
+            "actions": {
+                "disallows": {
+                    "resuming": true
+                }
+            },
+            "is_playing": true
+        }"#;
+
+        let state: PlaybackState = serde_json::from_str(json).unwrap();
+
+        assert_eq!(state.device().name(), "Living Room");
+        assert_eq!(state.device().device_type(), DeviceType::Speaker);
+        assert_eq!(state.device().volume_percent(), Some(42));
+        assert_eq!(state.repeat_state(), RepeatState::Context);
+        assert!(state.shuffle_state());
+
+        let currently_playing = state.currently_playing_item();
+        assert!(currently_playing.is_playing());
+        assert!(currently_playing.actions().disallows.resuming);
+        assert!(!currently_playing.actions().disallows.pausing);
+
+        let public_item = currently_playing.public_playing_item().unwrap();
+        assert_eq!(public_item.progress(), Duration::from_millis(12345));
+        assert!(matches!(public_item.context().unwrap().id(), PlayableContext::Album(_)));
+
+        let PlayingType::Track(track) = public_item.item() else {
+            panic!("expected a track, got {:?}", public_item.item());
+        };
+        assert_eq!(track.id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+    }
+
+    #[test]
+    fn now_playing() {
+        let json = r#"{
+            "timestamp": 1668785224153,
+            "context": null,
+            "progress_ms": 83456,
+            "item": {
+                "album": {
+                    "album_type": "single",
+                    "artists": [],
+                    "external_urls": {},
+                    "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                    "images": [],
+                    "name": "World is Mine",
+                    "release_date": "2008-05-31",
+                    "release_date_precision": "day",
+                    "total_tracks": 1,
+                    "type": "album"
+                },
+                "artists": [
+                    {
+                        "external_urls": {},
+                        "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                        "name": "ryo",
+                        "type": "artist",
+                        "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+                    },
+                    {
+                        "external_urls": {},
+                        "id": "6pNgnvzBa6Bthsv8SrZJYm",
+                        "name": "Hatsune Miku",
+                        "type": "artist",
+                        "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYm"
+                    }
+                ],
+                "disc_number": 1,
+                "duration_ms": 225000,
+                "explicit": false,
+                "external_ids": {},
+                "external_urls": {},
+                "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                "is_local": false,
+                "name": "World is Mine",
+                "popularity": 60,
+                "preview_url": null,
+                "track_number": 1,
+                "type": "track"
+            },
+            "currently_playing_type": "track",
+            "actions": { "disallows": {} },
+            "is_playing": false
+        }"#;
+
+        let item: CurrentlyPlayingItem = serde_json::from_str(json).unwrap();
+        let now_playing = item.now_playing().unwrap();
+
+        assert_eq!(now_playing.track_name(), "World is Mine");
+        assert_eq!(now_playing.artists(), "ryo, Hatsune Miku");
+        assert_eq!(now_playing.album_name(), "World is Mine");
+        assert_eq!(now_playing.progress(), Duration::from_millis(83456));
+        assert_eq!(now_playing.duration(), Duration::from_secs(225));
+        assert!(!now_playing.is_playing());
+        assert_eq!(now_playing.to_string(), "ryo, Hatsune Miku – World is Mine (1:23/3:45)");
+    }
+
+    #[test]
+    fn private_item_is_not_now_playing() {
+        let item: CurrentlyPlayingItem = serde_json::from_str(
+            r#"{ "timestamp": 1668785224153, "actions": { "disallows": {} }, "is_playing": true }"#,
+        )
+        .unwrap();
+
+        assert_eq!(item.now_playing(), None);
+    }
+
+    #[test]
+    fn repeat_state_serializes_as_snake_case() {
+        for state in [RepeatState::Off, RepeatState::Track, RepeatState::Context] {
+            assert_eq!(serde_json::to_value(state).unwrap(), state.as_str());
+        }
+    }
 
     #[test]
     fn deserialize_context_for_playlist() {
@@ -321,5 +729,256 @@ mod tests {
 
         assert!(matches!(context.uri, PlayableContext::Collection(_)));
         assert_eq!("1337420", context.uri.as_str());
+        assert_eq!(context.context_type(), ContextType::Collection);
+        assert_eq!(context.href(), Some("https://api.spotify.com/v1/me/tracks"));
+    }
+
+    #[test]
+    fn context_types() {
+        for (context_type, item_type, id) in [
+            (ContextType::Album, "album", "0tDsHtvN9YNuZjlqHvDY2P"),
+            (ContextType::Artist, "artist", "6pNgnvzBa6Bthsv8SrZJYl"),
+            (ContextType::Playlist, "playlist", "37i9dQZF1DWZipvLjDtZYe"),
+            (ContextType::Show, "show", "4rOoJ6Egrf8K2IrywzwOMk"),
+        ] {
+            let context: Context = serde_json::from_value(serde_json::json!({
+                "external_urls": {},
+                "href": format!("https://api.spotify.com/v1/{item_type}s/{id}"),
+                "type": item_type,
+                "uri": format!("spotify:{item_type}:{id}")
+            }))
+            .unwrap();
+
+            assert_eq!(context.context_type(), context_type);
+
+            match context.id() {
+                PlayableContext::Album(album) => assert_eq!(album.as_str(), id),
+                PlayableContext::Artist(artist) => assert_eq!(artist.as_str(), id),
+                PlayableContext::Playlist(playlist) => assert_eq!(playlist.as_str(), id),
+                PlayableContext::Show(show) => assert_eq!(show.as_str(), id),
+                PlayableContext::Collection(_) => panic!("unexpected collection context"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_devices() {
+        let json = r#"[
+            {
+                "id": "0123456789abcdef0123456789abcdef01234567",
+                "is_active": true,
+                "is_private_session": false,
+                "is_restricted": false,
+                "name": "Living Room",
+                "supports_volume": true,
+                "type": "Speaker",
+                "volume_percent": 42
+            },
+            {
+                "id": null,
+                "is_active": false,
+                "is_private_session": false,
+                "is_restricted": true,
+                "name": "Kitchen",
+                "supports_volume": false,
+                "type": "CastAudio",
+                "volume_percent": null
+            }
+        ]"#;
+
+        let devices: Vec<Device> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(devices[0].id(), Some("0123456789abcdef0123456789abcdef01234567"));
+        assert_eq!(devices[0].volume_percent(), Some(42));
+        assert!(devices[0].supports_volume());
+        assert!(devices[0].is_active());
+
+        assert_eq!(devices[1].id(), None);
+        assert_eq!(devices[1].volume_percent(), None);
+        assert!(!devices[1].supports_volume());
+        assert!(devices[1].is_restricted());
+        assert_eq!(devices[1].device_type(), DeviceType::CastAudio);
+    }
+
+    #[test]
+    fn deserialize_play_history() {
+        let json = r#"{
+            "track": {
+                "album": {
+                    "album_type": "single",
+                    "artists": [],
+                    "external_urls": {},
+                    "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                    "images": [],
+                    "name": "World is Mine",
+                    "release_date": "2008-05-31",
+                    "release_date_precision": "day",
+                    "total_tracks": 1,
+                    "type": "album"
+                },
+                "artists": [],
+                "disc_number": 1,
+                "duration_ms": 254000,
+                "explicit": false,
+                "external_ids": {},
+                "external_urls": {},
+                "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                "is_local": false,
+                "name": "World is Mine",
+                "popularity": 60,
+                "preview_url": null,
+                "track_number": 1,
+                "type": "track"
+            },
+            "played_at": "2016-12-13T20:44:04.589Z",
+            "context": null
+        }"#;
+
+        let history: PlayHistory = serde_json::from_str(json).unwrap();
+
+        assert_eq!(history.track().id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(
+            history
+                .played_at()
+                .as_system_time()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap(),
+            Duration::from_millis(1_481_661_844_589)
+        );
+        assert!(history.context().is_none());
+    }
+
+    #[test]
+    fn deserialize_mixed_queue() {
+        let json = r#"{
+            "currently_playing": null,
+            "queue": [
+                {
+                    "album": {
+                        "album_type": "single",
+                        "artists": [],
+                        "external_urls": {},
+                        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                        "images": [],
+                        "name": "World is Mine",
+                        "release_date": "2008-05-31",
+                        "release_date_precision": "day",
+                        "total_tracks": 1,
+                        "type": "album"
+                    },
+                    "artists": [],
+                    "disc_number": 1,
+                    "duration_ms": 254000,
+                    "explicit": false,
+                    "external_ids": {},
+                    "external_urls": {},
+                    "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                    "is_local": false,
+                    "name": "World is Mine",
+                    "popularity": 60,
+                    "preview_url": null,
+                    "track_number": 1,
+                    "type": "track"
+                },
+                {
+                    "audio_preview_url": null,
+                    "description": "A Spotify podcast sharing fresh insights on important topics of the moment.",
+                    "duration_ms": 1686230,
+                    "explicit": false,
+                    "external_urls": {},
+                    "id": "512ojhOuo1ktJprKbVcKyQ",
+                    "images": [],
+                    "is_externally_hosted": false,
+                    "name": "Starting Your Own Podcast",
+                    "release_date": "1981-12-15",
+                    "release_date_precision": "day",
+                    "show": {
+                        "copyrights": [],
+                        "description": "Candid conversations about the podcasting industry.",
+                        "explicit": false,
+                        "external_urls": {},
+                        "id": "38bS44xjbVVZ3No3ByF1dJ",
+                        "images": [],
+                        "is_externally_hosted": false,
+                        "languages": ["en"],
+                        "media_type": "audio",
+                        "name": "Spotify Sessions",
+                        "publisher": "Spotify",
+                        "total_episodes": 10,
+                        "type": "show"
+                    },
+                    "type": "episode"
+                }
+            ]
+        }"#;
+
+        let queue: PlaybackQueue = serde_json::from_str(json).unwrap();
+
+        assert!(queue.currently_playing().is_none());
+        assert_eq!(queue.queue().len(), 2);
+
+        match &queue.queue()[0] {
+            PlaylistItem::Track(Track::Full(track)) => assert_eq!(track.id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu"),
+            item => panic!("expected a track, got {item:?}"),
+        }
+
+        match &queue.queue()[1] {
+            PlaylistItem::Episode(episode) => assert_eq!(episode.id().as_str(), "512ojhOuo1ktJprKbVcKyQ"),
+            item => panic!("expected an episode, got {item:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_device_type_and_repeat_state() {
+        assert_eq!(
+            serde_json::from_str::<DeviceType>(r#""Toaster""#).unwrap(),
+            DeviceType::Unknown
+        );
+        assert_eq!(
+            serde_json::from_str::<RepeatState>(r#""shuffle_repeat""#).unwrap(),
+            RepeatState::Unknown
+        );
+    }
+
+    #[test]
+    fn unknown_context_type() {
+        assert_eq!(
+            serde_json::from_str::<ContextType>(r#""audiobook_collection""#).unwrap(),
+            ContextType::Unknown
+        );
+
+        let context: Context = serde_json::from_value(serde_json::json!({
+            "external_urls": {},
+            "type": "smart_shuffle",
+            "uri": "spotify:playlist:37i9dQZF1DWZipvLjDtZYe"
+        }))
+        .unwrap();
+
+        assert_eq!(context.context_type, ContextType::Unknown);
+        assert_eq!(context.context_type(), ContextType::Playlist);
+    }
+
+    #[test]
+    fn unknown_playing_type() {
+        let item: PlayingType = serde_json::from_value(serde_json::json!({
+            "currently_playing_type": "audiobook",
+            "item": null
+        }))
+        .unwrap();
+        assert_eq!(item, PlayingType::Unknown);
+
+        let item: PlayingType = serde_json::from_value(serde_json::json!({
+            "currently_playing_type": "ad",
+            "item": null
+        }))
+        .unwrap();
+        assert_eq!(item, PlayingType::Ad);
+
+        let item: PlayingType = serde_json::from_value(serde_json::json!({
+            "currently_playing_type": "episode",
+            "item": null
+        }))
+        .unwrap();
+        assert_eq!(item, PlayingType::Episode(None));
     }
 }
@@ -0,0 +1,758 @@
+//! Everything related to playlists.
+//!
+//! Contains the two different kinds of playlists; [FullPlaylist] and [PartialPlaylist].
+//!
+//! - [FullPlaylist]: contains all information about a playlist, including the first page of its items. Retrieved from
+//!   the [playlist](crate::client::UnscopedClient::playlist)-function, or when creating a playlist with the
+//!   [create_playlist](crate::client::ScopedClient::create_playlist)-function.
+//! - [PartialPlaylist]: contains most information about a playlist, but only a reference to its tracks. Retrieved from
+//!   playlist listings, such as the [current_user_playlists](crate::client::ScopedClient::current_user_playlists)- and
+//!   [user_playlists](crate::client::UnscopedClient::user_playlists)-functions.
+//!
+//! A playlist's items are [PlaylistItemWrappers](PlaylistItemWrapper), which contain the [PlaylistItem] itself along
+//! with information about when and by whom it was added. An item may be either a track or an episode.
+//!
+//! # Playlist equality
+//!
+//! Two playlists are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use serde::{Deserialize, Serialize};
+
+    use crate::model::{
+        episode::EpisodeObject,
+        id::{Id, PlaylistId},
+        object_type::{object_type_serialize, TypePlaylist},
+        track::TrackObject,
+        user::PublicUser,
+        ExternalUrls, Image, Timestamp,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonPlaylistFields;
+    }
+
+    /// A playlist item is either a track or an episode object. They're told apart by their type field.
+    #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+    #[serde(untagged)]
+    pub(crate) enum PlaylistItemObject {
+        Track(TrackObject),
+        Episode(EpisodeObject),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+    pub(crate) struct PlaylistItemWrapperObject {
+        #[serde(default)]
+        pub(crate) added_at: Option<Timestamp>,
+        pub(crate) added_by: Option<PublicUser>,
+        #[serde(default)]
+        pub(crate) is_local: bool,
+        pub(crate) track: Option<PlaylistItemObject>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonPlaylistFields {
+        pub(crate) name: String,
+        pub(crate) id: Id<'static, PlaylistId>,
+        pub(crate) owner: PublicUser,
+        pub(crate) public: Option<bool>,
+        pub(crate) collaborative: bool,
+        pub(crate) description: Option<String>,
+        // Spotify may return null instead of an empty list for playlists without images
+        pub(crate) images: Option<Vec<Image>>,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        pub(crate) snapshot_id: String,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypePlaylist,
+    }
+}
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use self::private::{CommonPlaylistFields, PlaylistItemObject, PlaylistItemWrapperObject};
+use super::{
+    episode::{Episode, FullEpisode},
+    id::{Id, IdTrait, PlaylistId},
+    page::{Page, PageInformation, PageObject},
+    track::{LocalTrack, Track},
+    user::PublicUser,
+    ExternalUrls, Followers, Image, Timestamp,
+};
+use crate::error::ConversionError;
+
+/// Functions for retrieving information that is common to every playlist type.
+pub trait CommonPlaylistInformation: crate::private::Sealed {
+    /// The playlist's name.
+    fn name(&self) -> &str;
+    /// The playlist's Spotify ID.
+    fn id(&self) -> Id<'_, PlaylistId>;
+    /// The user who owns the playlist.
+    fn owner(&self) -> &PublicUser;
+    /// Whether the playlist is public. `None` if the playlist's status isn't relevant, such as for a collaborative
+    /// playlist.
+    fn public(&self) -> Option<bool>;
+    /// Whether other users may modify the playlist.
+    fn collaborative(&self) -> bool;
+    /// The playlist's description, if it has one.
+    fn description(&self) -> Option<&str>;
+    /// The images for the playlist.
+    fn images(&self) -> &[Image];
+    /// The external URLs for the playlist.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The playlist's version identifier.
+    fn snapshot_id(&self) -> &str;
+}
+
+impl<T> CommonPlaylistInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn id(&self) -> Id<'_, PlaylistId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn owner(&self) -> &PublicUser {
+        &self.common_fields().owner
+    }
+
+    fn public(&self) -> Option<bool> {
+        self.common_fields().public
+    }
+
+    fn collaborative(&self) -> bool {
+        self.common_fields().collaborative
+    }
+
+    fn description(&self) -> Option<&str> {
+        self.common_fields().description.as_deref()
+    }
+
+    fn images(&self) -> &[Image] {
+        self.common_fields().images.as_deref().unwrap_or_default()
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn snapshot_id(&self) -> &str {
+        &self.common_fields().snapshot_id
+    }
+}
+
+/// A playlist with all available information.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct FullPlaylist {
+    #[serde(flatten)]
+    common: CommonPlaylistFields,
+    followers: Followers,
+    tracks: PlaylistItems,
+}
+
+/// A playlist with a reference to its tracks instead of the tracks themselves.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct PartialPlaylist {
+    #[serde(flatten)]
+    common: CommonPlaylistFields,
+    tracks: PlaylistTracksReference,
+}
+
+/// A reference to a playlist's tracks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistTracksReference {
+    // the API includes a href to the playlist's tracks, but it's redundant with the playlist's ID so it's not included
+    // here
+    /// The total amount of tracks in the playlist.
+    pub total: u32,
+}
+
+/// An item in a playlist; either a track or an episode.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "PlaylistItemObject")]
+pub enum PlaylistItem {
+    Track(Track),
+    Episode(Box<FullEpisode>),
+}
+
+/// An item in a playlist, along with information about when and by whom it was added.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "PlaylistItemWrapperObject")]
+pub struct PlaylistItemWrapper {
+    added_at: Option<Timestamp>,
+    added_by: Option<PublicUser>,
+    is_local: bool,
+    item: Option<PlaylistItem>,
+}
+
+/// This struct's only purpose is to make serializing more efficient by holding only references to its data.
+#[derive(Serialize)]
+struct PlaylistItemWrapperRef<'a> {
+    added_at: &'a Option<Timestamp>,
+    added_by: &'a Option<PublicUser>,
+    is_local: bool,
+    track: &'a Option<PlaylistItem>,
+}
+
+/// A page of items in a playlist.
+///
+/// This object is retrieved only through the [tracks](FullPlaylist::tracks)- and
+/// [playlist_items](crate::client::UnscopedClient::playlist_items)-functions. You won't be interacting objects of this
+/// type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct PlaylistItems {
+    #[serde(flatten)]
+    page: PageObject<PlaylistItemWrapper>,
+}
+
+/// A page of playlists.
+///
+/// This object is retrieved only through the playlist listing functions, such as
+/// [current_user_playlists](crate::client::ScopedClient::current_user_playlists). You won't be interacting objects of
+/// this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct Playlists {
+    #[serde(flatten)]
+    page: PageObject<PartialPlaylist>,
+}
+
+impl FullPlaylist {
+    /// Information about the playlist's followers.
+    pub fn followers(&self) -> &Followers {
+        &self.followers
+    }
+
+    /// The first page of the playlist's items.
+    pub fn tracks(&self) -> Page<PlaylistItems, PlaylistItemWrapper> {
+        Page {
+            inner: self.tracks.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl PartialPlaylist {
+    /// A reference to the playlist's tracks.
+    pub fn tracks(&self) -> PlaylistTracksReference {
+        self.tracks
+    }
+}
+
+impl PlaylistItemWrapper {
+    /// When the item was added. Very old playlists may not have this information.
+    pub fn added_at(&self) -> Option<Timestamp> {
+        self.added_at
+    }
+
+    /// The user who added the item. Very old playlists may not have this information.
+    pub fn added_by(&self) -> Option<&PublicUser> {
+        self.added_by.as_ref()
+    }
+
+    /// Whether the item is a local track.
+    pub fn is_local(&self) -> bool {
+        self.is_local
+    }
+
+    /// The item. `None` if the item is no longer available.
+    pub fn item(&self) -> Option<&PlaylistItem> {
+        self.item.as_ref()
+    }
+
+    /// The item. Take ownership of the item.
+    pub fn take_item(self) -> Option<PlaylistItem> {
+        self.item
+    }
+}
+
+impl TryFrom<PlaylistItemObject> for PlaylistItem {
+    type Error = ConversionError;
+
+    fn try_from(obj: PlaylistItemObject) -> Result<Self, Self::Error> {
+        match obj {
+            PlaylistItemObject::Track(track) => Ok(Self::Track(track.try_into()?)),
+            PlaylistItemObject::Episode(episode) => match Episode::from(episode) {
+                Episode::Full(full) => Ok(Self::Episode(full)),
+                Episode::Partial(_) => Err(ConversionError(
+                    "attempt to convert partial episode object into playlist item".into(),
+                )),
+            },
+        }
+    }
+}
+
+impl TryFrom<PlaylistItemWrapperObject> for PlaylistItemWrapper {
+    type Error = ConversionError;
+
+    fn try_from(obj: PlaylistItemWrapperObject) -> Result<Self, Self::Error> {
+        let item = match obj.track {
+            // a local track has only the information common to every track, regardless of which fields happen to be
+            // set in it
+            Some(PlaylistItemObject::Track(track)) if obj.is_local => {
+                Some(PlaylistItem::Track(Track::Local(Box::new(LocalTrack::from(track)))))
+            }
+
+            Some(item) => Some(item.try_into()?),
+            None => None,
+        };
+
+        Ok(Self {
+            added_at: obj.added_at,
+            added_by: obj.added_by,
+            is_local: obj.is_local,
+            item,
+        })
+    }
+}
+
+impl Serialize for PlaylistItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PlaylistItem::Track(track) => track.serialize(serializer),
+            PlaylistItem::Episode(episode) => episode.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for PlaylistItemWrapper {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PlaylistItemWrapperRef {
+            added_at: &self.added_at,
+            added_by: &self.added_by,
+            is_local: self.is_local,
+            track: &self.item,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl private::CommonFields for FullPlaylist {
+    fn common_fields(&self) -> &CommonPlaylistFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialPlaylist {
+    fn common_fields(&self) -> &CommonPlaylistFields {
+        &self.common
+    }
+}
+
+impl crate::private::Sealed for FullPlaylist {}
+impl crate::private::Sealed for PartialPlaylist {}
+
+impl PartialEq for FullPlaylist {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialPlaylist {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialPlaylist> for FullPlaylist {
+    fn eq(&self, other: &PartialPlaylist) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullPlaylist> for PartialPlaylist {
+    fn eq(&self, other: &FullPlaylist) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl From<Playlists> for Page<Playlists, PartialPlaylist> {
+    fn from(page: Playlists) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for Playlists {}
+
+impl PageInformation<PartialPlaylist> for Playlists {
+    type Items = Vec<PartialPlaylist>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<PartialPlaylist> as PageInformation<PartialPlaylist>>::has_previous(&self.page)
+    }
+}
+
+impl From<PlaylistItems> for Page<PlaylistItems, PlaylistItemWrapper> {
+    fn from(page: PlaylistItems) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for PlaylistItems {}
+
+impl PageInformation<PlaylistItemWrapper> for PlaylistItems {
+    type Items = Vec<PlaylistItemWrapper>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PlaylistItemWrapper> as PageInformation<PlaylistItemWrapper>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PlaylistItemWrapper> as PageInformation<PlaylistItemWrapper>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PlaylistItemWrapper> as PageInformation<PlaylistItemWrapper>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PlaylistItemWrapper> as PageInformation<PlaylistItemWrapper>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<PlaylistItemWrapper> as PageInformation<PlaylistItemWrapper>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<PlaylistItemWrapper> as PageInformation<PlaylistItemWrapper>>::has_previous(&self.page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::model::{track::CommonTrackInformation, user::CommonUserInformation};
+
+    fn playlists_json() -> Value {
+        json!({
+            "href": "https://api.spotify.com/v1/users/smedjan/playlists?offset=0&limit=2",
+            "items": [
+                {
+                    "collaborative": false,
+                    "description": "The best of vocaloid.",
+                    "external_urls": {
+                        "spotify": "https://open.spotify.com/playlist/37i9dQZF1DX6R7QUWePReA"
+                    },
+                    "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DX6R7QUWePReA",
+                    "id": "37i9dQZF1DX6R7QUWePReA",
+                    "images": [
+                        {
+                            "height": null,
+                            "url": "https://i.scdn.co/image/ab67706f00000003e8e28219724c2423afa4d320",
+                            "width": null
+                        }
+                    ],
+                    "name": "Vocaloid Hits",
+                    "owner": {
+                        "display_name": "Spotify",
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/user/spotify"
+                        },
+                        "href": "https://api.spotify.com/v1/users/spotify",
+                        "id": "spotify",
+                        "type": "user",
+                        "uri": "spotify:user:spotify"
+                    },
+                    "primary_color": null,
+                    "public": true,
+                    "snapshot_id": "MTY3ODg5NjAwMCwwMDAwMDAwMGQ0MWQ4Y2Q5OGYwMGIyMDRlOTgwMDk5OGVjZjg0Mjdl",
+                    "tracks": {
+                        "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DX6R7QUWePReA/tracks",
+                        "total": 50
+                    },
+                    "type": "playlist",
+                    "uri": "spotify:playlist:37i9dQZF1DX6R7QUWePReA"
+                },
+                {
+                    "collaborative": true,
+                    "description": null,
+                    "external_urls": {},
+                    "href": "https://api.spotify.com/v1/playlists/3cEYpjA9oz9GiPac4AsH4n",
+                    "id": "3cEYpjA9oz9GiPac4AsH4n",
+                    "images": null,
+                    "name": "Shared",
+                    "owner": {
+                        "display_name": null,
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/users/smedjan",
+                        "id": "smedjan",
+                        "type": "user",
+                        "uri": "spotify:user:smedjan"
+                    },
+                    "public": null,
+                    "snapshot_id": "AAAAAtvkoqvA8NtwY4vY7EdLvMr3nVLt",
+                    "tracks": {
+                        "href": "https://api.spotify.com/v1/playlists/3cEYpjA9oz9GiPac4AsH4n/tracks",
+                        "total": 0
+                    },
+                    "type": "playlist",
+                    "uri": "spotify:playlist:3cEYpjA9oz9GiPac4AsH4n"
+                }
+            ],
+            "limit": 2,
+            "next": "https://api.spotify.com/v1/users/smedjan/playlists?offset=2&limit=2",
+            "offset": 0,
+            "previous": null,
+            "total": 9
+        })
+    }
+
+    #[test]
+    fn deserialize_playlist_listing() {
+        let page = Page::from(serde_json::from_value::<Playlists>(playlists_json()).unwrap());
+        let playlists = page.items();
+
+        assert_eq!(page.total(), 9);
+        assert_eq!(page.next_offset(), Some(2));
+
+        let vocaloid = &playlists[0];
+        assert_eq!(vocaloid.name(), "Vocaloid Hits");
+        assert_eq!(vocaloid.id().as_str(), "37i9dQZF1DX6R7QUWePReA");
+        assert_eq!(vocaloid.owner().id().as_str(), "spotify");
+        assert_eq!(vocaloid.owner().display_name(), Some("Spotify"));
+        assert_eq!(vocaloid.public(), Some(true));
+        assert!(!vocaloid.collaborative());
+        assert_eq!(vocaloid.description(), Some("The best of vocaloid."));
+        assert_eq!(vocaloid.images().len(), 1);
+        assert_eq!(vocaloid.tracks().total, 50);
+
+        let shared = &playlists[1];
+        assert_eq!(shared.public(), None);
+        assert!(shared.collaborative());
+        assert_eq!(shared.description(), None);
+        assert!(shared.images().is_empty());
+        assert_eq!(shared.snapshot_id(), "AAAAAtvkoqvA8NtwY4vY7EdLvMr3nVLt");
+    }
+
+    #[test]
+    fn playlist_equality() {
+        let page = serde_json::from_value::<Playlists>(playlists_json()).unwrap();
+        let playlists = Page::from(page).take_items();
+
+        let mut renamed = playlists[0].clone();
+        renamed.common.name = "Renamed".to_owned();
+
+        assert_eq!(playlists[0], renamed);
+        assert_ne!(playlists[0], playlists[1]);
+    }
+
+    #[test]
+    fn deserialize_full_playlist() {
+        let playlist: FullPlaylist = serde_json::from_value(json!({
+            "collaborative": false,
+            "description": "New playlist description",
+            "external_urls": {
+                "spotify": "https://open.spotify.com/playlist/7d2D2S200NyUE5KYs80PwO"
+            },
+            "followers": { "href": null, "total": 0 },
+            "href": "https://api.spotify.com/v1/playlists/7d2D2S200NyUE5KYs80PwO",
+            "id": "7d2D2S200NyUE5KYs80PwO",
+            "images": [],
+            "name": "New Playlist",
+            "owner": {
+                "display_name": "smedjan",
+                "external_urls": {},
+                "href": "https://api.spotify.com/v1/users/smedjan",
+                "id": "smedjan",
+                "type": "user",
+                "uri": "spotify:user:smedjan"
+            },
+            "public": false,
+            "snapshot_id": "MSw1ZmE5MDRjZmQ3NDQ5NDg2ZDE2NDQ4YTljZDEwOTkyYmYwZTU3MjRj",
+            "tracks": {
+                "href": "https://api.spotify.com/v1/playlists/7d2D2S200NyUE5KYs80PwO/tracks",
+                "items": [],
+                "limit": 100,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 0
+            },
+            "type": "playlist",
+            "uri": "spotify:playlist:7d2D2S200NyUE5KYs80PwO"
+        }))
+        .unwrap();
+
+        assert_eq!(playlist.name(), "New Playlist");
+        assert_eq!(playlist.public(), Some(false));
+        assert_eq!(playlist.followers().total(), 0);
+        assert_eq!(playlist.tracks().total(), 0);
+    }
+
+    #[test]
+    fn deserialize_playlist_items() {
+        let page: PlaylistItems = serde_json::from_value(json!({
+            "href": "https://api.spotify.com/v1/playlists/3cEYpjA9oz9GiPac4AsH4n/tracks?offset=0&limit=100",
+            "items": [
+                {
+                    "added_at": "2016-12-13T20:44:04Z",
+                    "added_by": {
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/users/smedjan",
+                        "id": "smedjan",
+                        "type": "user",
+                        "uri": "spotify:user:smedjan"
+                    },
+                    "is_local": false,
+                    "track": {
+                        "album": {
+                            "album_type": "single",
+                            "artists": [],
+                            "external_urls": {},
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "World is Mine",
+                            "release_date": "2008-05-31",
+                            "release_date_precision": "day",
+                            "total_tracks": 1,
+                            "type": "album"
+                        },
+                        "artists": [],
+                        "disc_number": 1,
+                        "duration_ms": 254000,
+                        "explicit": false,
+                        "external_ids": {},
+                        "external_urls": {},
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "name": "World is Mine",
+                        "popularity": 60,
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track"
+                    }
+                },
+                {
+                    "added_at": "2021-05-01T12:00:00Z",
+                    "added_by": {
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/users/smedjan",
+                        "id": "smedjan",
+                        "type": "user",
+                        "uri": "spotify:user:smedjan"
+                    },
+                    "is_local": true,
+                    "track": {
+                        "album": {
+                            "album_type": null,
+                            "artists": [],
+                            "external_urls": {},
+                            "id": null,
+                            "images": [],
+                            "name": "Supercell",
+                            "release_date": null,
+                            "release_date_precision": null,
+                            "type": "album"
+                        },
+                        "artists": [{
+                            "external_urls": {},
+                            "name": "Hatsune Miku",
+                            "type": "artist"
+                        }],
+                        "disc_number": 0,
+                        "duration_ms": 254000,
+                        "explicit": false,
+                        "external_ids": {},
+                        "external_urls": {},
+                        "id": null,
+                        "is_local": true,
+                        "name": "Melt",
+                        "popularity": 0,
+                        "preview_url": null,
+                        "track_number": 0,
+                        "type": "track"
+                    }
+                },
+                {
+                    "added_at": null,
+                    "added_by": null,
+                    "is_local": false,
+                    "track": null
+                }
+            ],
+            "limit": 100,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 3
+        }))
+        .unwrap();
+
+        let items = Page::from(page).take_items();
+
+        let track = &items[0];
+        assert!(!track.is_local());
+        assert_eq!(track.added_by().unwrap().id().as_str(), "smedjan");
+        assert!(track.added_at().is_some());
+        assert!(matches!(track.item(), Some(PlaylistItem::Track(Track::Full(_)))));
+
+        let local = &items[1];
+        assert!(local.is_local());
+        match local.item() {
+            Some(PlaylistItem::Track(Track::Local(track))) => assert_eq!(track.name(), "Melt"),
+            item => panic!("expected a local track, got {item:?}"),
+        }
+
+        let unavailable = &items[2];
+        assert!(unavailable.added_at().is_none());
+        assert!(unavailable.added_by().is_none());
+        assert!(unavailable.item().is_none());
+    }
+}
@@ -0,0 +1,210 @@
+//! Contains the [Recommendations] object returned by the
+//! [recommendations](crate::client::UnscopedClient::recommendations)-function, and the [TunableAttribute] enum used to
+//! tune the recommendations.
+
+use serde::{Deserialize, Serialize};
+
+use super::track::FullTrack;
+
+macro_rules! tunable_attributes {
+    ($(#[$meta:meta])* pub enum $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $attribute:literal,)* }) => {
+        $(#[$meta])*
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl $name {
+            /// Returns the attribute's name as a string.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $attribute,)*
+                }
+            }
+
+            #[cfg(any(feature = "async", feature = "sync"))]
+            pub(crate) fn min_query(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => concat!("min_", $attribute),)*
+                }
+            }
+
+            #[cfg(any(feature = "async", feature = "sync"))]
+            pub(crate) fn max_query(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => concat!("max_", $attribute),)*
+                }
+            }
+
+            #[cfg(any(feature = "async", feature = "sync"))]
+            pub(crate) fn target_query(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => concat!("target_", $attribute),)*
+                }
+            }
+        }
+    };
+}
+
+tunable_attributes! {
+    /// A track attribute recommendations may be tuned with. See [AudioFeatures](super::audio_features::AudioFeatures)
+    /// for what each attribute describes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TunableAttribute {
+        Acousticness => "acousticness",
+        Danceability => "danceability",
+        /// The track's duration in milliseconds.
+        DurationMs => "duration_ms",
+        Energy => "energy",
+        Instrumentalness => "instrumentalness",
+        Key => "key",
+        Liveness => "liveness",
+        Loudness => "loudness",
+        Mode => "mode",
+        /// The track's popularity between 0 and 100.
+        Popularity => "popularity",
+        Speechiness => "speechiness",
+        Tempo => "tempo",
+        TimeSignature => "time_signature",
+        Valence => "valence",
+    }
+}
+
+/// A list of recommended tracks and the seeds they were generated from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recommendations {
+    seeds: Vec<RecommendationSeed>,
+    tracks: Vec<FullTrack>,
+}
+
+/// A seed the recommendations were generated from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecommendationSeed {
+    /// The Spotify ID of the seed artist or track, or the name of the seed genre.
+    pub id: String,
+    /// The seed's type.
+    #[serde(rename = "type")]
+    pub seed_type: SeedType,
+    /// A link to the seed artist or track in the Web API. `None` for genre seeds.
+    pub href: Option<String>,
+    /// The number of tracks available after the min and max filters have been applied.
+    #[serde(rename = "afterFilteringSize")]
+    pub after_filtering_size: u32,
+    /// The number of tracks available after relinking for regional availability.
+    #[serde(rename = "afterRelinkingSize")]
+    pub after_relinking_size: u32,
+    /// The number of recommended tracks available for this seed.
+    #[serde(rename = "initialPoolSize")]
+    pub initial_pool_size: u32,
+}
+
+/// The type of a recommendation seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SeedType {
+    #[serde(alias = "artist")]
+    Artist,
+    #[serde(alias = "track")]
+    Track,
+    #[serde(alias = "genre")]
+    Genre,
+    /// A seed type this library doesn't know of.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Recommendations {
+    /// The seeds the recommendations were generated from.
+    pub fn seeds(&self) -> &[RecommendationSeed] {
+        &self.seeds
+    }
+
+    /// The recommended tracks.
+    pub fn tracks(&self) -> &[FullTrack] {
+        &self.tracks
+    }
+
+    /// The recommended tracks. Take ownership of the tracks.
+    pub fn take_tracks(self) -> Vec<FullTrack> {
+        self.tracks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_recommendations() {
+        let recommendations: Recommendations = serde_json::from_value(json!({
+            "seeds": [
+                {
+                    "afterFilteringSize": 250,
+                    "afterRelinkingSize": 250,
+                    "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+                    "id": "6pNgnvzBa6Bthsv8SrZJYl",
+                    "initialPoolSize": 250,
+                    "type": "ARTIST"
+                },
+                {
+                    "afterFilteringSize": 118,
+                    "afterRelinkingSize": 118,
+                    "href": null,
+                    "id": "j-pop",
+                    "initialPoolSize": 250,
+                    "type": "GENRE"
+                }
+            ],
+            "tracks": []
+        }))
+        .unwrap();
+
+        let seeds = recommendations.seeds();
+        assert_eq!(seeds[0].seed_type, SeedType::Artist);
+        assert_eq!(seeds[1].id, "j-pop");
+        assert_eq!(seeds[1].seed_type, SeedType::Genre);
+        assert_eq!(seeds[1].after_filtering_size, 118);
+        assert_eq!(seeds[1].href, None);
+        assert!(recommendations.tracks().is_empty());
+    }
+
+    #[test]
+    fn seed_pool_sizes() {
+        let value = json!({
+            "afterFilteringSize": 12,
+            "afterRelinkingSize": 9,
+            "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+            "id": "2pDPOMX0kWA7kcPBcDCQBu",
+            "initialPoolSize": 250,
+            "type": "TRACK"
+        });
+
+        let seed: RecommendationSeed = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(seed.seed_type, SeedType::Track);
+        assert_eq!(
+            seed.href.as_deref(),
+            Some("https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu")
+        );
+        assert_eq!(seed.initial_pool_size, 250);
+        assert_eq!(seed.after_filtering_size, 12);
+        assert_eq!(seed.after_relinking_size, 9);
+        assert_eq!(serde_json::to_value(&seed).unwrap(), value);
+    }
+
+    #[test]
+    fn tunable_attribute_queries() {
+        assert_eq!(TunableAttribute::Energy.min_query(), "min_energy");
+        assert_eq!(TunableAttribute::TimeSignature.max_query(), "max_time_signature");
+        assert_eq!(TunableAttribute::DurationMs.target_query(), "target_duration_ms");
+    }
+
+    #[test]
+    fn unknown_seed_type() {
+        assert_eq!(
+            serde_json::from_value::<SeedType>(json!("PLAYLIST")).unwrap(),
+            SeedType::Unknown
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Contains the [ReleaseDate] struct.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::DatePrecision;
+use crate::error::ConversionError;
+
+/// The date an album or an episode was released.
+///
+/// Spotify knows some release dates only to the year or to the month. The date's [precision](ReleaseDate::precision)
+/// tells which parts of it are known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "ReleaseDateObject", into = "ReleaseDateObject")]
+pub struct ReleaseDate {
+    year: i32,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+/// The release date fields Spotify returns in an object.
+#[derive(Serialize, Deserialize)]
+struct ReleaseDateObject {
+    release_date: String,
+    release_date_precision: DatePrecision,
+}
+
+impl ReleaseDate {
+    /// The year of the release date.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month of the release date, from 1 to 12. `None` if the date's precision is a year.
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// The day of the month of the release date, from 1 to 31. `None` if the date's precision is a year or a month.
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// The release date's precision.
+    pub fn precision(&self) -> DatePrecision {
+        match (self.month, self.day) {
+            (Some(_), Some(_)) => DatePrecision::Day,
+            (Some(_), None) => DatePrecision::Month,
+            _ => DatePrecision::Year,
+        }
+    }
+
+    fn parse(date: &str, precision: DatePrecision) -> Option<Self> {
+        let mut parts = date.splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next().map(str::parse::<u8>).transpose().ok()?;
+        let day = parts.next().map(str::parse::<u8>).transpose().ok()?;
+
+        if month.is_some_and(|month| !(1..=12).contains(&month)) || day.is_some_and(|day| !(1..=31).contains(&day)) {
+            return None;
+        }
+
+        // the date may have more parts than its precision says, in which case the extra parts are ignored
+        match precision {
+            DatePrecision::Year => Some(Self {
+                year,
+                month: None,
+                day: None,
+            }),
+
+            DatePrecision::Month => Some(Self {
+                year,
+                month: Some(month?),
+                day: None,
+            }),
+
+            DatePrecision::Day => Some(Self {
+                year,
+                month: Some(month?),
+                day: Some(day?),
+            }),
+
+            // without knowing the precision, the date is as precise as its parts are
+            DatePrecision::Unknown => Some(Self {
+                year,
+                month,
+                day: month.and(day),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    /// Formats the release date as an ISO 8601 date with only the parts its precision allows, such as `1981`, `1981-12`
+    /// or `1981-12-15`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+
+        if let Some(month) = self.month {
+            write!(f, "-{month:02}")?;
+        }
+
+        if let Some(day) = self.day {
+            write!(f, "-{day:02}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<ReleaseDateObject> for ReleaseDate {
+    type Error = ConversionError;
+
+    fn try_from(obj: ReleaseDateObject) -> Result<Self, Self::Error> {
+        Self::parse(&obj.release_date, obj.release_date_precision).ok_or_else(|| {
+            ConversionError(
+                format!(
+                    "invalid release date {} with precision {:?}",
+                    obj.release_date, obj.release_date_precision
+                )
+                .into(),
+            )
+        })
+    }
+}
+
+impl From<ReleaseDate> for ReleaseDateObject {
+    fn from(date: ReleaseDate) -> Self {
+        Self {
+            release_date: date.to_string(),
+            release_date_precision: date.precision(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn release_date(date: &str, precision: &str) -> Result<ReleaseDate, serde_json::Error> {
+        serde_json::from_value(json!({ "release_date": date, "release_date_precision": precision }))
+    }
+
+    #[test]
+    fn year_precision() {
+        let date = release_date("1981", "year").unwrap();
+
+        assert_eq!(date.year(), 1981);
+        assert_eq!(date.month(), None);
+        assert_eq!(date.day(), None);
+        assert_eq!(date.precision(), DatePrecision::Year);
+        assert_eq!(date.to_string(), "1981");
+    }
+
+    #[test]
+    fn month_precision() {
+        let date = release_date("1981-12", "month").unwrap();
+
+        assert_eq!(date.year(), 1981);
+        assert_eq!(date.month(), Some(12));
+        assert_eq!(date.day(), None);
+        assert_eq!(date.precision(), DatePrecision::Month);
+        assert_eq!(date.to_string(), "1981-12");
+    }
+
+    #[test]
+    fn day_precision() {
+        let date = release_date("1981-12-15", "day").unwrap();
+
+        assert_eq!(date.year(), 1981);
+        assert_eq!(date.month(), Some(12));
+        assert_eq!(date.day(), Some(15));
+        assert_eq!(date.precision(), DatePrecision::Day);
+        assert_eq!(date.to_string(), "1981-12-15");
+    }
+
+    #[test]
+    fn extra_parts_are_ignored() {
+        let date = release_date("1981-12-15", "year").unwrap();
+        assert_eq!(date.to_string(), "1981");
+    }
+
+    #[test]
+    fn unknown_precision_uses_every_part() {
+        let date = release_date("1981-12", "week").unwrap();
+
+        assert_eq!(date.precision(), DatePrecision::Month);
+        assert_eq!(date.to_string(), "1981-12");
+        assert_eq!(
+            serde_json::from_value::<DatePrecision>(json!("week")).unwrap(),
+            DatePrecision::Unknown
+        );
+    }
+
+    #[test]
+    fn invalid_release_dates() {
+        assert!(release_date("1981", "day").is_err());
+        assert!(release_date("1981-13", "month").is_err());
+        assert!(release_date("December 1981", "year").is_err());
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let date = release_date("1981-12", "month").unwrap();
+
+        assert_eq!(
+            serde_json::to_value(date).unwrap(),
+            json!({ "release_date": "1981-12", "release_date_precision": "month" })
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Everything related to search results from Spotify's catalog.
 
 mod private {
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
     use crate::model::{
         page::PageObject,
@@ -9,10 +9,14 @@ mod private {
         track::TrackObject,
     };
 
-    #[derive(Debug, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize)]
     pub struct SearchResultsObject {
+        // Spotify leaves out the item types that weren't searched for
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub tracks: Option<PageObject<TrackObject>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub artists: Option<PageObject<ArtistObject>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub albums: Option<PageObject<AlbumObject>>,
         // playlists: Page<Playlist>,
         // shows: Page<Show>,
@@ -20,14 +24,14 @@ mod private {
     }
 }
 
-use std::{convert::Infallible, marker::PhantomData};
+use std::marker::PhantomData;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 pub(crate) use self::private::SearchResultsObject;
 use super::{
-    album::{AlbumObject, FullAlbum},
-    artist::{ArtistObject, FullArtist},
+    album::{AlbumObject, PartialAlbum},
+    artist::{ArtistObject, CommonArtistInformation, FullArtist},
     page::{Page, PageInformation, PageObject},
     track::{FullTrack, TrackObject},
     ItemType,
@@ -48,7 +52,8 @@ pub trait ToTypesString: crate::private::Sealed {
 }
 
 /// First pages of search results from a [search](crate::client::unscoped::UnscopedClient::search).
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
 pub struct SearchResults {
     pub(crate) inner: SearchResultsObject,
 }
@@ -58,7 +63,7 @@ pub struct SearchResults {
 ///
 /// This object is retrieved only through requesting the [next page](Page::next_page) from an existing page of results.
 /// You won't be interacting objects of this type directly.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[doc(hidden)]
 pub struct TrackSearchResults {
     tracks: PageObject<TrackObject>,
@@ -69,7 +74,7 @@ pub struct TrackSearchResults {
 ///
 /// This object is retrieved only through requesting the [next page](Page::next_page) from an existing page of results.
 /// You won't be interacting objects of this type directly.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[doc(hidden)]
 pub struct ArtistSearchResults {
     artists: PageObject<ArtistObject>,
@@ -80,17 +85,15 @@ pub struct ArtistSearchResults {
 ///
 /// This object is retrieved only through requesting the [next page](Page::next_page) from an existing page of results.
 /// You won't be interacting objects of this type directly.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[doc(hidden)]
 pub struct AlbumSearchResults {
     albums: PageObject<AlbumObject>,
 }
 
-impl TryFrom<SearchResultsObject> for SearchResults {
-    type Error = Infallible;
-
-    fn try_from(value: SearchResultsObject) -> Result<Self, Self::Error> {
-        Ok(Self { inner: value })
+impl From<SearchResultsObject> for SearchResults {
+    fn from(value: SearchResultsObject) -> Self {
+        Self { inner: value }
     }
 }
 
@@ -129,13 +132,29 @@ impl SearchResults {
         })
     }
 
-    /// Return the albums in these search results as a [Page] of [FullAlbums](FullAlbum).
+    /// Return the first artist in these search results whose name equals the given query, ignoring case.
+    ///
+    /// Spotify orders the results by relevance, which doesn't mean the first artist's name matches the query exactly.
+    /// Only the artists in the first page of results are considered. Returns `None` if none of them match.
+    pub fn best_artist_match(&self, query: &str) -> Option<FullArtist> {
+        let query = query.to_lowercase();
+
+        self.inner
+            .artists
+            .as_ref()
+            .map(<PageObject<ArtistObject> as PageInformation<FullArtist>>::items)?
+            .into_iter()
+            .find(|artist| artist.name().to_lowercase() == query)
+    }
+
+    /// Return the albums in these search results as a [Page] of [PartialAlbums](PartialAlbum). Spotify doesn't return
+    /// full albums in search results.
     ///
     /// If no albums matched the search query, this will return None. Therefore, the returned page will always contain
     /// some items.
-    pub fn albums(self) -> Option<Page<AlbumSearchResults, FullAlbum>> {
+    pub fn albums(self) -> Option<Page<AlbumSearchResults, PartialAlbum>> {
         self.inner.albums.and_then(|page| {
-            if !<PageObject<AlbumObject> as PageInformation<FullAlbum>>::items(&page).is_empty() {
+            if !<PageObject<AlbumObject> as PageInformation<PartialAlbum>>::items(&page).is_empty() {
                 Some(Page {
                     inner: AlbumSearchResults { albums: page },
                     phantom: PhantomData,
@@ -165,6 +184,26 @@ impl PageInformation<FullTrack> for TrackSearchResults {
     fn next(self) -> Option<String> {
         <PageObject<TrackObject> as PageInformation<FullTrack>>::next(self.tracks)
     }
+
+    fn total(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::total(&self.tracks)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::limit(&self.tracks)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::offset(&self.tracks)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::has_next(&self.tracks)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::has_previous(&self.tracks)
+    }
 }
 
 impl PageInformation<FullArtist> for ArtistSearchResults {
@@ -181,10 +220,30 @@ impl PageInformation<FullArtist> for ArtistSearchResults {
     fn next(self) -> Option<String> {
         <PageObject<ArtistObject> as PageInformation<FullArtist>>::next(self.artists)
     }
+
+    fn total(&self) -> usize {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::total(&self.artists)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::limit(&self.artists)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::offset(&self.artists)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::has_next(&self.artists)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::has_previous(&self.artists)
+    }
 }
 
-impl PageInformation<FullAlbum> for AlbumSearchResults {
-    type Items = Vec<FullAlbum>;
+impl PageInformation<PartialAlbum> for AlbumSearchResults {
+    type Items = Vec<PartialAlbum>;
 
     fn items(&self) -> Self::Items {
         self.albums.items()
@@ -195,7 +254,27 @@ impl PageInformation<FullAlbum> for AlbumSearchResults {
     }
 
     fn next(self) -> Option<String> {
-        <PageObject<AlbumObject> as PageInformation<FullAlbum>>::next(self.albums)
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::next(self.albums)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::total(&self.albums)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::limit(&self.albums)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::offset(&self.albums)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::has_next(&self.albums)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::has_previous(&self.albums)
     }
 }
 
@@ -213,3 +292,180 @@ where
             .join(",")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::model::{
+        album::CommonAlbumInformation,
+        artist::{Artist, PartialArtist},
+        track::CommonTrackInformation,
+    };
+
+    fn artist_json() -> Value {
+        json!({
+            "external_urls": {
+                "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+            },
+            "followers": { "href": null, "total": 1224745 },
+            "genres": ["vocaloid"],
+            "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+            "id": "6pNgnvzBa6Bthsv8SrZJYl",
+            "images": [],
+            "name": "Hatsune Miku",
+            "popularity": 66,
+            "type": "artist",
+            "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+        })
+    }
+
+    fn partial_artist_json() -> Value {
+        json!({
+            "external_urls": {
+                "spotify": "https://open.spotify.com/artist/6pNgnvzBa6Bthsv8SrZJYl"
+            },
+            "href": "https://api.spotify.com/v1/artists/6pNgnvzBa6Bthsv8SrZJYl",
+            "id": "6pNgnvzBa6Bthsv8SrZJYl",
+            "name": "Hatsune Miku",
+            "type": "artist",
+            "uri": "spotify:artist:6pNgnvzBa6Bthsv8SrZJYl"
+        })
+    }
+
+    fn album_json() -> Value {
+        json!({
+            "album_type": "single",
+            "artists": [partial_artist_json()],
+            "external_urls": {
+                "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+            "images": [],
+            "name": "World is Mine",
+            "release_date": "2008-05-31",
+            "release_date_precision": "day",
+            "total_tracks": 1,
+            "type": "album",
+            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+        })
+    }
+
+    fn track_json() -> Value {
+        json!({
+            "album": album_json(),
+            "artists": [partial_artist_json()],
+            "disc_number": 1,
+            "duration_ms": 254000,
+            "explicit": false,
+            "external_ids": { "isrc": "JPU900800002" },
+            "external_urls": {
+                "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu"
+            },
+            "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+            "id": "2pDPOMX0kWA7kcPBcDCQBu",
+            "is_local": false,
+            "name": "World is Mine",
+            "popularity": 60,
+            "preview_url": null,
+            "track_number": 1,
+            "type": "track",
+            "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+        })
+    }
+
+    fn page_json(items: Vec<Value>, next: Option<&str>) -> Value {
+        json!({
+            "href": "https://api.spotify.com/v1/search?query=hatsune+miku&offset=0&limit=20",
+            "items": items,
+            "limit": 20,
+            "next": next,
+            "offset": 0,
+            "previous": null,
+            "total": items.len(),
+        })
+    }
+
+    fn search_results(value: Value) -> SearchResults {
+        let obj: SearchResultsObject = serde_json::from_value(value).unwrap();
+        SearchResults::from(obj)
+    }
+
+    #[test]
+    fn combined_search_results() {
+        let value = json!({
+            "tracks": page_json(vec![track_json()], Some("https://api.spotify.com/v1/search?offset=20&limit=20")),
+            "artists": page_json(vec![artist_json()], None),
+            "albums": page_json(vec![album_json()], None),
+        });
+
+        let tracks = search_results(value.clone()).tracks().unwrap();
+        assert_eq!(tracks.items().len(), 1);
+        assert_eq!(tracks.items()[0].name(), "World is Mine");
+
+        let artists = search_results(value.clone()).artists().unwrap();
+        assert_eq!(artists.items()[0].name(), "Hatsune Miku");
+
+        let albums = search_results(value).albums().unwrap();
+        assert_eq!(albums.items()[0].name(), "World is Mine");
+    }
+
+    #[test]
+    fn artist_only_search_results() {
+        let value = json!({
+            "artists": page_json(vec![artist_json()], None),
+        });
+
+        assert!(search_results(value.clone()).tracks().is_none());
+        assert!(search_results(value.clone()).albums().is_none());
+
+        let artists = search_results(value).artists().unwrap().take_items();
+        let partial = artists
+            .into_iter()
+            .map(|artist| PartialArtist::try_from(Artist::from(artist)).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(partial[0].name(), "Hatsune Miku");
+    }
+
+    #[test]
+    fn serialize_search_results() {
+        let value = json!({
+            "artists": page_json(vec![artist_json()], None),
+        });
+        let serialized = serde_json::to_value(search_results(value)).unwrap();
+
+        assert!(serialized.get("tracks").is_none());
+        assert_eq!(serialized["artists"]["items"][0]["name"], "Hatsune Miku");
+        assert_eq!(serialized["artists"]["items"][0]["followers"]["total"], 1224745);
+    }
+
+    #[test]
+    fn empty_page_is_none() {
+        let value = json!({
+            "tracks": page_json(Vec::new(), None),
+        });
+
+        assert!(search_results(value).tracks().is_none());
+    }
+
+    #[test]
+    fn best_artist_match_skips_fuzzy_top_result() {
+        let mut fuzzy = artist_json();
+        fuzzy["id"] = json!("0AqlFI0tz2DsEoJlKSIiT9");
+        fuzzy["name"] = json!("Hatsune Miku Symphony");
+
+        let value = json!({
+            "artists": page_json(vec![fuzzy, artist_json()], None),
+        });
+
+        let results = search_results(value);
+
+        assert_eq!(
+            results.best_artist_match("hatsune miku").unwrap().name(),
+            "Hatsune Miku"
+        );
+        assert!(results.best_artist_match("Kagamine Rin").is_none());
+    }
+}
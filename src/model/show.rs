@@ -0,0 +1,391 @@
+//! Everything related to shows, also known as podcasts.
+//!
+//! Contains the two different kinds of shows; [FullShow] and [PartialShow].
+//!
+//! - [FullShow]: contains all information about a show, including the first page of its episodes. Retrieved from the
+//!   [show](crate::client::UnscopedClient::show)-function.
+//! - [PartialShow]: contains most information about a show, but not its episodes. Retrieved as part of a
+//!   [full episode](crate::model::episode::FullEpisode), or from the user's saved shows.
+//!
+//! # Show equality
+//!
+//! Two shows are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use std::collections::HashSet;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::model::{
+        id::{Id, ShowId},
+        object_type::{object_type_serialize, TypeShow},
+        show::MediaType,
+        Copyright, CountryCode, ExternalUrls, Image,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonShowFields;
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonShowFields {
+        pub(crate) name: String,
+        pub(crate) id: Id<'static, ShowId>,
+        pub(crate) publisher: String,
+        pub(crate) description: String,
+        #[serde(default)]
+        pub(crate) html_description: String,
+        pub(crate) explicit: bool,
+        pub(crate) languages: Vec<String>,
+        pub(crate) media_type: MediaType,
+        pub(crate) images: Vec<Image>,
+        pub(crate) is_externally_hosted: Option<bool>,
+        #[serde(default)]
+        pub(crate) total_episodes: u32,
+        #[serde(default)]
+        pub(crate) copyrights: Vec<Copyright>,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(default)]
+        pub(crate) available_markets: HashSet<CountryCode>,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypeShow,
+    }
+}
+
+use std::{collections::HashSet, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
+
+use self::private::CommonShowFields;
+use super::{
+    episode::PartialEpisode,
+    id::{Id, IdTrait, ShowId},
+    page::{Page, PageInformation, PageObject},
+    Copyright, CountryCode, ExternalUrls, Image,
+};
+
+/// Functions for retrieving information that is common to every show type.
+pub trait CommonShowInformation: crate::private::Sealed {
+    /// The show's name.
+    fn name(&self) -> &str;
+    /// The show's Spotify ID.
+    fn id(&self) -> Id<'_, ShowId>;
+    /// The show's publisher.
+    fn publisher(&self) -> &str;
+    /// The show's description without HTML tags.
+    fn description(&self) -> &str;
+    /// The show's description, which may contain HTML tags.
+    fn html_description(&self) -> &str;
+    /// Whether the show has explicit content.
+    fn explicit(&self) -> bool;
+    /// The languages used in the show, as ISO 639 codes.
+    fn languages(&self) -> &[String];
+    /// The media type of the show's episodes.
+    fn media_type(&self) -> MediaType;
+    /// The images for the show.
+    fn images(&self) -> &[Image];
+    /// Whether the show is hosted outside of Spotify's CDN. `None` if unknown.
+    fn is_externally_hosted(&self) -> Option<bool>;
+    /// The total amount of episodes in the show.
+    fn total_episodes(&self) -> u32;
+    /// The show's copyrights.
+    fn copyrights(&self) -> &[Copyright];
+    /// The external URLs for the show.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The countries the show is available in.
+    fn available_markets(&self) -> &HashSet<CountryCode>;
+}
+
+impl<T> CommonShowInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn id(&self) -> Id<'_, ShowId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn publisher(&self) -> &str {
+        &self.common_fields().publisher
+    }
+
+    fn description(&self) -> &str {
+        &self.common_fields().description
+    }
+
+    fn html_description(&self) -> &str {
+        &self.common_fields().html_description
+    }
+
+    fn explicit(&self) -> bool {
+        self.common_fields().explicit
+    }
+
+    fn languages(&self) -> &[String] {
+        &self.common_fields().languages
+    }
+
+    fn media_type(&self) -> MediaType {
+        self.common_fields().media_type
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn is_externally_hosted(&self) -> Option<bool> {
+        self.common_fields().is_externally_hosted
+    }
+
+    fn total_episodes(&self) -> u32 {
+        self.common_fields().total_episodes
+    }
+
+    fn copyrights(&self) -> &[Copyright] {
+        &self.common_fields().copyrights
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn available_markets(&self) -> &HashSet<CountryCode> {
+        &self.common_fields().available_markets
+    }
+}
+
+/// A full show. Contains all [common](self::CommonShowInformation) information about a show, in addition to the first
+/// page of its episodes.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct FullShow {
+    #[serde(flatten)]
+    common: CommonShowFields,
+    episodes: ShowEpisodes,
+}
+
+/// A partial show. Contains all [common](self::CommonShowInformation) information about a show.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct PartialShow {
+    #[serde(flatten)]
+    common: CommonShowFields,
+}
+
+/// A page of a show's episodes.
+///
+/// This object is retrieved only through the [episodes](FullShow::episodes)- and
+/// [show_episodes](crate::client::UnscopedClient::show_episodes)-functions. You won't be interacting objects of this type
+/// directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct ShowEpisodes {
+    #[serde(flatten)]
+    page: PageObject<PartialEpisode>,
+}
+
+/// The media type of a show's episodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    Audio,
+    Video,
+    Mixed,
+    /// A media type this library doesn't know of.
+    #[serde(other)]
+    Unknown,
+}
+
+impl FullShow {
+    /// The first page of the show's episodes.
+    pub fn episodes(&self) -> Page<ShowEpisodes, PartialEpisode> {
+        Page {
+            inner: self.episodes.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl PartialEq for FullShow {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialShow {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialShow> for FullShow {
+    fn eq(&self, other: &PartialShow) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullShow> for PartialShow {
+    fn eq(&self, other: &FullShow) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl From<FullShow> for PartialShow {
+    fn from(full: FullShow) -> Self {
+        PartialShow { common: full.common }
+    }
+}
+
+impl crate::private::Sealed for FullShow {}
+impl crate::private::Sealed for PartialShow {}
+
+impl private::CommonFields for FullShow {
+    fn common_fields(&self) -> &CommonShowFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialShow {
+    fn common_fields(&self) -> &CommonShowFields {
+        &self.common
+    }
+}
+
+impl From<ShowEpisodes> for Page<ShowEpisodes, PartialEpisode> {
+    fn from(page: ShowEpisodes) -> Self {
+        Page {
+            inner: page,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for ShowEpisodes {}
+
+impl PageInformation<PartialEpisode> for ShowEpisodes {
+    type Items = Vec<PartialEpisode>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PartialEpisode> as PageInformation<PartialEpisode>>::next(self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PartialEpisode> as PageInformation<PartialEpisode>>::total(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PartialEpisode> as PageInformation<PartialEpisode>>::limit(&self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PartialEpisode> as PageInformation<PartialEpisode>>::offset(&self.page)
+    }
+
+    fn has_next(&self) -> bool {
+        <PageObject<PartialEpisode> as PageInformation<PartialEpisode>>::has_next(&self.page)
+    }
+
+    fn has_previous(&self) -> bool {
+        <PageObject<PartialEpisode> as PageInformation<PartialEpisode>>::has_previous(&self.page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::{episode::CommonEpisodeInformation, DatePrecision};
+
+    #[test]
+    fn full_show_with_episodes() {
+        let show: FullShow = serde_json::from_value(json!({
+            "available_markets": ["FI", "JP"],
+            "copyrights": [],
+            "description": "Candid conversations about the podcasting industry.",
+            "episodes": {
+                "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ/episodes?offset=0&limit=50",
+                "items": [
+                    {
+                        "audio_preview_url": null,
+                        "description": "Tips, tricks and advice from Anchor creators.",
+                        "duration_ms": 1686230,
+                        "explicit": false,
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/episodes/512ojhOuo1ktJprKbVcKyQ",
+                        "html_description": "<p>Tips, tricks and advice from Anchor creators.</p>",
+                        "id": "512ojhOuo1ktJprKbVcKyQ",
+                        "images": [],
+                        "is_externally_hosted": false,
+                        "is_playable": true,
+                        "languages": ["en"],
+                        "name": "Starting Your Own Podcast",
+                        "release_date": "2019-07-23",
+                        "release_date_precision": "day",
+                        "resume_point": { "fully_played": true, "resume_position_ms": 0 },
+                        "type": "episode",
+                        "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ"
+                    }
+                ],
+                "limit": 50,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 1
+            },
+            "explicit": false,
+            "external_urls": { "spotify": "https://open.spotify.com/show/38bS44xjbVVZ3No3ByF1dJ" },
+            "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+            "html_description": "<p>Candid conversations about the podcasting industry.</p>",
+            "id": "38bS44xjbVVZ3No3ByF1dJ",
+            "images": [],
+            "is_externally_hosted": false,
+            "languages": ["en"],
+            "media_type": "audio",
+            "name": "Spotify Sessions",
+            "publisher": "Spotify",
+            "total_episodes": 1,
+            "type": "show",
+            "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+        }))
+        .unwrap();
+
+        assert_eq!(show.name(), "Spotify Sessions");
+        assert_eq!(show.publisher(), "Spotify");
+        assert_eq!(show.media_type(), MediaType::Audio);
+        assert_eq!(show.is_externally_hosted(), Some(false));
+        assert_eq!(show.languages(), ["en"]);
+
+        let episodes = show.episodes();
+        assert_eq!(episodes.total(), 1);
+
+        let episode = &episodes.items()[0];
+        assert_eq!(episode.id().as_str(), "512ojhOuo1ktJprKbVcKyQ");
+        assert_eq!(episode.duration(), Duration::from_millis(1686230));
+        assert_eq!(episode.release_date_precision(), DatePrecision::Day);
+        assert_eq!(episode.is_playable(), Some(true));
+        assert!(episode.resume_point().unwrap().fully_played);
+
+        assert_eq!(PartialShow::from(show.clone()), show);
+    }
+
+    #[test]
+    fn unknown_media_type() {
+        assert_eq!(
+            serde_json::from_value::<MediaType>(json!("interactive")).unwrap(),
+            MediaType::Unknown
+        );
+    }
+}
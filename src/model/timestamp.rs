@@ -0,0 +1,294 @@
+//! Contains the [Timestamp] struct and the [DateTime] type it converts into.
+
+use std::{fmt, str::FromStr, time::SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::TimestampError,
+    util::timestamp::{self, SECONDS_PER_DAY},
+};
+
+/// A point in time Spotify returns as an ISO 8601 UTC timestamp, such as when a track was added to a playlist or when
+/// it was played.
+///
+/// The timestamp converts into a [SystemTime], and its date and time of day in UTC are available through its functions.
+/// It is displayed and serialized as an ISO 8601 UTC timestamp with millisecond precision.
+///
+/// With the `chrono` crate feature, the timestamp converts into and from `chrono::DateTime<Utc>`. With the `time` crate
+/// feature, it converts into and from `time::OffsetDateTime`. Either way, [datetime](Timestamp::datetime) returns the
+/// timestamp as the enabled feature's [DateTime] type. Like the conversions from [SystemTime] in those crates,
+/// converting a timestamp outside their supported range panics, which never happens with timestamps from Spotify.
+///
+/// ```
+/// # use ferrispot::model::Timestamp;
+/// let timestamp: Timestamp = "2016-12-13T20:44:04.589Z".parse().unwrap();
+///
+/// assert_eq!(timestamp.year(), 2016);
+/// assert_eq!(timestamp.hour(), 20);
+/// assert_eq!(timestamp.to_string(), "2016-12-13T20:44:04.589Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(#[serde(with = "timestamp")] SystemTime);
+
+/// The date and time type [Timestamp::datetime] returns. It is `chrono::DateTime<Utc>` with the `chrono` crate feature,
+/// `time::OffsetDateTime` with the `time` crate feature, and the raw ISO 8601 UTC timestamp string without either.
+#[cfg(feature = "chrono")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// The date and time type [Timestamp::datetime] returns. It is `chrono::DateTime<Utc>` with the `chrono` crate feature,
+/// `time::OffsetDateTime` with the `time` crate feature, and the raw ISO 8601 UTC timestamp string without either.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type DateTime = time::OffsetDateTime;
+
+/// The date and time type [Timestamp::datetime] returns. It is `chrono::DateTime<Utc>` with the `chrono` crate feature,
+/// `time::OffsetDateTime` with the `time` crate feature, and the raw ISO 8601 UTC timestamp string without either.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type DateTime = String;
+
+impl Timestamp {
+    /// The timestamp as a [DateTime]: `chrono::DateTime<Utc>` with the `chrono` crate feature, `time::OffsetDateTime`
+    /// with the `time` crate feature, and the ISO 8601 UTC timestamp string, as it is displayed, without either.
+    pub fn datetime(&self) -> DateTime {
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        {
+            self.0.into()
+        }
+
+        #[cfg(not(any(feature = "chrono", feature = "time")))]
+        {
+            self.to_string()
+        }
+    }
+
+    /// The timestamp as a [SystemTime].
+    pub fn as_system_time(&self) -> SystemTime {
+        self.0
+    }
+
+    /// The year of the timestamp.
+    pub fn year(&self) -> i32 {
+        self.date().0 as i32
+    }
+
+    /// The month of the timestamp, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.date().1 as u8
+    }
+
+    /// The day of the month of the timestamp, from 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.date().2 as u8
+    }
+
+    /// The hour of the timestamp, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        (self.seconds_of_day() / 3600) as u8
+    }
+
+    /// The minute of the timestamp, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        (self.seconds_of_day() % 3600 / 60) as u8
+    }
+
+    /// The second of the timestamp, from 0 to 59.
+    pub fn second(&self) -> u8 {
+        (self.seconds_of_day() % 60) as u8
+    }
+
+    /// The fraction of a second of the timestamp in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        timestamp::seconds_and_nanos(self.0).1
+    }
+
+    fn date(&self) -> (i64, i64, i64) {
+        let (seconds, _) = timestamp::seconds_and_nanos(self.0);
+        timestamp::civil_from_days(seconds.div_euclid(SECONDS_PER_DAY))
+    }
+
+    fn seconds_of_day(&self) -> i64 {
+        timestamp::seconds_and_nanos(self.0).0.rem_euclid(SECONDS_PER_DAY)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        Self(time)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0.into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for Timestamp
+where
+    Tz: chrono::TimeZone,
+{
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        Self(datetime.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0.into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        Self(datetime.into())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampError;
+
+    /// Parses an ISO 8601 UTC timestamp with an optional fractional second, such as `2016-12-13T20:44:04Z` or
+    /// `2016-12-13T20:44:04.589Z`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        timestamp::parse(s)
+            .map(Self)
+            .ok_or_else(|| TimestampError(s.to_owned()))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&timestamp::format(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_with_millis() {
+        let timestamp: Timestamp = serde_json::from_value(json!("2016-12-13T20:44:04.589Z")).unwrap();
+
+        assert_eq!(
+            timestamp
+                .as_system_time()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap(),
+            Duration::from_millis(1_481_661_844_589)
+        );
+        assert_eq!((timestamp.year(), timestamp.month(), timestamp.day()), (2016, 12, 13));
+        assert_eq!((timestamp.hour(), timestamp.minute(), timestamp.second()), (20, 44, 4));
+        assert_eq!(timestamp.nanosecond(), 589_000_000);
+    }
+
+    #[test]
+    fn deserialize_without_millis() {
+        let timestamp: Timestamp = serde_json::from_value(json!("2000-02-29T23:59:59Z")).unwrap();
+
+        assert_eq!((timestamp.year(), timestamp.month(), timestamp.day()), (2000, 2, 29));
+        assert_eq!((timestamp.hour(), timestamp.minute(), timestamp.second()), (23, 59, 59));
+        assert_eq!(timestamp.nanosecond(), 0);
+    }
+
+    #[test]
+    fn before_unix_epoch() {
+        let timestamp: Timestamp = "1969-12-31T23:59:59.500Z".parse().unwrap();
+
+        assert_eq!((timestamp.year(), timestamp.month(), timestamp.day()), (1969, 12, 31));
+        assert_eq!(timestamp.second(), 59);
+        assert_eq!(timestamp.nanosecond(), 500_000_000);
+    }
+
+    #[test]
+    fn invalid_timestamp() {
+        assert!("2016-12-13 20:44:04Z".parse::<Timestamp>().is_err());
+        assert!(serde_json::from_value::<Timestamp>(json!("2016-12-13")).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversion() {
+        use chrono::{DateTime, Datelike, Timelike, Utc};
+
+        let timestamp: Timestamp = "2016-12-13T20:44:04.589Z".parse().unwrap();
+        let datetime = DateTime::<Utc>::from(timestamp);
+
+        assert_eq!((datetime.year(), datetime.month(), datetime.day()), (2016, 12, 13));
+        assert_eq!((datetime.hour(), datetime.minute(), datetime.second()), (20, 44, 4));
+        assert_eq!(datetime.nanosecond(), 589_000_000);
+        assert_eq!(Timestamp::from(datetime), timestamp);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_conversion() {
+        use time::{Month, OffsetDateTime};
+
+        let timestamp: Timestamp = "2016-12-13T20:44:04.589Z".parse().unwrap();
+        let datetime = OffsetDateTime::from(timestamp);
+
+        assert_eq!(
+            (datetime.year(), datetime.month(), datetime.day()),
+            (2016, Month::December, 13)
+        );
+        assert_eq!((datetime.hour(), datetime.minute(), datetime.second()), (20, 44, 4));
+        assert_eq!(datetime.nanosecond(), 589_000_000);
+        assert_eq!(Timestamp::from(datetime), timestamp);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn datetime_with_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        let timestamp: Timestamp = "2016-12-13T20:44:04Z".parse().unwrap();
+        let datetime: chrono::DateTime<Utc> = timestamp.datetime();
+
+        assert_eq!(datetime, Utc.with_ymd_and_hms(2016, 12, 13, 20, 44, 4).unwrap());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn datetime_with_time() {
+        use time::OffsetDateTime;
+
+        let timestamp: Timestamp = "2016-12-13T20:44:04Z".parse().unwrap();
+        let datetime: OffsetDateTime = timestamp.datetime();
+
+        assert_eq!(datetime, OffsetDateTime::from_unix_timestamp(1_481_661_844).unwrap());
+    }
+
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    #[test]
+    fn datetime_without_features() {
+        let timestamp: Timestamp = "2016-12-13T20:44:04Z".parse().unwrap();
+        let datetime: String = timestamp.datetime();
+
+        assert_eq!(datetime, "2016-12-13T20:44:04.000Z");
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let timestamp: Timestamp = "2016-12-13T20:44:04Z".parse().unwrap();
+        let value = serde_json::to_value(timestamp).unwrap();
+
+        assert_eq!(value, json!("2016-12-13T20:44:04.000Z"));
+        assert_eq!(serde_json::from_value::<Timestamp>(value).unwrap(), timestamp);
+    }
+}
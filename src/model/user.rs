@@ -14,6 +14,10 @@
 //!
 //! Additionally, there is the [User] enum that encompasses all three kinds of users.
 //!
+//! Since the private user information depends on the granted scopes, a user object is deserialized as the most specific
+//! kind of user its fields allow. For example, a user object without every private field is deserialized as a
+//! [CurrentUser] instead of a [PrivateUser].
+//!
 //! The user object Spotify returns from the API is not directly available. The three user objects, or the [User] enum,
 //! may be serialized to get almost all of the original API response back. The model strips certain unnecessary or
 //! redundant fields from the response.
@@ -21,7 +25,7 @@
 mod private {
     use serde::{Deserialize, Serialize};
 
-    use super::{ExplicitContent, Followers};
+    use super::{ExplicitContent, Followers, SubscriptionLevel};
     use crate::model::{
         id::{Id, UserId},
         object_type::{object_type_serialize, TypeUser},
@@ -62,6 +66,8 @@ mod private {
         pub(crate) display_name: Option<String>,
         #[serde(default)]
         pub(crate) external_urls: ExternalUrls,
+        // users that are part of another object, such as a playlist's owner, don't include their followers
+        #[serde(default)]
         pub(crate) followers: Followers,
         pub(crate) id: Id<'static, UserId>,
         #[serde(default)]
@@ -80,29 +86,20 @@ mod private {
     pub(crate) struct PrivateUserFields {
         pub(crate) country: CountryCode,
         pub(crate) explicit_content: ExplicitContent,
-        // TODO: this should really be an enum, but I don't know what all the variants could be. there's at least
-        // "premium", "free" aka "open", but there's also something like "family" maybe? "duo", "student"? even
-        // more?
-        pub(crate) product: String,
+        pub(crate) product: SubscriptionLevel,
     }
 }
 
 use serde::{Deserialize, Serialize};
 
 use self::private::{CommonUserFields, CurrentUserFields, PrivateUserFields, UserObject};
+pub use super::Followers;
 use super::{
     id::{Id, UserId},
     CountryCode, ExternalUrls, Image,
 };
 use crate::{error::ConversionError, prelude::IdTrait};
 
-/// Information about a user's followers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Followers {
-    // the API documents a href parameter but says it's always null, so it's not included here
-    pub total: u32,
-}
-
 /// Information about a user's explicit content settings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExplicitContent {
@@ -112,6 +109,21 @@ pub struct ExplicitContent {
     pub filter_locked: bool,
 }
 
+/// A user's Spotify subscription level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionLevel {
+    /// Spotify Premium, including the family, duo and student plans.
+    Premium,
+    /// Spotify Free.
+    Free,
+    /// Spotify Free; an older name for the free subscription level.
+    Open,
+    /// A subscription level this library doesn't know of.
+    #[serde(other)]
+    Unknown,
+}
+
 /// Functions for retrieving information that is common to every user type.
 pub trait CommonUserInformation: crate::private::Sealed {
     /// The user's display name if available.
@@ -119,7 +131,7 @@ pub trait CommonUserInformation: crate::private::Sealed {
     /// The external URLs for the user.
     fn external_urls(&self) -> &ExternalUrls;
     /// Information about the user's followers.
-    fn followers(&self) -> Followers;
+    fn followers(&self) -> &Followers;
     /// The user's ID.
     fn id(&self) -> Id<'_, UserId>;
     /// The user's images.
@@ -139,7 +151,7 @@ pub trait PrivateUserInformation: crate::private::Sealed {
     /// The user's explicit content settings.
     fn explicit_content(&self) -> ExplicitContent;
     /// The user's subscription level.
-    fn product(&self) -> &str;
+    fn product(&self) -> SubscriptionLevel;
 }
 
 impl<T> CommonUserInformation for T
@@ -154,8 +166,8 @@ where
         &self.common_fields().external_urls
     }
 
-    fn followers(&self) -> Followers {
-        self.common_fields().followers
+    fn followers(&self) -> &Followers {
+        &self.common_fields().followers
     }
 
     fn id(&self) -> Id<'_, UserId> {
@@ -188,8 +200,8 @@ where
         self.private_fields().explicit_content
     }
 
-    fn product(&self) -> &str {
-        &self.private_fields().product
+    fn product(&self) -> SubscriptionLevel {
+        self.private_fields().product
     }
 }
 
@@ -296,6 +308,19 @@ impl PartialEq<CurrentUser> for PublicUser {
     }
 }
 
+impl User {
+    /// Returns whether the user's explicit content filter is enabled. `None` if the user's explicit content settings
+    /// aren't known, which is the case for every user other than a [PrivateUser](self::PrivateUser).
+    ///
+    /// When the filter is enabled, Spotify may refuse to play explicit tracks for the user.
+    pub fn explicit_content_filtered(&self) -> Option<bool> {
+        match self {
+            User::Private(private) => Some(private.explicit_content().filter_enabled),
+            User::Current(_) | User::Public(_) => None,
+        }
+    }
+}
+
 impl TryFrom<UserObject> for User {
     type Error = ConversionError;
 
@@ -560,5 +585,150 @@ impl Serialize for PublicUser {
     }
 }
 
-// TODO: unit tests for all the various functions here. deserializing, serializing, equality between users, conversion
-// between users
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn user_json() -> serde_json::Value {
+        json!({
+            "display_name": "Hatsune Miku",
+            "external_urls": { "spotify": "https://open.spotify.com/user/hatsunemiku" },
+            "followers": { "href": null, "total": 39 },
+            "href": "https://api.spotify.com/v1/users/hatsunemiku",
+            "id": "hatsunemiku",
+            "images": [],
+            "type": "user",
+            "uri": "spotify:user:hatsunemiku"
+        })
+    }
+
+    fn current_user_json() -> serde_json::Value {
+        let mut user = user_json();
+        user["email"] = json!("miku@example.com");
+        user
+    }
+
+    fn private_user_json() -> serde_json::Value {
+        let mut user = current_user_json();
+        user["country"] = json!("JP");
+        user["explicit_content"] = json!({ "filter_enabled": false, "filter_locked": true });
+        user["product"] = json!("premium");
+        user
+    }
+
+    #[test]
+    fn public_user() {
+        let user: User = serde_json::from_value(user_json()).unwrap();
+        let User::Public(public) = user else {
+            panic!("expected public user, got {user:?}");
+        };
+
+        assert_eq!(public.display_name(), Some("Hatsune Miku"));
+        assert_eq!(public.id().as_str(), "hatsunemiku");
+        assert_eq!(public.followers().total(), 39);
+        assert!(public.images().is_empty());
+    }
+
+    #[test]
+    fn current_user() {
+        let user: User = serde_json::from_value(current_user_json()).unwrap();
+        let User::Current(current) = user else {
+            panic!("expected current user, got {user:?}");
+        };
+
+        assert_eq!(current.email(), "miku@example.com");
+    }
+
+    #[test]
+    fn private_user() {
+        let user: User = serde_json::from_value(private_user_json()).unwrap();
+        let User::Private(private) = user else {
+            panic!("expected private user, got {user:?}");
+        };
+
+        assert_eq!(private.email(), "miku@example.com");
+        assert_eq!(private.country(), CountryCode::JP);
+        assert_eq!(private.product(), SubscriptionLevel::Premium);
+        assert_eq!(
+            private.explicit_content(),
+            ExplicitContent {
+                filter_enabled: false,
+                filter_locked: true
+            }
+        );
+    }
+
+    #[test]
+    fn explicit_content_filter() {
+        let mut user = private_user_json();
+        user["explicit_content"] = json!({ "filter_enabled": true, "filter_locked": false });
+
+        let private: User = serde_json::from_value(user).unwrap();
+        let current: User = serde_json::from_value(current_user_json()).unwrap();
+
+        assert_eq!(private.explicit_content_filtered(), Some(true));
+        assert_eq!(current.explicit_content_filtered(), None);
+    }
+
+    #[test]
+    fn subscription_levels() {
+        for (product, level) in [
+            ("premium", SubscriptionLevel::Premium),
+            ("free", SubscriptionLevel::Free),
+            ("open", SubscriptionLevel::Open),
+        ] {
+            let mut user = private_user_json();
+            user["product"] = json!(product);
+
+            let private: PrivateUser = serde_json::from_value(user).unwrap();
+            assert_eq!(private.product(), level);
+        }
+    }
+
+    #[test]
+    fn missing_private_fields_is_current_user() {
+        let mut user = private_user_json();
+        user.as_object_mut().unwrap().remove("product");
+
+        assert!(matches!(
+            serde_json::from_value(user.clone()).unwrap(),
+            User::Current(_)
+        ));
+        assert!(serde_json::from_value::<PrivateUser>(user).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        let private: PrivateUser = serde_json::from_value(private_user_json()).unwrap();
+        let current = CurrentUser::try_from(User::from(private.clone())).unwrap();
+        let public = PublicUser::from(User::from(current.clone()));
+
+        assert_eq!(private, current);
+        assert_eq!(current, public);
+        assert!(PrivateUser::try_from(User::from(current)).is_err());
+        assert!(CurrentUser::try_from(User::from(public)).is_err());
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let private: PrivateUser = serde_json::from_value(private_user_json()).unwrap();
+        let serialized = serde_json::to_value(&private).unwrap();
+
+        assert_eq!(serialized["product"], "premium");
+        assert_eq!(serialized["email"], "miku@example.com");
+
+        let deserialized: PrivateUser = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized.product(), private.product());
+        assert_eq!(deserialized.country(), private.country());
+    }
+
+    #[test]
+    fn unknown_subscription_level() {
+        assert_eq!(
+            serde_json::from_value::<SubscriptionLevel>(json!("family")).unwrap(),
+            SubscriptionLevel::Unknown
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! Contains the [Scope]-enum that represents an OAuth authorization scope and various utilities surrounding it.
 
-use std::fmt::Display;
+use std::{borrow::Borrow, fmt::Display, str::FromStr};
+
+use serde::{de, Deserialize, Serialize};
+
+use crate::error::ScopeError;
 
 /// Trait for converting an object to a scopes string. This is currently implemented for all iterators of
-/// [Scope's](Scope).
+/// [Scope's](Scope) or references to them, such as `&[Scope]`.
 pub trait ToScopesString {
     /// Convert `self` to a scopes string.
     fn to_scopes_string(self) -> String;
@@ -57,42 +61,218 @@ pub enum Scope {
     UserLibraryModify,
     /// Read access to a user's library.
     UserLibraryRead,
+    /// Link a partner user account to a Spotify user account. Available only to Spotify Open Access partners.
+    UserSoaLink,
+    /// Unlink a partner user account from a Spotify account. Available only to Spotify Open Access partners.
+    UserSoaUnlink,
+    /// Modify entitlements for linked users. Available only to Spotify Open Access partners.
+    SoaManageEntitlements,
+    /// Update partner information. Available only to Spotify Open Access partners.
+    SoaManagePartner,
+    /// Create new partners. Available only to Spotify Open Access partners, and only to platform partners.
+    SoaCreatePartner,
+}
+
+impl Scope {
+    /// Every scope Spotify supports.
+    pub const ALL: &'static [Scope] = &[
+        Scope::UgcImageUpload,
+        Scope::UserModifyPlaybackState,
+        Scope::UserReadPlaybackState,
+        Scope::UserReadCurrentlyPlaying,
+        Scope::UserFollowModify,
+        Scope::UserFollowRead,
+        Scope::UserReadRecentlyPlayed,
+        Scope::UserReadPlaybackPosition,
+        Scope::UserTopRead,
+        Scope::PlaylistReadCollaborative,
+        Scope::PlaylistModifyPublic,
+        Scope::PlaylistReadPrivate,
+        Scope::PlaylistModifyPrivate,
+        Scope::AppRemoteControl,
+        Scope::Streaming,
+        Scope::UserReadEmail,
+        Scope::UserReadPrivate,
+        Scope::UserLibraryModify,
+        Scope::UserLibraryRead,
+        Scope::UserSoaLink,
+        Scope::UserSoaUnlink,
+        Scope::SoaManageEntitlements,
+        Scope::SoaManagePartner,
+        Scope::SoaCreatePartner,
+    ];
+
+    /// Returns the scope's string representation Spotify expects, e.g. `user-read-private`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::UgcImageUpload => "ugc-image-upload",
+            Scope::UserModifyPlaybackState => "user-modify-playback-state",
+            Scope::UserReadPlaybackState => "user-read-playback-state",
+            Scope::UserReadCurrentlyPlaying => "user-read-currently-playing",
+            Scope::UserFollowModify => "user-follow-modify",
+            Scope::UserFollowRead => "user-follow-read",
+            Scope::UserReadRecentlyPlayed => "user-read-recently-played",
+            Scope::UserReadPlaybackPosition => "user-read-playback-position",
+            Scope::UserTopRead => "user-top-read",
+            Scope::PlaylistReadCollaborative => "playlist-read-collaborative",
+            Scope::PlaylistModifyPublic => "playlist-modify-public",
+            Scope::PlaylistReadPrivate => "playlist-read-private",
+            Scope::PlaylistModifyPrivate => "playlist-modify-private",
+            Scope::AppRemoteControl => "app-remote-control",
+            Scope::Streaming => "streaming",
+            Scope::UserReadEmail => "user-read-email",
+            Scope::UserReadPrivate => "user-read-private",
+            Scope::UserLibraryModify => "user-library-modify",
+            Scope::UserLibraryRead => "user-library-read",
+            Scope::UserSoaLink => "user-soa-link",
+            Scope::UserSoaUnlink => "user-soa-unlink",
+            Scope::SoaManageEntitlements => "soa-manage-entitlements",
+            Scope::SoaManagePartner => "soa-manage-partner",
+            Scope::SoaCreatePartner => "soa-create-partner",
+        }
+    }
 }
 
 impl Display for Scope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Scope::UgcImageUpload => write!(f, "ugc-image-upload"),
-            Scope::UserModifyPlaybackState => write!(f, "user-modify-playback-state"),
-            Scope::UserReadPlaybackState => write!(f, "user-read-playback-state"),
-            Scope::UserReadCurrentlyPlaying => write!(f, "user-read-currently-playing"),
-            Scope::UserFollowModify => write!(f, "user-follow-modify"),
-            Scope::UserFollowRead => write!(f, "user-follow-read"),
-            Scope::UserReadRecentlyPlayed => write!(f, "user-read-recently-played"),
-            Scope::UserReadPlaybackPosition => write!(f, "user-read-playback-position"),
-            Scope::UserTopRead => write!(f, "user-top-read"),
-            Scope::PlaylistReadCollaborative => write!(f, "playlist-read-collaborative"),
-            Scope::PlaylistModifyPublic => write!(f, "playlist-modify-public"),
-            Scope::PlaylistReadPrivate => write!(f, "playlist-read-private"),
-            Scope::PlaylistModifyPrivate => write!(f, "playlist-modify-private"),
-            Scope::AppRemoteControl => write!(f, "app-remote-control"),
-            Scope::Streaming => write!(f, "streaming"),
-            Scope::UserReadEmail => write!(f, "user-read-email"),
-            Scope::UserReadPrivate => write!(f, "user-read-private"),
-            Scope::UserLibraryModify => write!(f, "user-library-modify"),
-            Scope::UserLibraryRead => write!(f, "user-library-read"),
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ugc-image-upload" => Ok(Scope::UgcImageUpload),
+            "user-modify-playback-state" => Ok(Scope::UserModifyPlaybackState),
+            "user-read-playback-state" => Ok(Scope::UserReadPlaybackState),
+            "user-read-currently-playing" => Ok(Scope::UserReadCurrentlyPlaying),
+            "user-follow-modify" => Ok(Scope::UserFollowModify),
+            "user-follow-read" => Ok(Scope::UserFollowRead),
+            "user-read-recently-played" => Ok(Scope::UserReadRecentlyPlayed),
+            "user-read-playback-position" => Ok(Scope::UserReadPlaybackPosition),
+            "user-top-read" => Ok(Scope::UserTopRead),
+            "playlist-read-collaborative" => Ok(Scope::PlaylistReadCollaborative),
+            "playlist-modify-public" => Ok(Scope::PlaylistModifyPublic),
+            "playlist-read-private" => Ok(Scope::PlaylistReadPrivate),
+            "playlist-modify-private" => Ok(Scope::PlaylistModifyPrivate),
+            "app-remote-control" => Ok(Scope::AppRemoteControl),
+            "streaming" => Ok(Scope::Streaming),
+            "user-read-email" => Ok(Scope::UserReadEmail),
+            "user-read-private" => Ok(Scope::UserReadPrivate),
+            "user-library-modify" => Ok(Scope::UserLibraryModify),
+            "user-library-read" => Ok(Scope::UserLibraryRead),
+            "user-soa-link" => Ok(Scope::UserSoaLink),
+            "user-soa-unlink" => Ok(Scope::UserSoaUnlink),
+            "soa-manage-entitlements" => Ok(Scope::SoaManageEntitlements),
+            "soa-manage-partner" => Ok(Scope::SoaManagePartner),
+            "soa-create-partner" => Ok(Scope::SoaCreatePartner),
+            _ => Err(ScopeError(s.to_owned())),
         }
     }
 }
 
+impl Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&s), &"a Spotify authorization scope"))
+    }
+}
+
 impl<I> ToScopesString for I
 where
-    I: IntoIterator<Item = Scope>,
+    I: IntoIterator,
+    I::Item: Borrow<Scope>,
 {
     fn to_scopes_string(self) -> String {
         self.into_iter()
-            .map(|scope| scope.to_string())
-            .collect::<Vec<String>>()
+            .map(|scope| scope.borrow().as_str())
+            .collect::<Vec<&str>>()
             .join(" ")
     }
 }
+
+/// Parses a space-separated scopes string, as returned by Spotify in token responses, into scopes. Unknown scopes are
+/// ignored.
+#[cfg(any(feature = "async", feature = "sync"))]
+pub(crate) fn parse_scopes_string(scopes: &str) -> std::collections::HashSet<Scope> {
+    scopes
+        .split_whitespace()
+        .filter_map(|scope| scope.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_round_trip() {
+        for scope in Scope::ALL {
+            assert_eq!(scope.to_string().parse::<Scope>().unwrap(), *scope);
+            assert_eq!(scope.as_ref(), scope.as_str());
+        }
+    }
+
+    #[test]
+    fn invalid_scope() {
+        assert!("user-read-everything".parse::<Scope>().is_err());
+        assert!("USER-READ-PRIVATE".parse::<Scope>().is_err());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let scopes = [Scope::UserReadPrivate, Scope::PlaylistModifyPublic];
+        let serialized = serde_json::to_string(&scopes).unwrap();
+
+        assert_eq!(serialized, r#"["user-read-private","playlist-modify-public"]"#);
+
+        let deserialized: Vec<Scope> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, scopes);
+    }
+
+    #[test]
+    fn scopes_string() {
+        let scopes = [Scope::UserReadPrivate, Scope::PlaylistModifyPublic, Scope::Streaming];
+
+        assert_eq!(
+            scopes.as_slice().to_scopes_string(),
+            "user-read-private playlist-modify-public streaming"
+        );
+        assert_eq!(
+            scopes.to_scopes_string(),
+            "user-read-private playlist-modify-public streaming"
+        );
+        assert_eq!(Vec::<Scope>::new().to_scopes_string(), "");
+    }
+
+    #[cfg(any(feature = "async", feature = "sync"))]
+    #[test]
+    fn parse_granted_scopes() {
+        let scopes = parse_scopes_string("user-read-private  playlist-modify-public unknown-scope");
+
+        assert_eq!(scopes.len(), 2);
+        assert!(scopes.contains(&Scope::UserReadPrivate));
+        assert!(scopes.contains(&Scope::PlaylistModifyPublic));
+    }
+}
@@ -1,2 +1,5 @@
 pub(crate) mod duration_millis;
 pub(crate) mod maybe_split_once;
+#[cfg(any(feature = "async", feature = "sync"))]
+pub(crate) mod redacted;
+pub(crate) mod timestamp;
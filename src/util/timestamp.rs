@@ -0,0 +1,172 @@
+//! (De)serializes a [SystemTime] from and into an ISO 8601 UTC timestamp, such as `2016-12-13T20:44:04.589Z`.
+
+use std::time::{Duration, SystemTime};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+
+pub(crate) fn serialize<S>(timestamp: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    format(*timestamp).serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let timestamp = String::deserialize(deserializer)?;
+    parse(&timestamp).ok_or_else(|| de::Error::custom(format!("invalid timestamp: {timestamp}")))
+}
+
+/// Parses an ISO 8601 UTC timestamp with an optional fractional second.
+pub(crate) fn parse(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.strip_suffix('Z')?.split_once('T')?;
+
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+
+    let mut time = time.splitn(3, ':').map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..24).contains(&hour)
+        || !(0..60).contains(&minute)
+        || !(0..=60).contains(&second)
+    {
+        return None;
+    }
+
+    let nanos = match fraction {
+        Some(fraction) if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            // only the first nine digits fit in nanoseconds
+            let digits = &fraction[..fraction.len().min(9)];
+            digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32)
+        }
+
+        Some(_) => return None,
+        None => 0,
+    };
+
+    let seconds = days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second;
+    let since_epoch = Duration::new(seconds.unsigned_abs(), 0);
+
+    if seconds >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(since_epoch + Duration::from_nanos(nanos.into()))
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(since_epoch - Duration::from_nanos(nanos.into()))
+    }
+}
+
+/// Formats a timestamp as an ISO 8601 UTC timestamp with millisecond precision.
+pub(crate) fn format(timestamp: SystemTime) -> String {
+    let (seconds, nanos) = seconds_and_nanos(timestamp);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    let seconds_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60,
+        nanos / 1_000_000
+    )
+}
+
+/// Splits a timestamp into the whole seconds since the Unix epoch, rounded down, and the nanoseconds past that second.
+pub(crate) fn seconds_and_nanos(timestamp: SystemTime) -> (i64, u32) {
+    match timestamp.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since_epoch) => (since_epoch.as_secs() as i64, since_epoch.subsec_nanos()),
+        Err(e) => {
+            let before_epoch = e.duration();
+
+            match before_epoch.subsec_nanos() {
+                0 => (-(before_epoch.as_secs() as i64), 0),
+                nanos => (-(before_epoch.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    }
+}
+
+/// Returns the number of days since 1970-01-01 for the given date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date in the proleptic Gregorian calendar for the given number of days since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_with_millis() {
+        let timestamp = parse("2016-12-13T20:44:04.589Z").unwrap();
+
+        assert_eq!(
+            timestamp.duration_since(SystemTime::UNIX_EPOCH).unwrap(),
+            Duration::from_millis(1_481_661_844_589)
+        );
+    }
+
+    #[test]
+    fn parse_without_millis() {
+        let timestamp = parse("2000-02-29T00:00:00Z").unwrap();
+
+        assert_eq!(
+            timestamp.duration_since(SystemTime::UNIX_EPOCH).unwrap(),
+            Duration::from_secs(951_782_400)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(parse("2016-12-13T20:44:04.589").is_none());
+        assert!(parse("2016-12-13 20:44:04Z").is_none());
+        assert!(parse("2016-13-13T20:44:04Z").is_none());
+        assert!(parse("2016-12-13T20:44:04.Z").is_none());
+        assert!(parse("not a timestamp").is_none());
+    }
+
+    #[test]
+    fn format_round_trip() {
+        for timestamp in [
+            "2016-12-13T20:44:04.589Z",
+            "1970-01-01T00:00:00.000Z",
+            "1969-12-31T23:59:59.500Z",
+        ] {
+            assert_eq!(format(parse(timestamp).unwrap()), timestamp);
+        }
+    }
+}